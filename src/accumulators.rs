@@ -0,0 +1,146 @@
+use crate::error::GResult;
+use crate::{Geom, Geometry};
+
+/// Computes the convex hull of a stream of geometries in one pass.
+///
+/// Only the running hull is kept: each added geometry is merged into it, so the hull of a
+/// dataset too large to hold in memory can be computed chunk by chunk. Accumulators filled
+/// separately (on other threads for instance) can be combined with
+/// [`ConvexHullAccumulator::merge`].
+///
+/// # Example
+///
+/// ```
+/// use geos::{ConvexHullAccumulator, Geom, Geometry};
+///
+/// let mut accumulator = ConvexHullAccumulator::new();
+/// for wkt in &["POINT(0 0)", "LINESTRING(10 0, 10 10)", "POINT(0 10)", "POINT(5 5)"] {
+///     let geom = Geometry::new_from_wkt(wkt).unwrap();
+///     accumulator.add(&geom).expect("add failed");
+/// }
+/// let hull = accumulator.finish().expect("finish failed");
+/// assert_eq!(hull.area(), Ok(100.));
+/// ```
+#[derive(Default)]
+pub struct ConvexHullAccumulator {
+    hull: Option<Geometry>,
+}
+
+impl ConvexHullAccumulator {
+    pub fn new() -> ConvexHullAccumulator {
+        ConvexHullAccumulator::default()
+    }
+
+    /// Merges the convex hull of `geom` into the running hull.
+    pub fn add<G: Geom>(&mut self, geom: &G) -> GResult<()> {
+        let hull = geom.convex_hull()?;
+        self.add_hull(hull)
+    }
+
+    /// Merges the running hull of `other` into `self`.
+    pub fn merge(&mut self, other: ConvexHullAccumulator) -> GResult<()> {
+        match other.hull {
+            Some(hull) => self.add_hull(hull),
+            None => Ok(()),
+        }
+    }
+
+    fn add_hull(&mut self, hull: Geometry) -> GResult<()> {
+        if hull.is_empty()? {
+            return Ok(());
+        }
+        self.hull = Some(match self.hull.take() {
+            Some(current) => {
+                Geometry::create_geometry_collection(vec![current, hull])?.convex_hull()?
+            }
+            None => hull,
+        });
+        Ok(())
+    }
+
+    /// Returns the current hull, without consuming the accumulator. Returns `None` if no
+    /// (non-empty) geometry was added.
+    pub fn hull(&self) -> Option<&Geometry> {
+        self.hull.as_ref()
+    }
+
+    /// Returns the hull of all the added geometries: a `Polygon`, or a `LineString` or a `Point`
+    /// if they are collinear or all the same point. Returns an empty `Polygon` if no (non-empty)
+    /// geometry was added.
+    pub fn finish(self) -> GResult<Geometry> {
+        match self.hull {
+            Some(hull) => Ok(hull),
+            None => Geometry::create_empty_polygon(),
+        }
+    }
+}
+
+/// A circle, as returned by [`Geom::minimum_bounding_circle`] and
+/// [`MinimumBoundingCircleAccumulator::finish`].
+///
+/// Available using the `v3_8_0` feature.
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingCircle {
+    pub center: (f64, f64),
+    pub radius: f64,
+}
+
+/// Computes the minimum bounding circle of a stream of geometries in one pass.
+///
+/// The minimum bounding circle of a set of geometries is the one of their convex hull, so only
+/// the running hull is kept (see [`ConvexHullAccumulator`]) and the circle is computed by
+/// [`MinimumBoundingCircleAccumulator::finish`]. The result is the same as if all the
+/// geometries were processed at once.
+///
+/// Available using the `v3_8_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geometry, MinimumBoundingCircleAccumulator};
+///
+/// let mut chunk1 = MinimumBoundingCircleAccumulator::new();
+/// chunk1.add(&Geometry::new_from_wkt("POINT(-10 0)").unwrap()).expect("add failed");
+/// chunk1.add(&Geometry::new_from_wkt("POINT(0 5)").unwrap()).expect("add failed");
+///
+/// let mut chunk2 = MinimumBoundingCircleAccumulator::new();
+/// chunk2.add(&Geometry::new_from_wkt("MULTIPOINT(10 0, 1 1)").unwrap()).expect("add failed");
+///
+/// chunk1.merge(chunk2).expect("merge failed");
+/// let circle = chunk1.finish().expect("finish failed").expect("no circle");
+/// assert_eq!(circle.center, (0., 0.));
+/// assert_eq!(circle.radius, 10.);
+/// ```
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+#[derive(Default)]
+pub struct MinimumBoundingCircleAccumulator {
+    hull: ConvexHullAccumulator,
+}
+
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+impl MinimumBoundingCircleAccumulator {
+    pub fn new() -> MinimumBoundingCircleAccumulator {
+        MinimumBoundingCircleAccumulator::default()
+    }
+
+    pub fn add<G: Geom>(&mut self, geom: &G) -> GResult<()> {
+        self.hull.add(geom)
+    }
+
+    /// Merges the geometries added to `other` into `self`.
+    pub fn merge(&mut self, other: MinimumBoundingCircleAccumulator) -> GResult<()> {
+        self.hull.merge(other.hull)
+    }
+
+    /// Returns the minimum bounding circle of all the added geometries, `None` if no
+    /// (non-empty) geometry was added.
+    pub fn finish(self) -> GResult<Option<BoundingCircle>> {
+        match self.hull.hull {
+            Some(hull) => hull
+                .minimum_bounding_circle()
+                .map(|(_, circle)| Some(circle)),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Robust low-level predicates on coordinates.
+//!
+//! These functions work on raw coordinates instead of [`Geometry`](crate::Geometry)s, so they
+//! can be used when implementing custom algorithms (sweep-lines for example) while relying on
+//! the same robust computations as GEOS.
+use crate::error::{Error, GResult};
+use crate::{ContextHandle, Orientation};
+use geos_sys::*;
+use std::convert::TryFrom;
+
+/// Returns the orientation of the point `(px, py)` relative to the directed segment going from
+/// `(ax, ay)` to `(bx, by)`.
+///
+/// # Example
+///
+/// ```
+/// use geos::algorithms::orientation_index;
+/// use geos::Orientation;
+///
+/// assert_eq!(orientation_index(0., 0., 1., 0., 0., 1.), Ok(Orientation::CounterClockwise));
+/// assert_eq!(orientation_index(0., 0., 1., 0., 0., -1.), Ok(Orientation::Clockwise));
+/// assert_eq!(orientation_index(0., 0., 1., 0., 2., 0.), Ok(Orientation::Colinear));
+/// ```
+pub fn orientation_index(
+    ax: f64,
+    ay: f64,
+    bx: f64,
+    by: f64,
+    px: f64,
+    py: f64,
+) -> GResult<Orientation> {
+    match ContextHandle::init() {
+        Ok(context) => unsafe {
+            match Orientation::try_from(GEOSOrientationIndex_r(
+                context.as_raw(),
+                ax,
+                ay,
+                bx,
+                by,
+                px,
+                py,
+            )) {
+                Ok(o) => Ok(o),
+                Err(e) => Err(Error::GenericError(e.to_owned())),
+            }
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns [`None`] if the segments `(ax0, ay0)-(ax1, ay1)` and `(bx0, by0)-(bx1, by1)` don't
+/// intersect, otherwise returns `Some(x_pos, y_pos)`.
+///
+/// Available using the `v3_7_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::algorithms::segment_intersection;
+///
+/// assert_eq!(
+///     segment_intersection(0., 0., 2., 2., 0., 2., 2., 0.),
+///     Ok(Some((1., 1.))),
+/// );
+/// assert_eq!(segment_intersection(0., 0., 1., 0., 0., 1., 1., 1.), Ok(None));
+/// ```
+#[cfg(any(feature = "v3_7_0", feature = "dox"))]
+#[allow(clippy::too_many_arguments)]
+pub fn segment_intersection(
+    ax0: f64,
+    ay0: f64,
+    ax1: f64,
+    ay1: f64,
+    bx0: f64,
+    by0: f64,
+    bx1: f64,
+    by1: f64,
+) -> GResult<Option<(f64, f64)>> {
+    match ContextHandle::init() {
+        Ok(context) => unsafe {
+            let mut cx = 0.;
+            let mut cy = 0.;
+
+            let ret = GEOSSegmentIntersection_r(
+                context.as_raw(),
+                ax0,
+                ay0,
+                ax1,
+                ay1,
+                bx0,
+                by0,
+                bx1,
+                by1,
+                &mut cx,
+                &mut cy,
+            );
+            if ret == -1 {
+                Ok(None)
+            } else if ret == 1 {
+                Ok(Some((cx, cy)))
+            } else {
+                Err(Error::GenericError(
+                    "GEOSSegmentIntersection_r failed".to_owned(),
+                ))
+            }
+        },
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::orientation_index;
+    use crate::Orientation;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_orientation_values() {
+        assert_eq!(Orientation::try_from(-1), Ok(Orientation::Clockwise));
+        assert_eq!(Orientation::try_from(0), Ok(Orientation::Colinear));
+        assert_eq!(Orientation::try_from(1), Ok(Orientation::CounterClockwise));
+        assert!(Orientation::try_from(2).is_err());
+
+        for orientation in [
+            Orientation::Clockwise,
+            Orientation::Colinear,
+            Orientation::CounterClockwise,
+        ] {
+            let value: i32 = orientation.into();
+            assert_eq!(Orientation::try_from(value), Ok(orientation));
+        }
+    }
+
+    #[test]
+    fn test_orientation_index() {
+        assert_eq!(
+            orientation_index(0., 0., 1., 0., 0., -1.),
+            Ok(Orientation::Clockwise)
+        );
+        assert_eq!(
+            orientation_index(0., 0., 1., 0., 2., 0.),
+            Ok(Orientation::Colinear)
+        );
+        assert_eq!(
+            orientation_index(0., 0., 1., 0., 0., 1.),
+            Ok(Orientation::CounterClockwise)
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! Planar azimuths and bearings, in radians clockwise from the positive Y axis ("north"), in
+//! the `[0, 2π)` range (as PostGIS `ST_Azimuth`).
+use crate::error::{Error, GResult};
+use crate::functions::create_point_with_context;
+use crate::{Geom, Geometry, GeometryTypes};
+use std::f64::consts::TAU;
+
+fn point_xy<G: Geom>(point: &G) -> GResult<(f64, f64)> {
+    match point.geometry_type() {
+        GeometryTypes::Point if !point.is_empty()? => Ok((point.get_x()?, point.get_y()?)),
+        GeometryTypes::Point => Err(Error::GenericError("Point must not be empty".to_owned())),
+        t => Err(Error::GenericError(format!(
+            "Geometry must be a Point, got a {t:?}"
+        ))),
+    }
+}
+
+fn xy_azimuth((ax, ay): (f64, f64), (bx, by): (f64, f64)) -> f64 {
+    let azimuth = (bx - ax).atan2(by - ay);
+    if azimuth < 0. {
+        azimuth + TAU
+    } else {
+        azimuth
+    }
+}
+
+/// Returns the azimuth of the segment going from `p1` to `p2`, two points, in radians clockwise
+/// from the positive Y axis. Returns an error if the points are equal.
+///
+/// # Example
+///
+/// ```
+/// use geos::{azimuth, Geometry};
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// let p1 = Geometry::new_from_wkt("POINT(0 0)").unwrap();
+/// let p2 = Geometry::new_from_wkt("POINT(10 0)").unwrap();
+///
+/// assert_eq!(azimuth(&p1, &p2), Ok(FRAC_PI_2));
+/// assert!(azimuth(&p1, &p1).is_err());
+/// ```
+pub fn azimuth<A: Geom, B: Geom>(p1: &A, p2: &B) -> GResult<f64> {
+    let (a, b) = (point_xy(p1)?, point_xy(p2)?);
+    if a == b {
+        return Err(Error::GenericError(
+            "azimuth is undefined between equal points".to_owned(),
+        ));
+    }
+    Ok(xy_azimuth(a, b))
+}
+
+pub(crate) fn segment_bearings<G: Geom>(line: &G) -> GResult<Vec<f64>> {
+    match line.geometry_type() {
+        GeometryTypes::LineString | GeometryTypes::LinearRing => {}
+        t => {
+            return Err(Error::GenericError(format!(
+                "Geometry must be a LineString, got a {t:?}"
+            )))
+        }
+    }
+    if line.is_empty()? {
+        return Ok(Vec::new());
+    }
+    let coords = line.get_coord_seq()?;
+    let xy = (0..coords.size()?)
+        .map(|i| Ok((coords.get_x(i)?, coords.get_y(i)?)))
+        .collect::<GResult<Vec<_>>>()?;
+    Ok(xy
+        .windows(2)
+        .map(|w| {
+            if w[0] == w[1] {
+                f64::NAN
+            } else {
+                xy_azimuth(w[0], w[1])
+            }
+        })
+        .collect())
+}
+
+pub(crate) fn destination<G: Geom>(point: &G, bearing: f64, distance: f64) -> GResult<Geometry> {
+    let (x, y) = point_xy(point)?;
+    create_point_with_context(
+        point.clone_context(),
+        x + distance * bearing.sin(),
+        y + distance * bearing.cos(),
+    )
+}
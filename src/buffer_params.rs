@@ -8,6 +8,33 @@ use geos_sys::*;
 use std::sync::Arc;
 
 /// Contains the parameters which describe how a [Geometry](crate::Geometry) buffer should be constructed using [buffer_with_params](crate::Geom::buffer_with_params)
+///
+/// # Example
+///
+/// ```
+/// use geos::{BufferParams, CapStyle, Geom, Geometry, JoinStyle};
+///
+/// let road = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)").unwrap();
+/// let params = BufferParams::builder()
+///     .end_cap_style(CapStyle::Flat)
+///     .join_style(JoinStyle::Mitre)
+///     .mitre_limit(2.)
+///     .build()
+///     .expect("failed to create BufferParams");
+///
+/// let buffer = road.buffer_with_params(1., &params).expect("buffer_with_params failed");
+/// // Flat ends and a square corner.
+/// assert!((buffer.area().unwrap() - 40.).abs() < 1e-9);
+///
+/// // Only on the left side of the line.
+/// let params = BufferParams::builder()
+///     .single_sided(true)
+///     .join_style(JoinStyle::Mitre)
+///     .build()
+///     .expect("failed to create BufferParams");
+/// let buffer = road.buffer_with_params(1., &params).expect("buffer_with_params failed");
+/// assert!((buffer.area().unwrap() - 19.).abs() < 1e-9);
+/// ```
 pub struct BufferParams {
     ptr: PtrWrap<*mut GEOSBufferParams>,
     context: Arc<ContextHandle>,
@@ -28,6 +55,11 @@ impl BufferParams {
         match ContextHandle::init_e(Some("BufferParams::new")) {
             Ok(context) => unsafe {
                 let ptr = GEOSBufferParams_create_r(context.as_raw());
+                if ptr.is_null() {
+                    return Err(Error::NoConstructionFromNullPtr(
+                        "BufferParams::new".to_owned(),
+                    ));
+                }
                 Ok(BufferParams {
                     ptr: PtrWrap(ptr),
                     context: Arc::new(context),
@@ -214,26 +246,32 @@ impl ContextHandling for BufferParams {
 }
 
 impl BufferParamsBuilder {
+    /// See [`BufferParams::set_end_cap_style`].
     pub fn end_cap_style(mut self, style: CapStyle) -> BufferParamsBuilder {
         self.end_cap_style = Some(style);
         self
     }
+    /// See [`BufferParams::set_join_style`].
     pub fn join_style(mut self, style: JoinStyle) -> BufferParamsBuilder {
         self.join_style = Some(style);
         self
     }
+    /// See [`BufferParams::set_mitre_limit`].
     pub fn mitre_limit(mut self, limit: f64) -> BufferParamsBuilder {
         self.mitre_limit = Some(limit);
         self
     }
+    /// See [`BufferParams::set_quadrant_segments`].
     pub fn quadrant_segments(mut self, quadsegs: i32) -> BufferParamsBuilder {
         self.quadrant_segments = Some(quadsegs);
         self
     }
+    /// See [`BufferParams::set_single_sided`].
     pub fn single_sided(mut self, is_single_sided: bool) -> BufferParamsBuilder {
         self.single_sided = Some(is_single_sided);
         self
     }
+    /// Creates the [`BufferParams`], the options which weren't set keeping their default value.
     pub fn build(self) -> GResult<BufferParams> {
         let mut params = BufferParams::new()?;
         if let Some(style) = self.end_cap_style {
@@ -0,0 +1,46 @@
+use crate::error::{Error, GResult};
+use crate::{Geom, GeometryTypes};
+use std::f64::consts::PI;
+
+/// Shape compactness measures of a polygon, as returned by [`Geom::compactness`]. They are all
+/// between `0` (not compact at all) and `1` (a circle for the first three, any convex polygon
+/// for `convexity`).
+///
+/// Available using the `v3_8_0` feature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompactnessMetrics {
+    /// `4πA / P²`, comparing the area to the one of a circle with the same perimeter.
+    pub polsby_popper: f64,
+    /// Ratio of the perimeter of a circle with the same area to the perimeter of the polygon.
+    pub schwartzberg: f64,
+    /// Ratio of the area to the one of the minimum bounding circle.
+    pub reock: f64,
+    /// Ratio of the area to the one of the convex hull.
+    pub convexity: f64,
+}
+
+pub(crate) fn compactness<G: Geom>(g: &G) -> GResult<CompactnessMetrics> {
+    match g.geometry_type() {
+        GeometryTypes::Polygon | GeometryTypes::MultiPolygon => {}
+        t => {
+            return Err(Error::GenericError(format!(
+                "Geometry must be a Polygon or a MultiPolygon, got a {t:?}"
+            )))
+        }
+    }
+    let area = g.area()?;
+    if area <= 0. {
+        return Err(Error::GenericError(
+            "cannot compute the compactness of a geometry without area".to_owned(),
+        ));
+    }
+    let perimeter = g.length()?;
+    let (_, circle) = g.minimum_bounding_circle()?;
+    let polsby_popper = 4. * PI * area / (perimeter * perimeter);
+    Ok(CompactnessMetrics {
+        polsby_popper,
+        schwartzberg: polsby_popper.sqrt(),
+        reock: area / (PI * circle.radius * circle.radius),
+        convexity: area / g.convex_hull()?.area()?,
+    })
+}
@@ -9,6 +9,82 @@ use std::slice;
 use std::sync::Mutex;
 
 pub type HandlerCallback = Box<dyn Fn(&str) + Send + Sync>;
+pub type DetailedHandlerCallback = Box<dyn Fn(&GeosErrorDetail) + Send + Sync>;
+
+/// Category of a GEOS error message, parsed from the name of the GEOS exception.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum GeosErrorKind {
+    /// A robustness issue, which can often be worked around by reducing the precision of the
+    /// inputs.
+    TopologyException,
+    IllegalArgument,
+    Unsupported,
+    OutOfMemory,
+    Parse,
+    Other,
+}
+
+/// A GEOS error message, along with its category and the coordinate it mentions (if any).
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeosErrorDetail {
+    pub kind: GeosErrorKind,
+    pub message: String,
+    pub coordinate: Option<(f64, f64)>,
+}
+
+impl GeosErrorDetail {
+    /// Parses a message as received by the error message handler.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{GeosErrorDetail, GeosErrorKind};
+    ///
+    /// let detail = GeosErrorDetail::parse(
+    ///     "TopologyException: Input geom 1 is invalid: Self-intersection at 0.5 2",
+    /// );
+    /// assert_eq!(detail.kind, GeosErrorKind::TopologyException);
+    /// assert_eq!(detail.coordinate, Some((0.5, 2.)));
+    ///
+    /// let detail = GeosErrorDetail::parse("IllegalArgumentException: Invalid number of points");
+    /// assert_eq!(detail.kind, GeosErrorKind::IllegalArgument);
+    /// assert_eq!(detail.coordinate, None);
+    /// ```
+    pub fn parse(message: &str) -> GeosErrorDetail {
+        let kind = if message.starts_with("TopologyException") {
+            GeosErrorKind::TopologyException
+        } else if message.starts_with("IllegalArgumentException") {
+            GeosErrorKind::IllegalArgument
+        } else if message.starts_with("UnsupportedOperationException") {
+            GeosErrorKind::Unsupported
+        } else if message.starts_with("ParseException") {
+            GeosErrorKind::Parse
+        } else if message.contains("bad_alloc") || message.contains("out of memory") {
+            GeosErrorKind::OutOfMemory
+        } else {
+            GeosErrorKind::Other
+        };
+        GeosErrorDetail {
+            kind,
+            message: message.to_owned(),
+            coordinate: parse_coordinate(message),
+        }
+    }
+}
+
+/// Returns the last pair of consecutive numbers of `message`, which is where GEOS puts the
+/// location of the problem (`... at 1 2`, `... [1 2]`).
+fn parse_coordinate(message: &str) -> Option<(f64, f64)> {
+    let numbers = message
+        .split(|c: char| c.is_whitespace() || "[](),".contains(c))
+        .filter(|token| !token.is_empty())
+        .map(|token| token.parse::<f64>().ok())
+        .collect::<Vec<_>>();
+    numbers.windows(2).rev().find_map(|w| match (w[0], w[1]) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    })
+}
 
 macro_rules! set_callbacks {
     ($c_func:ident, $kind:ident, $callback_name:ident, $last:ident) => {
@@ -177,6 +253,30 @@ impl ContextHandle {
         }
     }
 
+    /// Same as [`ContextHandle::set_error_message_handler`] but the callback receives the
+    /// parsed message.
+    ///
+    /// Passing [`None`] as parameter will unset this callback.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{ContextHandle, GeosErrorKind};
+    ///
+    /// let context_handle = ContextHandle::init().expect("invalid init");
+    ///
+    /// context_handle.set_error_detail_handler(Some(Box::new(|detail| {
+    ///     if detail.kind == GeosErrorKind::TopologyException {
+    ///         println!("topology error at {:?}", detail.coordinate);
+    ///     }
+    /// })));
+    /// ```
+    pub fn set_error_detail_handler(&self, ef: Option<DetailedHandlerCallback>) {
+        self.set_error_message_handler(ef.map(|ef| {
+            Box::new(move |message: &str| ef(&GeosErrorDetail::parse(message))) as HandlerCallback
+        }));
+    }
+
     /// Returns the last error encountered.
     ///
     /// Please note that calling this function will remove the current last error!
@@ -201,6 +301,26 @@ impl ContextHandle {
         }
     }
 
+    /// Same as [`ContextHandle::get_last_error`] but returns the parsed message.
+    ///
+    /// Please note that calling this function will remove the current last error!
+    ///
+    /// ```
+    /// use geos::{ContextHandle, GeosErrorKind};
+    ///
+    /// let context_handle = ContextHandle::init().expect("invalid init");
+    /// // make some functions calls...
+    /// if let Some(last_error) = context_handle.get_last_error_detail() {
+    ///     if last_error.kind == GeosErrorKind::TopologyException {
+    ///         println!("Retrying with a lower precision...");
+    ///     }
+    /// }
+    /// ```
+    pub fn get_last_error_detail(&self) -> Option<GeosErrorDetail> {
+        self.get_last_error()
+            .map(|message| GeosErrorDetail::parse(&message))
+    }
+
     /// Returns the last notification encountered.
     ///
     /// Please note that calling this function will remove the current last notification!
@@ -0,0 +1,295 @@
+//! Operations on polygonal coverages: sets of polygons which shouldn't overlap nor leave gaps
+//! between them, such as administrative boundaries or parcels.
+use crate::error::{Error, GResult};
+use crate::functions::union_all;
+use crate::layer::{build_tree, intersecting};
+#[cfg(any(feature = "v3_12_0", feature = "dox"))]
+use crate::{AsRaw, ContextHandling};
+use crate::{Geom, Geometry, GeometryTypes};
+#[cfg(any(feature = "v3_12_0", feature = "dox"))]
+use geos_sys::*;
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+use std::borrow::Borrow;
+
+/// Defects of a coverage, as returned by [`find_defects`].
+#[derive(Clone, Default)]
+pub struct Defects {
+    /// Narrow holes enclosed by the coverage.
+    pub gaps: Vec<Geometry>,
+    /// Overlapping areas, along with the indices of the two overlapping polygons (the first one
+    /// being the smallest).
+    pub overlaps: Vec<(usize, usize, Geometry)>,
+}
+
+impl Defects {
+    pub fn is_empty(&self) -> bool {
+        self.gaps.is_empty() && self.overlaps.is_empty()
+    }
+}
+
+fn push_polygons<G: Geom>(g: &G, out: &mut Vec<Geometry>) -> GResult<()> {
+    match g.geometry_type() {
+        GeometryTypes::Polygon if !g.is_empty()? && g.area()? > 0. => out.push(Geom::clone(g)),
+        GeometryTypes::MultiPolygon | GeometryTypes::GeometryCollection => {
+            for i in 0..g.get_num_geometries()? {
+                push_polygons(&g.get_geometry_n(i)?, out)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Returns the polygonal parts of `g` with a non-zero area, `None` if there is none.
+fn polygonal_part<G: Geom>(g: &G) -> GResult<Option<Geometry>> {
+    let mut polygons = Vec::new();
+    push_polygons(g, &mut polygons)?;
+    match polygons.len() {
+        0 => Ok(None),
+        1 => Ok(polygons.pop()),
+        _ => Geometry::create_multipolygon(polygons).map(Some),
+    }
+}
+
+/// Returns the holes of `union` narrower than `gap_tolerance`.
+fn narrow_holes(union: &Geometry, gap_tolerance: f64) -> GResult<Vec<Geometry>> {
+    let mut polygons = Vec::new();
+    push_polygons(union, &mut polygons)?;
+    let mut gaps = Vec::new();
+    for polygon in polygons {
+        for i in 0..polygon.get_num_interior_rings()? {
+            let ring = Geom::clone(&polygon.get_interior_ring_n(i as _)?);
+            let hole = Geometry::create_polygon(ring, Vec::new())?;
+            if hole.buffer(-gap_tolerance / 2., 8)?.is_empty()? {
+                gaps.push(hole);
+            }
+        }
+    }
+    Ok(gaps)
+}
+
+/// Result of [`validate`].
+///
+/// Available using the `v3_12_0` feature.
+#[cfg(any(feature = "v3_12_0", feature = "dox"))]
+#[derive(Clone)]
+pub struct CoverageValidity {
+    pub is_valid: bool,
+    /// For each input polygon, the linework of its edges which are invalid in the coverage (an
+    /// empty geometry if there is none).
+    pub invalid_edges: Vec<Geometry>,
+}
+
+/// Runs `GEOSCoverageIsValid_r` on `polygons`, returning the invalid edges if `with_edges` is
+/// `true`.
+#[cfg(any(feature = "v3_12_0", feature = "dox"))]
+fn check_coverage<T: Borrow<Geometry>>(
+    polygons: &[T],
+    gap_width: f64,
+    with_edges: bool,
+) -> GResult<(bool, Option<Geometry>)> {
+    let collection = Geometry::create_geometry_collection(
+        polygons.iter().map(|g| Geom::clone(g.borrow())).collect(),
+    )?;
+    unsafe {
+        let mut output = std::ptr::null_mut();
+        let ret = GEOSCoverageIsValid_r(
+            collection.get_raw_context(),
+            collection.as_raw(),
+            gap_width,
+            if with_edges {
+                &mut output
+            } else {
+                std::ptr::null_mut()
+            },
+        );
+        let is_valid = match ret {
+            0 => false,
+            1 => true,
+            _ => {
+                return Err(Error::GenericError(
+                    "GEOSCoverageIsValid_r failed".to_owned(),
+                ))
+            }
+        };
+        let edges = if output.is_null() {
+            None
+        } else {
+            Some(Geometry::new_from_raw(
+                output,
+                collection.clone_context(),
+                "coverage_is_valid",
+            )?)
+        };
+        Ok((is_valid, edges))
+    }
+}
+
+/// Checks that `polygons` form a valid coverage: they don't overlap, adjacent polygons share
+/// the same vertices along their common edges, and they don't enclose gaps narrower than
+/// `gap_width` (use `0` to ignore gaps). Unlike [`find_defects`], it returns the invalid edges
+/// of each polygon, which is what has to be fixed before a [`union`] or a [`simplify`].
+///
+/// Available using the `v3_12_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{coverage, Geom, Geometry};
+///
+/// let polygons = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((3.9 0, 6 0, 6 2, 3.9 2, 3.9 0))").unwrap(),
+/// ];
+///
+/// let validity = coverage::validate(&polygons[..2], 0.).expect("validate failed");
+/// assert!(validity.is_valid);
+///
+/// let validity = coverage::validate(&polygons, 0.).expect("validate failed");
+/// assert!(!validity.is_valid);
+/// assert_eq!(validity.invalid_edges[0].is_empty(), Ok(true));
+/// assert_eq!(validity.invalid_edges[2].is_empty(), Ok(false));
+/// ```
+#[cfg(any(feature = "v3_12_0", feature = "dox"))]
+pub fn validate<T: Borrow<Geometry>>(polygons: &[T], gap_width: f64) -> GResult<CoverageValidity> {
+    let (is_valid, edges) = check_coverage(polygons, gap_width, true)?;
+    let mut invalid_edges = Vec::with_capacity(polygons.len());
+    if let Some(edges) = edges {
+        for i in 0..edges.get_num_geometries()? {
+            invalid_edges.push(Geom::clone(&edges.get_geometry_n(i)?));
+        }
+    }
+    if invalid_edges.len() != polygons.len() {
+        return Err(Error::GenericError(
+            "GEOSCoverageIsValid_r returned an unexpected number of geometries".to_owned(),
+        ));
+    }
+    Ok(CoverageValidity {
+        is_valid,
+        invalid_edges,
+    })
+}
+
+/// Finds the overlaps between `polygons` and the gaps narrower than `gap_tolerance` they
+/// enclose, so a coverage can be checked before being unioned or simplified.
+///
+/// Pairs of polygons are found using a [`STRtree`](crate::STRtree), and only the overlaps with a
+/// non-zero area are reported. A gap is a hole of the union of `polygons` which disappears when
+/// shrunk by `gap_tolerance / 2`: bigger holes (lakes for instance) are considered intended.
+///
+/// With the `v3_12_0` feature, the coverage is first checked by GEOS, and the (slower) search of
+/// defects is skipped if it is valid.
+///
+/// # Example
+///
+/// ```
+/// use geos::{coverage, Geom, Geometry};
+///
+/// let polygons = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 1, 0 1, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((0 1, 1 1, 1 3, 0 3, 0 1))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((1.1 1, 2 1, 2 3, 0.9 3, 1.1 1))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((1.5 0.5, 2.5 0.5, 2.5 1, 1.5 1, 1.5 0.5))").unwrap(),
+/// ];
+///
+/// let defects = coverage::find_defects(&polygons, 0.5).expect("find_defects failed");
+/// assert_eq!(defects.gaps.len(), 1);
+/// assert_eq!(defects.overlaps.len(), 2);
+/// let (a, b, ref overlap) = defects.overlaps[0];
+/// assert_eq!((a, b), (0, 3));
+/// assert_eq!(overlap.area(), Ok(0.25));
+/// ```
+pub fn find_defects(polygons: &[Geometry], gap_tolerance: f64) -> GResult<Defects> {
+    for polygon in polygons {
+        match polygon.geometry_type() {
+            GeometryTypes::Polygon | GeometryTypes::MultiPolygon => {}
+            t => {
+                return Err(Error::GenericError(format!(
+                    "Geometries must be Polygons or MultiPolygons, got a {t:?}"
+                )))
+            }
+        }
+    }
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    if !polygons.is_empty() && check_coverage(polygons, gap_tolerance, false)?.0 {
+        return Ok(Defects::default());
+    }
+    let tree = build_tree(polygons)?;
+    let mut defects = Defects::default();
+    for (i, polygon) in polygons.iter().enumerate() {
+        for j in intersecting(&tree, polygons, polygon)? {
+            if j <= i {
+                continue;
+            }
+            if let Some(overlap) = polygonal_part(&polygon.intersection(&polygons[j])?)? {
+                defects.overlaps.push((i, j, overlap));
+            }
+        }
+    }
+    if !polygons.is_empty() {
+        defects.gaps = narrow_holes(&union_all(polygons)?, gap_tolerance)?;
+    }
+    Ok(defects)
+}
+
+/// Returns the union of `polygons`, which must form a valid coverage (see [`find_defects`]).
+/// It is much faster than a regular union, see [`Geom::coverage_union`].
+///
+/// Available using the `v3_8_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{coverage, Geom, Geometry};
+///
+/// let polygons = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))").unwrap(),
+/// ];
+///
+/// let union = coverage::union(&polygons).expect("union failed");
+/// assert_eq!(union.area(), Ok(8.));
+/// ```
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+pub fn union<T: Borrow<Geometry>>(polygons: &[T]) -> GResult<Geometry> {
+    let collection = Geometry::create_geometry_collection(
+        polygons.iter().map(|g| Geom::clone(g.borrow())).collect(),
+    )?;
+    collection.coverage_union()
+}
+
+/// Simplifies `polygons`, which must form a valid coverage (see [`validate`]), keeping it
+/// valid: shared edges are simplified the same way, see [`Geom::coverage_simplify`]. The
+/// simplified polygons are returned in the same order.
+///
+/// Available using the `v3_12_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{coverage, Geom, Geometry};
+///
+/// let polygons = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 5, 10.1 5.1, 10 10, 0 10, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((10 0, 20 0, 20 10, 10 10, 10.1 5.1, 10 5, 10 0))").unwrap(),
+/// ];
+///
+/// let simplified = coverage::simplify(&polygons, 1., true).expect("simplify failed");
+/// assert_eq!(simplified.len(), 2);
+/// assert_eq!(simplified[0].intersection(&simplified[1]).unwrap().area(), Ok(0.));
+/// ```
+#[cfg(any(feature = "v3_12_0", feature = "dox"))]
+pub fn simplify<T: Borrow<Geometry>>(
+    polygons: &[T],
+    tolerance: f64,
+    preserve_boundary: bool,
+) -> GResult<Vec<Geometry>> {
+    let collection = Geometry::create_geometry_collection(
+        polygons.iter().map(|g| Geom::clone(g.borrow())).collect(),
+    )?;
+    let simplified = collection.coverage_simplify(tolerance, preserve_boundary)?;
+    (0..simplified.get_num_geometries()?)
+        .map(|i| simplified.get_geometry_n(i).map(|g| Geom::clone(&g)))
+        .collect()
+}
@@ -1,3 +1,19 @@
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+use geos_sys::{
+    GEOSMakeValidMethods, GEOSMakeValidMethods_GEOS_MAKE_VALID_LINEWORK,
+    GEOSMakeValidMethods_GEOS_MAKE_VALID_STRUCTURE,
+};
+#[cfg(any(feature = "v3_11_0", feature = "dox"))]
+use geos_sys::{
+    GEOSPolygonHullParameterModes, GEOSPolygonHullParameterModes_GEOSHULL_PARAM_AREA_RATIO,
+    GEOSPolygonHullParameterModes_GEOSHULL_PARAM_VERTEX_RATIO,
+};
+use geos_sys::{
+    GEOSRelateBoundaryNodeRules, GEOSRelateBoundaryNodeRules_GEOSRELATE_BNR_ENDPOINT,
+    GEOSRelateBoundaryNodeRules_GEOSRELATE_BNR_MOD2,
+    GEOSRelateBoundaryNodeRules_GEOSRELATE_BNR_MONOVALENT_ENDPOINT,
+    GEOSRelateBoundaryNodeRules_GEOSRELATE_BNR_MULTIVALENT_ENDPOINT,
+};
 use libc::{c_int, size_t};
 
 use std::convert::TryFrom;
@@ -184,9 +200,9 @@ impl TryFrom<c_int> for Orientation {
 
     fn try_from(orientation: c_int) -> Result<Self, Self::Error> {
         match orientation {
-            -1 => Ok(Orientation::CounterClockwise),
-            0 => Ok(Orientation::Clockwise),
-            1 => Ok(Orientation::Colinear),
+            -1 => Ok(Orientation::Clockwise),
+            0 => Ok(Orientation::Colinear),
+            1 => Ok(Orientation::CounterClockwise),
             _ => Err("value must be -1, 0 or 1"),
         }
     }
@@ -196,9 +212,9 @@ impl TryFrom<c_int> for Orientation {
 impl Into<c_int> for Orientation {
     fn into(self) -> c_int {
         match self {
-            Orientation::CounterClockwise => -1,
-            Orientation::Clockwise => 0,
-            Orientation::Colinear => 1,
+            Orientation::Clockwise => -1,
+            Orientation::Colinear => 0,
+            Orientation::CounterClockwise => 1,
         }
     }
 }
@@ -234,10 +250,19 @@ impl Into<u32> for Ordinate {
     }
 }
 
+/// How [`Geom::set_precision`](crate::Geom::set_precision) reduces the precision of a geometry.
+///
+/// Available using the `v3_6_0` feature.
 #[cfg(any(feature = "v3_6_0", feature = "dox"))]
-#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Precision {
+    /// The result is kept valid: the geometry is snap-rounded, and the components collapsing
+    /// to a lower dimension are removed.
+    #[default]
+    ValidOutput,
+    /// Only the coordinates are rounded, the topology isn't fixed so the result may be invalid.
     NoTopo,
+    /// Same as `ValidOutput`, but collapsed components are kept (with their lower dimension).
     KeepCollapsed,
 }
 
@@ -247,6 +272,7 @@ impl TryFrom<c_int> for Precision {
 
     fn try_from(order: c_int) -> Result<Self, Self::Error> {
         match order {
+            0 => Ok(Precision::ValidOutput),
             1 => Ok(Precision::NoTopo),
             2 => Ok(Precision::KeepCollapsed),
             _ => Err("Unknown precision type"),
@@ -259,12 +285,33 @@ impl TryFrom<c_int> for Precision {
 impl Into<c_int> for Precision {
     fn into(self) -> c_int {
         match self {
-            Precision::NoTopo => 0,
-            Precision::KeepCollapsed => 1,
+            Precision::ValidOutput => 0,
+            Precision::NoTopo => 1,
+            Precision::KeepCollapsed => 2,
         }
     }
 }
 
+/// How two geometries are compared by [`Geom::equals_with`](crate::Geom::equals_with).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EqualityMode {
+    /// Geometries cover the same points, whatever their structure ([`Geom::equals`](crate::Geom::equals)).
+    Topological,
+    /// Geometries have the same structure and their coordinates are at most `tolerance` apart
+    /// ([`Geom::equals_exact`](crate::Geom::equals_exact)).
+    Exact { tolerance: f64 },
+    /// Same as `Exact` with a zero tolerance, once both geometries are
+    /// [normalized](crate::Geometry::normalize): the order of the vertices of a ring or of the
+    /// parts of a collection doesn't matter.
+    Normalized,
+    /// Geometries have the same structure, coordinates (including Z and M, `NaN` being equal to
+    /// `NaN`) and SRID.
+    ///
+    /// Available using the `v3_12_0` feature.
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    Identical,
+}
+
 /// Join styles for a [`Geometry`](crate::Geometry) [buffer](crate::Geom::buffer_with_style) operation
 #[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
 pub enum JoinStyle {
@@ -336,3 +383,132 @@ impl Into<c_int> for CapStyle {
         }
     }
 }
+
+/// Side of a line, relative to its direction.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// Part of a geometry, used to read an [`IntersectionMatrix`](crate::IntersectionMatrix).
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+impl Location {
+    /// Index of the row (or column) of the location in a DE-9IM matrix.
+    pub(crate) fn index(self) -> usize {
+        match self {
+            Location::Interior => 0,
+            Location::Boundary => 1,
+            Location::Exterior => 2,
+        }
+    }
+}
+
+/// Rule deciding which endpoints of lines are part of their boundary, used by
+/// [`Geom::relate_with_rule`](crate::Geom::relate_with_rule).
+#[derive(Debug, Default, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum BoundaryNodeRule {
+    /// Endpoints shared by an odd number of lines are in the boundary, the others are in the
+    /// interior. It is the OGC rule, used by [`Geom::relate`](crate::Geom::relate).
+    #[default]
+    Mod2,
+    /// All the endpoints are in the boundary.
+    EndPoint,
+    /// Endpoints shared by several lines are in the boundary.
+    MultivalentEndPoint,
+    /// Endpoints belonging to a single line are in the boundary.
+    MonovalentEndPoint,
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<GEOSRelateBoundaryNodeRules> for BoundaryNodeRule {
+    fn into(self) -> GEOSRelateBoundaryNodeRules {
+        match self {
+            BoundaryNodeRule::Mod2 => GEOSRelateBoundaryNodeRules_GEOSRELATE_BNR_MOD2,
+            BoundaryNodeRule::EndPoint => GEOSRelateBoundaryNodeRules_GEOSRELATE_BNR_ENDPOINT,
+            BoundaryNodeRule::MultivalentEndPoint => {
+                GEOSRelateBoundaryNodeRules_GEOSRELATE_BNR_MULTIVALENT_ENDPOINT
+            }
+            BoundaryNodeRule::MonovalentEndPoint => {
+                GEOSRelateBoundaryNodeRules_GEOSRELATE_BNR_MONOVALENT_ENDPOINT
+            }
+        }
+    }
+}
+
+/// Algorithms of [`Geom::make_valid_with_params`](crate::Geom::make_valid_with_params).
+///
+/// Available using the `v3_10_0` feature.
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum MakeValidMethod {
+    /// Combines all rings into a set of noded lines, then extracts valid polygons from them.
+    /// It is the algorithm of [`Geom::make_valid`](crate::Geom::make_valid).
+    #[default]
+    Linework,
+    /// Makes the rings valid separately, then merges them: the exterior rings are unioned and
+    /// the holes are subtracted from them.
+    Structure,
+}
+
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+impl TryFrom<GEOSMakeValidMethods> for MakeValidMethod {
+    type Error = &'static str;
+
+    fn try_from(method: GEOSMakeValidMethods) -> Result<Self, Self::Error> {
+        if method == GEOSMakeValidMethods_GEOS_MAKE_VALID_LINEWORK {
+            Ok(MakeValidMethod::Linework)
+        } else if method == GEOSMakeValidMethods_GEOS_MAKE_VALID_STRUCTURE {
+            Ok(MakeValidMethod::Structure)
+        } else {
+            Err("Unknown make valid method")
+        }
+    }
+}
+
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+#[allow(clippy::from_over_into)]
+impl Into<GEOSMakeValidMethods> for MakeValidMethod {
+    fn into(self) -> GEOSMakeValidMethods {
+        match self {
+            MakeValidMethod::Linework => GEOSMakeValidMethods_GEOS_MAKE_VALID_LINEWORK,
+            MakeValidMethod::Structure => GEOSMakeValidMethods_GEOS_MAKE_VALID_STRUCTURE,
+        }
+    }
+}
+
+/// Meaning of the parameter of
+/// [`Geom::polygon_hull_simplify_mode`](crate::Geom::polygon_hull_simplify_mode).
+///
+/// Available using the `v3_11_0` feature.
+#[cfg(any(feature = "v3_11_0", feature = "dox"))]
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum PolygonHullParameterMode {
+    /// Fraction of the vertices of the input to keep.
+    #[default]
+    VertexRatio,
+    /// Maximum difference between the area of the hull and the area of the input, relative to
+    /// the area of the input: `0` keeps the input unchanged.
+    AreaRatio,
+}
+
+#[cfg(any(feature = "v3_11_0", feature = "dox"))]
+#[allow(clippy::from_over_into)]
+impl Into<GEOSPolygonHullParameterModes> for PolygonHullParameterMode {
+    fn into(self) -> GEOSPolygonHullParameterModes {
+        match self {
+            PolygonHullParameterMode::VertexRatio => {
+                GEOSPolygonHullParameterModes_GEOSHULL_PARAM_VERTEX_RATIO
+            }
+            PolygonHullParameterMode::AreaRatio => {
+                GEOSPolygonHullParameterModes_GEOSHULL_PARAM_AREA_RATIO
+            }
+        }
+    }
+}
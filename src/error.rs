@@ -1,3 +1,4 @@
+use crate::LimitKind;
 use std::{self, fmt};
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone)]
@@ -11,6 +12,8 @@ pub enum Error {
     GenericError(String),
     VoronoiError(String),
     NormalizeError(String),
+    /// A [`ReaderLimits`](crate::ReaderLimits) limit was exceeded, with its maximum value.
+    LimitExceeded(LimitKind, usize),
 }
 
 impl std::error::Error for Error {}
@@ -33,6 +36,9 @@ impl fmt::Display for Error {
             Error::ConversionError(ref s) => write!(f, "impossible to convert geometry, {s}"),
             Error::GenericError(ref s) => write!(f, "generic error: {s}"),
             Error::VoronoiError(ref s) => write!(f, "voronoi error: {s}"),
+            Error::LimitExceeded(kind, max) => {
+                write!(f, "limit exceeded: {kind} is greater than {max}")
+            }
         }
     }
 }
@@ -49,6 +55,7 @@ pub enum PredicateType {
     Within,
     Equals,
     EqualsExact,
+    EqualsIdentical,
     Covers,
     CoveredBy,
     Contains,
@@ -65,7 +72,14 @@ pub enum PredicateType {
     PreparedOverlaps,
     PreparedTouches,
     PreparedWithin,
+    PreparedDistance,
+    PreparedDistanceWithin,
+    PreparedContainsXY,
+    PreparedIntersectsXY,
     Normalize,
+    DistanceWithin,
+    IsValidDetail,
+    RelatePattern,
 }
 
 impl std::fmt::Display for PredicateType {
@@ -1,5 +1,5 @@
 use crate::error::{Error, GResult};
-use crate::{CoordDimensions, CoordSeq, Geometry as GGeometry};
+use crate::{CoordDimensions, CoordSeq, Geometry as GGeometry, ReaderLimits};
 use geojson::{Geometry, Value};
 
 use std::convert::{TryFrom, TryInto};
@@ -124,6 +124,38 @@ impl TryFrom<Geometry> for GGeometry {
     }
 }
 
+/// Parses a GeoJSON geometry, checking the given `limits` before creating the GEOS geometry.
+/// Returns [`Error::LimitExceeded`] if one of them is exceeded. Useful to parse untrusted input.
+///
+/// # Example
+///
+/// ```
+/// use geos::from_geojson::parse_with_limits;
+/// use geos::{Error, Geom, LimitKind, ReaderLimits};
+///
+/// let limits = ReaderLimits {
+///     max_depth: Some(1),
+///     ..Default::default()
+/// };
+/// let point = parse_with_limits(r#"{"type": "Point", "coordinates": [1, 2]}"#, &limits)
+///     .expect("invalid GeoJSON");
+/// assert_eq!(point.to_wkt_precision(0), Ok("POINT (1 2)".to_owned()));
+///
+/// let multi_point = r#"{"type": "MultiPoint", "coordinates": [[1, 2], [3, 4]]}"#;
+/// assert_eq!(
+///     parse_with_limits(multi_point, &limits).err(),
+///     Some(Error::LimitExceeded(LimitKind::Depth, 1)),
+/// );
+/// ```
+pub fn parse_with_limits(json: &str, limits: &ReaderLimits) -> GResult<GGeometry> {
+    limits.check_bytes(json.len())?;
+    let geometry = json
+        .parse::<Geometry>()
+        .map_err(|e| Error::ConversionError(format!("invalid GeoJSON geometry: {e}")))?;
+    limits.check_geojson(&geometry)?;
+    GGeometry::try_from(&geometry)
+}
+
 #[cfg(test)]
 mod test {
     use crate::{Geom, Geometry as GGeometry};
@@ -4,7 +4,7 @@ use crate::error::{Error, GResult, PredicateType};
 use crate::geometry::Geometry;
 use crate::{AsRawMut, ContextHandle, ContextHandling, Geom};
 use geos_sys::*;
-use std::convert::TryFrom;
+use std::borrow::Borrow;
 use std::ffi::CStr;
 use std::os::raw::c_char;
 use std::str;
@@ -44,18 +44,135 @@ pub(crate) unsafe fn managed_string(
     s
 }
 
-#[allow(dead_code)]
-pub fn clip_by_rect<G: Geom>(
+/// Returns `None` if `g` is valid, otherwise the reason why it isn't along with the location of
+/// the problem (if GEOS provides one).
+pub(crate) fn is_valid_detail<G: Geom>(
     g: &G,
+    flags: i32,
+) -> GResult<Option<(String, Option<Geometry>)>> {
+    unsafe {
+        let context = g.clone_context();
+        let mut reason = std::ptr::null_mut();
+        let mut location = std::ptr::null_mut();
+        let ret = GEOSisValidDetail_r(
+            context.as_raw(),
+            g.as_raw(),
+            flags,
+            &mut reason,
+            &mut location,
+        );
+        match ret {
+            1 => Ok(None),
+            0 => {
+                let reason = managed_string(reason, &context, "is_valid_detail")?;
+                let location = if location.is_null() {
+                    None
+                } else {
+                    Some(Geometry::new_from_raw(
+                        location,
+                        context,
+                        "is_valid_detail",
+                    )?)
+                };
+                Ok(Some((reason, location)))
+            }
+            _ => Err(Error::GeosFunctionError(
+                PredicateType::IsValidDetail,
+                ret as _,
+            )),
+        }
+    }
+}
+
+/// Returns the `(xmin, ymin, xmax, ymax)` bounds of `g`, `None` if `g` is empty.
+pub(crate) fn bounds<G: Geom>(g: &G) -> GResult<Option<(f64, f64, f64, f64)>> {
+    let envelope = g.envelope()?;
+    if envelope.is_empty()? {
+        return Ok(None);
+    }
+    let coords = match envelope.geometry_type() {
+        GeometryTypes::Point => envelope.get_coord_seq()?,
+        _ => envelope.get_exterior_ring()?.get_coord_seq()?,
+    };
+    let mut res = (
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+    );
+    for i in 0..coords.size()? {
+        let (x, y) = (coords.get_x(i)?, coords.get_y(i)?);
+        res = (res.0.min(x), res.1.min(y), res.2.max(x), res.3.max(y));
+    }
+    Ok(Some(res))
+}
+
+/// Creates a polygon without holes from the `exterior` coordinates (which must be closed) using
+/// the given `context`.
+pub(crate) fn create_polygon_with_context(
+    context: Arc<ContextHandle>,
+    exterior: &[(f64, f64)],
+) -> GResult<Geometry> {
+    unsafe {
+        let coords = GEOSCoordSeq_create_r(context.as_raw(), exterior.len() as _, 2);
+        if coords.is_null() {
+            return Err(Error::NoConstructionFromNullPtr(
+                "create_polygon_with_context".to_owned(),
+            ));
+        }
+        for (i, &(x, y)) in exterior.iter().enumerate() {
+            GEOSCoordSeq_setX_r(context.as_raw(), coords, i as _, x);
+            GEOSCoordSeq_setY_r(context.as_raw(), coords, i as _, y);
+        }
+        let ring = GEOSGeom_createLinearRing_r(context.as_raw(), coords);
+        if ring.is_null() {
+            return Err(Error::NoConstructionFromNullPtr(
+                "create_polygon_with_context".to_owned(),
+            ));
+        }
+        let ptr = GEOSGeom_createPolygon_r(context.as_raw(), ring, std::ptr::null_mut(), 0);
+        Geometry::new_from_raw(ptr, context, "create_polygon_with_context")
+    }
+}
+
+/// Creates the rectangle polygon with the given bounds using the given `context`.
+pub(crate) fn rectangle(
+    context: Arc<ContextHandle>,
     xmin: f64,
     ymin: f64,
     xmax: f64,
     ymax: f64,
+) -> GResult<Geometry> {
+    create_polygon_with_context(
+        context,
+        &[
+            (xmin, ymin),
+            (xmax, ymin),
+            (xmax, ymax),
+            (xmin, ymax),
+            (xmin, ymin),
+        ],
+    )
+}
+
+/// Creates a 2D point using the given `context`, without creating a new one as
+/// [`CoordSeq::new_from_vec`](crate::CoordSeq::new_from_vec) does.
+pub(crate) fn create_point_with_context(
+    context: Arc<ContextHandle>,
+    x: f64,
+    y: f64,
 ) -> GResult<Geometry> {
     unsafe {
-        let context = g.clone_context();
-        let ptr = GEOSClipByRect_r(context.as_raw(), g.as_raw(), xmin, ymin, xmax, ymax);
-        Geometry::new_from_raw(ptr, context, "clip_by_rect")
+        let coords = GEOSCoordSeq_create_r(context.as_raw(), 1, 2);
+        if coords.is_null() {
+            return Err(Error::NoConstructionFromNullPtr(
+                "create_point_with_context".to_owned(),
+            ));
+        }
+        GEOSCoordSeq_setX_r(context.as_raw(), coords, 0, x);
+        GEOSCoordSeq_setY_r(context.as_raw(), coords, 0, y);
+        let ptr = GEOSGeom_createPoint_r(context.as_raw(), coords);
+        Geometry::new_from_raw(ptr, context, "create_point_with_context")
     }
 }
 
@@ -117,78 +234,30 @@ pub(crate) fn create_multi_geom(
     res
 }
 
-pub fn orientation_index(
-    ax: f64,
-    ay: f64,
-    bx: f64,
-    by: f64,
-    px: f64,
-    py: f64,
-) -> GResult<Orientation> {
-    match ContextHandle::init() {
-        Ok(context) => unsafe {
-            match Orientation::try_from(GEOSOrientationIndex_r(
-                context.as_raw(),
-                ax,
-                ay,
-                bx,
-                by,
-                px,
-                py,
-            )) {
-                Ok(o) => Ok(o),
-                Err(e) => Err(Error::GenericError(e.to_owned())),
-            }
-        },
-        Err(e) => Err(e),
-    }
-}
-
-/// Returns [`None`] if the segments don't intersect, otherwise returns `Some(x_pos, y_pos)`.
+/// Returns the union of `geometries`, an empty `GeometryCollection` if there is none.
 ///
-/// Available using the `v3_7_0` feature.
-#[cfg(any(feature = "v3_7_0", feature = "dox"))]
-#[allow(clippy::too_many_arguments)]
-pub fn segment_intersection(
-    ax0: f64,
-    ay0: f64,
-    ax1: f64,
-    ay1: f64,
-    bx0: f64,
-    by0: f64,
-    bx1: f64,
-    by1: f64,
-) -> GResult<Option<(f64, f64)>> {
-    match ContextHandle::init() {
-        Ok(context) => unsafe {
-            let mut cx = 0.;
-            let mut cy = 0.;
-
-            let ret = GEOSSegmentIntersection_r(
-                context.as_raw(),
-                ax0,
-                ay0,
-                ax1,
-                ay1,
-                bx0,
-                by0,
-                bx1,
-                by1,
-                &mut cx,
-                &mut cy,
-            );
-            if ret == -1 {
-                Ok(None)
-            } else if ret == 0 {
-                Ok(Some((cx, cy)))
-            } else {
-                Err(Error::GenericError(
-                    "GEOSSegmentIntersection_r failed".to_owned(),
-                ))
-            }
-        },
-        Err(e) => Err(e),
-    }
+/// It is the same as building a `GeometryCollection` of `geometries` and calling
+/// [`Geom::unary_union`] on it, which is much faster than merging the geometries one by one
+/// with [`Geom::union`].
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry};
+///
+/// let polygons = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((1 0, 3 0, 3 2, 1 2, 1 0))").unwrap(),
+/// ];
+///
+/// let union = geos::union_all(&polygons).expect("union_all failed");
+/// assert_eq!(union.area(), Ok(6.));
+/// ```
+pub fn union_all<T: Borrow<Geometry>>(geometries: &[T]) -> GResult<Geometry> {
+    Geometry::create_geometry_collection(
+        geometries.iter().map(|g| Geom::clone(g.borrow())).collect(),
+    )?
+    .unary_union()
 }
 
 #[cfg(test)]
@@ -0,0 +1,160 @@
+use crate::error::{Error, GResult};
+use crate::functions::rectangle;
+use crate::{ContextHandling, Geom, GeometryTypes, PreparedGeometry};
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const MAX_PRECISION: usize = 12;
+
+fn check_precision(precision: usize) -> GResult<()> {
+    if precision == 0 || precision > MAX_PRECISION {
+        return Err(Error::GenericError(format!(
+            "geohash precision must be between 1 and {MAX_PRECISION}, got {precision}"
+        )));
+    }
+    Ok(())
+}
+
+/// Encodes the (`lon`, `lat`) position into a geohash of `precision` characters.
+pub(crate) fn encode(lon: f64, lat: f64, precision: usize) -> GResult<String> {
+    check_precision(precision)?;
+    if !(-180. ..=180.).contains(&lon) || !(-90. ..=90.).contains(&lat) {
+        return Err(Error::GenericError(format!(
+            "invalid position for a geohash: ({lon}, {lat})"
+        )));
+    }
+    let (mut lon_range, mut lat_range) = ((-180., 180.), (-90., 90.));
+    let mut hash = String::with_capacity(precision);
+    let mut is_lon = true;
+    while hash.len() < precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (range, value) = if is_lon {
+                (&mut lon_range, lon)
+            } else {
+                (&mut lat_range, lat)
+            };
+            let middle = (range.0 + range.1) / 2.;
+            index <<= 1;
+            if value >= middle {
+                index |= 1;
+                range.0 = middle;
+            } else {
+                range.1 = middle;
+            }
+            is_lon = !is_lon;
+        }
+        hash.push(BASE32[index] as char);
+    }
+    Ok(hash)
+}
+
+/// Returns the `(lon_min, lat_min, lon_max, lat_max)` bounds of the `hash` cell.
+fn bounds(hash: &str) -> (f64, f64, f64, f64) {
+    let (mut lon_range, mut lat_range) = ((-180., 180.), (-90., 90.));
+    let mut is_lon = true;
+    for c in hash.bytes() {
+        let index = BASE32
+            .iter()
+            .position(|&b| b == c)
+            .expect("invalid geohash character");
+        for bit in (0..5).rev() {
+            let range = if is_lon {
+                &mut lon_range
+            } else {
+                &mut lat_range
+            };
+            let middle = (range.0 + range.1) / 2.;
+            if (index >> bit) & 1 == 1 {
+                range.0 = middle;
+            } else {
+                range.1 = middle;
+            }
+            is_lon = !is_lon;
+        }
+    }
+    (lon_range.0, lat_range.0, lon_range.1, lat_range.1)
+}
+
+fn push_all_children(hash: &mut String, precision: usize, cells: &mut Vec<String>) {
+    if hash.len() == precision {
+        cells.push(hash.clone());
+        return;
+    }
+    for &c in BASE32.iter() {
+        hash.push(c as char);
+        push_all_children(hash, precision, cells);
+        hash.pop();
+    }
+}
+
+fn cover_cell(
+    prepared: &PreparedGeometry,
+    hash: &mut String,
+    precision: usize,
+    cells: &mut Vec<String>,
+) -> GResult<()> {
+    let (xmin, ymin, xmax, ymax) = bounds(hash);
+    let cell = rectangle(prepared.clone_context(), xmin, ymin, xmax, ymax)?;
+    if !prepared.intersects(&cell)? {
+        return Ok(());
+    }
+    if hash.len() == precision {
+        cells.push(hash.clone());
+    } else if prepared.contains(&cell)? {
+        // All the sub-cells intersect the geometry, no need to check them.
+        push_all_children(hash, precision, cells);
+    } else {
+        for &c in BASE32.iter() {
+            hash.push(c as char);
+            cover_cell(prepared, hash, precision, cells)?;
+            hash.pop();
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn geohash<G: Geom>(g: &G, precision: usize) -> GResult<String> {
+    if g.geometry_type() != GeometryTypes::Point || g.is_empty()? {
+        return Err(Error::GenericError(
+            "geohash can only be computed for non-empty points".to_owned(),
+        ));
+    }
+    encode(g.get_x()?, g.get_y()?, precision)
+}
+
+pub(crate) fn geohash_cover<G: Geom>(g: &G, precision: usize) -> GResult<Vec<String>> {
+    check_precision(precision)?;
+    let mut cells = Vec::new();
+    if g.is_empty()? {
+        return Ok(cells);
+    }
+    let prepared = PreparedGeometry::new(g)?;
+    let mut hash = String::with_capacity(precision);
+    for &c in BASE32.iter() {
+        hash.push(c as char);
+        cover_cell(&prepared, &mut hash, precision, &mut cells)?;
+        hash.pop();
+    }
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bounds, encode};
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode(-5.6, 42.6, 5).unwrap(), "ezs42");
+        assert_eq!(encode(10.40744, 57.64911, 11).unwrap(), "u4pruydqqvj");
+        assert!(encode(0., 91., 5).is_err());
+        assert!(encode(0., 0., 13).is_err());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let (xmin, ymin, xmax, ymax) = bounds("ezs42");
+        assert!(xmin <= -5.6 && -5.6 <= xmax);
+        assert!(ymin <= 42.6 && 42.6 <= ymax);
+        assert_eq!(bounds(""), (-180., -90., 180., 90.));
+    }
+}
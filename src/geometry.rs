@@ -2,12 +2,21 @@ use crate::context_handle::PtrWrap;
 use crate::enums::*;
 use crate::error::{Error, GResult, PredicateType};
 use crate::functions::*;
+use crate::intersection_matrix::parse_pattern;
+use crate::tile::TileBounds;
+#[cfg(any(feature = "v3_9_0", feature = "dox"))]
+use crate::LabelAnchor;
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+use crate::MakeValidParams;
 #[cfg(any(feature = "v3_6_0", feature = "dox"))]
 use crate::Precision;
 use crate::{
     AsRaw, AsRawMut, BufferParams, ContextHandle, ContextHandling, ContextInteractions, CoordSeq,
-    PreparedGeometry, WKTWriter,
+    IntersectionMatrix, Polygonized, PreparedGeometry, ReaderLimits, RepairReport, RepairStrategy,
+    ValidityDetail, ValidityFlags, WKTWriter,
 };
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+use crate::{BoundingCircle, CompactnessMetrics};
 use c_vec::CVec;
 use geos_sys::*;
 use std::borrow::Borrow;
@@ -98,6 +107,28 @@ pub trait Geom:
     /// );
     /// ```
     fn is_valid_reason(&self) -> GResult<String>;
+    /// Returns `None` if the geometry is valid, otherwise why it isn't along with the location
+    /// of the problem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, ValidityFlags};
+    ///
+    /// // Bowtie polygon with self-intersection
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))")
+    ///                     .expect("Invalid geometry");
+    /// let detail = geom
+    ///     .is_valid_detail(ValidityFlags::default())
+    ///     .expect("is_valid_detail failed")
+    ///     .expect("geometry should be invalid");
+    /// assert_eq!(detail.reason, "Self-intersection");
+    /// assert_eq!(detail.location.unwrap().to_wkt_precision(0).unwrap(), "POINT (1 1)");
+    ///
+    /// let geom = Geometry::new_from_wkt("POINT(1 1)").expect("Invalid geometry");
+    /// assert!(geom.is_valid_detail(ValidityFlags::default()).unwrap().is_none());
+    /// ```
+    fn is_valid_detail(&self, flags: ValidityFlags) -> GResult<Option<ValidityDetail>>;
     /// Get the underlying geos CoordSeq object from the geometry
     ///
     /// Note: this clones the underlying CoordSeq to avoid double free
@@ -163,6 +194,50 @@ pub trait Geom:
     /// );
     /// ```
     fn to_wkt(&self) -> GResult<String>;
+    /// Writes the WKT representation of `self` into `out`, without the intermediate `String`
+    /// allocated by [`Geom::to_wkt`]. To control the output, use
+    /// [`WKTWriter::write_to`](crate::WKTWriter::write_to).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)").expect("Invalid geometry");
+    /// let mut out = String::new();
+    /// point_geom.write_wkt(&mut out).expect("write failed");
+    /// assert_eq!(out, "POINT (2.5000000000000000 2.5000000000000000)");
+    /// ```
+    fn write_wkt<W: std::fmt::Write>(&self, out: &mut W) -> GResult<()>;
+    /// Same as [`Geom::write_wkt`] but writes into an [`std::io::Write`] (a file for example).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)").expect("Invalid geometry");
+    /// let mut out: Vec<u8> = Vec::new();
+    /// point_geom.write_wkt_io(&mut out).expect("write failed");
+    /// assert_eq!(out, b"POINT (2.5000000000000000 2.5000000000000000)");
+    /// ```
+    fn write_wkt_io<W: std::io::Write>(&self, out: &mut W) -> GResult<()>;
+    /// Returns the EWKT representation of `self`: the WKT prefixed with `SRID=<srid>;` if an SRID
+    /// is set, as expected by PostGIS `ST_GeomFromEWKT`. Plain WKT is returned otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let mut point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)").expect("Invalid geometry");
+    /// assert_eq!(point_geom.to_ewkt().unwrap(), "POINT (2.5000000000000000 2.5000000000000000)");
+    ///
+    /// point_geom.set_srid(4326);
+    /// assert_eq!(point_geom.to_ewkt().unwrap(),
+    ///            "SRID=4326;POINT (2.5000000000000000 2.5000000000000000)");
+    /// ```
+    fn to_ewkt(&self) -> GResult<String>;
     /// Returns a WKT representation of the geometry with the given `precision`. It is a wrapper
     /// around [`WKTWriter::set_rounding_precision`].
     ///
@@ -340,6 +415,23 @@ pub trait Geom:
     /// assert_eq!(geom1.equals_exact(&geom3, 0.1), Ok(true));
     /// ```
     fn equals_exact<G: Geom>(&self, other: &G, precision: f64) -> GResult<bool>;
+    /// Checks if the two geometries are equal, using the given [`EqualityMode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{EqualityMode, Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("LINESTRING(0 0, 2 2)").unwrap();
+    /// let geom2 = Geometry::new_from_wkt("LINESTRING(2 2, 1 1, 0 0)").unwrap();
+    /// let geom3 = Geometry::new_from_wkt("LINESTRING(2 2, 0 0)").unwrap();
+    ///
+    /// assert_eq!(geom1.equals_with(&geom2, EqualityMode::Topological), Ok(true));
+    /// assert_eq!(geom1.equals_with(&geom2, EqualityMode::Normalized), Ok(false));
+    /// assert_eq!(geom1.equals_with(&geom3, EqualityMode::Normalized), Ok(true));
+    /// assert_eq!(geom1.equals_with(&geom3, EqualityMode::Exact { tolerance: 0.1 }), Ok(false));
+    /// ```
+    fn equals_with<G: Geom>(&self, other: &G, mode: EqualityMode) -> GResult<bool>;
     /// Returns `true` if no point of `other` is outside of `self`.
     ///
     /// # Example
@@ -370,6 +462,59 @@ pub trait Geom:
     /// assert_eq!(big_geom.covered_by(&little_geom), Ok(false));
     /// ```
     fn covered_by<G: Geom>(&self, other: &G) -> GResult<bool>;
+    /// Returns the DE-9IM [`IntersectionMatrix`] describing how `self` and `other` intersect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap();
+    /// let geom2 = Geometry::new_from_wkt("POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))").unwrap();
+    ///
+    /// let matrix = geom1.relate(&geom2).expect("relate failed");
+    /// assert_eq!(matrix.to_string(), "FF2F11212");
+    /// ```
+    fn relate<G: Geom>(&self, other: &G) -> GResult<IntersectionMatrix>;
+    /// Returns `true` if the DE-9IM matrix of `self` and `other` matches `pattern` (see
+    /// [`IntersectionMatrix::matches`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap();
+    /// let geom2 = Geometry::new_from_wkt("POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))").unwrap();
+    ///
+    /// // The polygons share an edge.
+    /// assert_eq!(geom1.relate_pattern(&geom2, "F***1****"), Ok(true));
+    /// assert!(geom1.relate_pattern(&geom2, "F***1").is_err());
+    /// ```
+    fn relate_pattern<G: Geom>(&self, other: &G, pattern: &str) -> GResult<bool>;
+    /// Same as [`Geom::relate`], using `rule` to decide which endpoints of the lines are part
+    /// of their boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{BoundaryNodeRule, Geom, Geometry, Location};
+    ///
+    /// let lines = Geometry::new_from_wkt("MULTILINESTRING((0 0, 1 0), (1 0, 2 0))").unwrap();
+    /// let point = Geometry::new_from_wkt("POINT(1 0)").unwrap();
+    ///
+    /// // (1 0) ends two lines so it is in the interior with the default rule...
+    /// let matrix = lines.relate_with_rule(&point, BoundaryNodeRule::Mod2).expect("relate failed");
+    /// assert_eq!(matrix.get(Location::Interior, Location::Interior), Some(0));
+    /// // ...but in the boundary when all the endpoints are.
+    /// let matrix = lines.relate_with_rule(&point, BoundaryNodeRule::EndPoint).expect("relate failed");
+    /// assert_eq!(matrix.get(Location::Boundary, Location::Interior), Some(0));
+    /// ```
+    fn relate_with_rule<G: Geom>(
+        &self,
+        other: &G,
+        rule: BoundaryNodeRule,
+    ) -> GResult<IntersectionMatrix>;
     /// Returns `true` if no points of the `other` geometry is outside the exterior of `self`.
     ///
     /// # Example
@@ -471,6 +616,25 @@ pub trait Geom:
         join_style: JoinStyle,
         mitre_limit: f64,
     ) -> GResult<Geometry>;
+    /// Returns the buffer of width `width` of only one `side` of each line of `self` (see
+    /// [`BufferParams::set_single_sided`]). The sign of `width` is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, Side};
+    ///
+    /// let road = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)").expect("Invalid geometry");
+    ///
+    /// let left = road.single_sided_buffer(2., Side::Left).expect("single_sided_buffer failed");
+    /// assert_eq!(left.get_y_min(), Ok(0.));
+    /// assert_eq!(left.get_y_max(), Ok(2.));
+    ///
+    /// let right = road.single_sided_buffer(2., Side::Right).expect("single_sided_buffer failed");
+    /// assert_eq!(right.get_y_min(), Ok(-2.));
+    /// assert_eq!(right.get_y_max(), Ok(0.));
+    /// ```
+    fn single_sided_buffer(&self, width: f64, side: Side) -> GResult<Geometry>;
     /// Returns `true` if the given geometry is empty.
     ///
     /// # Example
@@ -521,6 +685,26 @@ pub trait Geom:
     ///            "LINESTRING (50.0 150.0, 50.0 200.0)");
     /// ```
     fn difference<G: Geom>(&self, other: &G) -> GResult<Geometry>;
+    /// Same as [`Geom::difference`], computed with a fixed precision: the coordinates of the
+    /// inputs and of the result are snap-rounded to a grid of size `grid_size` (see
+    /// [`Geom::intersection_prec`]).
+    ///
+    /// Available using the `v3_9_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+    /// let geom2 = Geometry::new_from_wkt("POLYGON((5.1 0.2, 15 0.2, 15 10.1, 5.1 10.1, 5.1 0.2))")
+    ///                      .unwrap();
+    ///
+    /// let res = geom1.difference_prec(&geom2, 1.).expect("difference_prec failed");
+    /// assert_eq!(res.area(), Ok(50.));
+    /// ```
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn difference_prec<G: Geom>(&self, other: &G, grid_size: f64) -> GResult<Geometry>;
     /// Returns the minimum bouding box of the given geometry.
     ///
     /// # Example
@@ -558,6 +742,26 @@ pub trait Geom:
     /// );
     /// ```
     fn sym_difference<G: Geom>(&self, other: &G) -> GResult<Geometry>;
+    /// Same as [`Geom::sym_difference`], computed with a fixed precision: the coordinates of the
+    /// inputs and of the result are snap-rounded to a grid of size `grid_size` (see
+    /// [`Geom::intersection_prec`]).
+    ///
+    /// Available using the `v3_9_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+    /// let geom2 = Geometry::new_from_wkt("POLYGON((5.1 0.2, 15 0.2, 15 10.1, 5.1 10.1, 5.1 0.2))")
+    ///                      .unwrap();
+    ///
+    /// let res = geom1.sym_difference_prec(&geom2, 1.).expect("sym_difference_prec failed");
+    /// assert_eq!(res.area(), Ok(100.));
+    /// ```
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn sym_difference_prec<G: Geom>(&self, other: &G, grid_size: f64) -> GResult<Geometry>;
     /// Aggregates the given geometry with another one.
     ///
     /// # Example
@@ -573,6 +777,26 @@ pub trait Geom:
     /// assert_eq!(union_geom.to_wkt_precision(1).unwrap(), "MULTIPOINT (1.0 2.0, 3.0 4.0)");
     /// ```
     fn union<G: Geom>(&self, other: &G) -> GResult<Geometry>;
+    /// Same as [`Geom::union`], computed with a fixed precision: the coordinates of the
+    /// inputs and of the result are snap-rounded to a grid of size `grid_size` (see
+    /// [`Geom::intersection_prec`]).
+    ///
+    /// Available using the `v3_9_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+    /// let geom2 = Geometry::new_from_wkt("POLYGON((5.1 0.2, 15 0.2, 15 10.1, 5.1 10.1, 5.1 0.2))")
+    ///                      .unwrap();
+    ///
+    /// let res = geom1.union_prec(&geom2, 1.).expect("union_prec failed");
+    /// assert_eq!(res.area(), Ok(150.));
+    /// ```
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn union_prec<G: Geom>(&self, other: &G, grid_size: f64) -> GResult<Geometry>;
     /// Returns the geometric center or (equivalently) the center of mass of the given geometry as
     /// a point.
     ///
@@ -599,6 +823,9 @@ pub trait Geom:
     /// > ST_Collect to fine-tune how many geometries at once you want to dissolve to be nice on
     /// > both memory size and CPU time, finding the balance between ST_Union and ST_MemUnion.
     ///
+    /// When `self` is made of many disjoint groups of geometries, [`Geom::disjoint_subset_union`]
+    /// is usually faster.
+    ///
     /// # Example
     ///
     /// ```
@@ -621,7 +848,110 @@ pub trait Geom:
     /// );
     /// ```
     fn unary_union(&self) -> GResult<Geometry>;
-    /// Create a voronoi diagram.
+    /// Same as [`Geom::unary_union`], computed with a fixed precision: the coordinates are
+    /// snap-rounded to a grid of size `grid_size` (see [`Geom::intersection_prec`]). It makes
+    /// dissolving large and dirty collections robust.
+    ///
+    /// Available using the `v3_9_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, GeometryTypes};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOLYGON(((0 0, 10.2 0, 9.8 10, 0 10, 0 0)),
+    ///                                                 ((10.1 0, 20 0, 20 10, 9.9 10, 10.1 0)))")
+    ///                     .expect("Invalid geometry");
+    ///
+    /// let union_geom = geom.unary_union_prec(1.).expect("unary_union_prec failed");
+    /// assert_eq!(union_geom.geometry_type(), GeometryTypes::Polygon);
+    /// assert_eq!(union_geom.area(), Ok(200.));
+    /// ```
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn unary_union_prec(&self, grid_size: f64) -> GResult<Geometry>;
+    /// Same as [`Geom::unary_union`], but the geometries of `self` are first grouped into
+    /// subsets of intersecting geometries, and each subset is unioned separately. It is faster
+    /// when `self` is made of many disjoint groups of geometries (buildings of several cities
+    /// for instance), and slower when most geometries end up in a single group.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOLYGON(((0 0, 2 0, 2 2, 0 2, 0 0)),
+    ///                                                 ((1 0, 3 0, 3 2, 1 2, 1 0)),
+    ///                                                 ((10 0, 12 0, 12 2, 10 2, 10 0)))")
+    ///                     .expect("Invalid geometry");
+    ///
+    /// let union_geom = geom.disjoint_subset_union().expect("disjoint_subset_union failed");
+    /// assert_eq!(union_geom.get_num_geometries(), Ok(2));
+    /// assert_eq!(union_geom.area(), Ok(10.));
+    /// ```
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn disjoint_subset_union(&self) -> GResult<Geometry>;
+    /// Returns the union of the polygons of `self`, a collection of polygons forming a coverage
+    /// (they don't overlap and adjacent polygons share the same vertices along their common
+    /// edges). It is much faster than [`Geom::unary_union`] since only the edges which aren't
+    /// shared have to be kept, but the result is invalid if `self` isn't a valid coverage (see
+    /// the [`coverage`](crate::coverage) module).
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, GeometryTypes};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOLYGON(((0 0, 2 0, 2 2, 0 2, 0 0)),
+    ///                                                 ((2 0, 4 0, 4 2, 2 2, 2 0)))")
+    ///                     .expect("Invalid geometry");
+    ///
+    /// let union_geom = geom.coverage_union().expect("coverage_union failed");
+    /// assert_eq!(union_geom.geometry_type(), GeometryTypes::Polygon);
+    /// assert_eq!(union_geom.area(), Ok(8.));
+    /// ```
+    #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    fn coverage_union(&self) -> GResult<Geometry>;
+    /// Simplifies the polygons of `self`, a collection of polygons forming a valid coverage,
+    /// with the Visvalingam-Whyatt algorithm. Unlike [`Geometry::simplify`] applied to each
+    /// polygon, the edges shared by adjacent polygons are simplified the same way, so the result
+    /// is still a coverage without gaps nor overlaps. `tolerance` is roughly the width of the
+    /// removed details, and the outer boundary of the coverage is left as is if
+    /// `preserve_boundary` is `true`.
+    ///
+    /// The result is a `GeometryCollection` with the simplified version of each polygon of
+    /// `self`, in the same order.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("GEOMETRYCOLLECTION(
+    ///     POLYGON((0 0, 10 0, 10 5, 10.1 5.1, 10 10, 0 10, 0 0)),
+    ///     POLYGON((10 0, 20 0, 20 10, 10 10, 10.1 5.1, 10 5, 10 0)))")
+    ///                     .expect("Invalid geometry");
+    ///
+    /// let simplified = geom.coverage_simplify(1., true).expect("coverage_simplify failed");
+    /// assert!(simplified.get_num_coordinates().unwrap() < geom.get_num_coordinates().unwrap());
+    /// assert!((simplified.area().unwrap() - 200.).abs() < 1e-9);
+    /// ```
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn coverage_simplify(&self, tolerance: f64, preserve_boundary: bool) -> GResult<Geometry>;
+    /// Create a voronoi diagram of the vertices of `self`.
+    ///
+    /// The diagram is returned as a `GeometryCollection` of `Polygon`s (one cell per vertex), or
+    /// as a `MultiLineString` of the cell edges if `only_edges` is `true`. It covers the larger
+    /// of `envelope` (or rather its envelope) and an envelope slightly bigger than `self`, so the
+    /// outer cells have to be intersected with the area of interest to clip them exactly.
+    ///
+    /// Vertices closer than `tolerance` are snapped together (`0` disables snapping). As noted
+    /// by GEOS, a non-zero tolerance can make the computation fail in some cases.
     ///
     /// # Example
     ///
@@ -642,6 +972,28 @@ pub trait Geom:
     ///
     /// assert_eq!(expected.equals(&voronoi), Ok(true));
     /// ```
+    ///
+    /// Service area of each store of a town:
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let stores = Geometry::new_from_wkt("MULTIPOINT(2 2, 4 2)").expect("Invalid geometry");
+    /// let town = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")
+    ///                  .expect("Invalid geometry");
+    ///
+    /// let cells = stores.voronoi(Some(&town), 0., false).expect("voronoi failed");
+    /// assert_eq!(cells.get_num_geometries(), Ok(2));
+    ///
+    /// let mut areas = Vec::new();
+    /// for i in 0..cells.get_num_geometries().unwrap() {
+    ///     let cell = cells.get_geometry_n(i).expect("get_geometry_n failed");
+    ///     let area = cell.intersection(&town).expect("intersection failed");
+    ///     areas.push(area.area().unwrap());
+    /// }
+    /// areas.sort_by(f64::total_cmp);
+    /// assert_eq!(areas, vec![30., 70.]);
+    /// ```
     fn voronoi<G: Geom>(
         &self,
         envelope: Option<&G>,
@@ -672,6 +1024,49 @@ pub trait Geom:
     /// assert_eq!(intersection_geom.to_wkt_precision(1).unwrap(), "POINT (0.0 0.0)");
     /// ```
     fn intersection<G: Geom>(&self, other: &G) -> GResult<Geometry>;
+    /// Same as [`Geom::intersection`], computed with a fixed precision: the coordinates of the
+    /// inputs and of the result are snap-rounded to a grid of size `grid_size`. Unlike a
+    /// floating precision overlay, it can't fail because of robustness issues, which makes it
+    /// the way to overlay noisy data.
+    ///
+    /// Available using the `v3_9_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+    /// let geom2 = Geometry::new_from_wkt("POLYGON((5.1 0.2, 15 0.2, 15 10.1, 5.1 10.1, 5.1 0.2))")
+    ///                      .unwrap();
+    ///
+    /// let res = geom1.intersection_prec(&geom2, 1.).expect("intersection_prec failed");
+    /// assert_eq!(res.area(), Ok(50.));
+    /// ```
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn intersection_prec<G: Geom>(&self, other: &G, grid_size: f64) -> GResult<Geometry>;
+    /// Returns the part of `self` inside the `(xmin, ymin, xmax, ymax)` rectangle.
+    ///
+    /// It is much faster than an [`intersection`](Geom::intersection) with the rectangle, which
+    /// makes it the way to cut geometries into tiles. However the result may be invalid for
+    /// polygons (parts of their boundary can be collapsed along the rectangle edges), and it
+    /// isn't guaranteed to be the same as the intersection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")
+    ///                     .expect("Invalid geometry");
+    ///
+    /// let clipped = geom.clip_by_rect(5., 5., 20., 20.).expect("clip_by_rect failed");
+    /// assert_eq!(clipped.area(), Ok(25.));
+    ///
+    /// let clipped = geom.clip_by_rect(20., 20., 30., 30.).expect("clip_by_rect failed");
+    /// assert_eq!(clipped.is_empty(), Ok(true));
+    /// ```
+    fn clip_by_rect(&self, xmin: f64, ymin: f64, xmax: f64, ymax: f64) -> GResult<Geometry>;
     /// Documentation from [postgis](https://postgis.net/docs/ST_ConvexHull.html):
     ///
     /// > The convex hull of a geometry represents the minimum convex geometry that encloses all
@@ -705,135 +1100,439 @@ pub trait Geom:
     ///            "POLYGON ((50.0 5.0, 10.0 8.0, 10.0 10.0, 100.0 190.0, 150.0 30.0, 150.0 10.0, 50.0 5.0))");
     /// ```
     fn convex_hull(&self) -> GResult<Geometry>;
-    /// Returns the closure of the combinatorial boundary of `self`.
+    /// Returns a concave hull of `self`, computed by eroding its Delaunay triangulation.
+    ///
+    /// `ratio` is the maximum edge length of the hull relative to the longest edge of the
+    /// triangulation: `1` gives the convex hull, `0` the most concave hull. An error is returned
+    /// if `ratio` isn't between `0` and `1`. If `allow_holes` is `true`, the hull can have holes.
+    ///
+    /// Available using the `v3_11_0` feature.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom = Geometry::new_from_wkt("LINESTRING(1 1,0 0, -1 1)").expect("Invalid geometry");
-    /// let boundary_geom = geom.boundary().expect("boundary failed");
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT(0 0, 10 0, 10 10, 0 10, 5 1)").unwrap();
     ///
-    /// assert_eq!(boundary_geom.to_wkt_precision(1).unwrap(), "MULTIPOINT (1.0 1.0, -1.0 1.0)");
+    /// let hull = geom.concave_hull(1., false).expect("concave_hull failed");
+    /// assert_eq!(hull.area(), Ok(100.));
+    /// // The hull goes through (5 1).
+    /// let hull = geom.concave_hull(0., false).expect("concave_hull failed");
+    /// assert!(hull.area().unwrap() < 100.);
+    ///
+    /// assert!(geom.concave_hull(1.5, false).is_err());
     /// ```
-    fn boundary(&self) -> GResult<Geometry>;
-    /// Returns `true` if `self` has a Z coordinate.
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn concave_hull(&self, ratio: f64, allow_holes: bool) -> GResult<Geometry>;
+    /// Returns a concave hull of `self` whose edges are not longer than `length` (unless it
+    /// would disconnect the hull), computed by eroding its Delaunay triangulation. Unlike
+    /// [`Geom::concave_hull`], the parameter is in the units of the coordinates, which makes it
+    /// easy to choose for point sets with a known spacing. If `allow_holes` is `true`, the hull
+    /// can have holes.
+    ///
+    /// Available using the `v3_12_0` feature.
     ///
     /// # Example
     ///
     /// ```
-    /// use geos::{Geom, Geometry};
+    /// use geos::{Geom, Geometry, GeometryTypes};
     ///
-    /// let geom = Geometry::new_from_wkt("POINT(1 2 3)").expect("Invalid geometry");
-    /// assert_eq!(geom.has_z(), Ok(true));
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT(0 0, 10 0, 10 10, 0 10, 5 1)").unwrap();
     ///
-    /// let geom = Geometry::new_from_wkt("POINT(1 2)").expect("Invalid geometry");
-    /// assert_eq!(geom.has_z(), Ok(false));
+    /// let hull = geom.concave_hull_by_length(100., false).expect("concave_hull_by_length failed");
+    /// assert_eq!(hull.area(), Ok(100.));
+    ///
+    /// let hull = geom.concave_hull_by_length(6., false).expect("concave_hull_by_length failed");
+    /// assert_eq!(hull.geometry_type(), GeometryTypes::Polygon);
+    /// assert!(hull.area().unwrap() < 100.);
     /// ```
-    fn has_z(&self) -> GResult<bool>;
-    /// Returns `true` if start and end point are coincident.
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn concave_hull_by_length(&self, length: f64, allow_holes: bool) -> GResult<Geometry>;
+    /// Returns a concave hull of `self`, a `Polygon` or a `MultiPolygon`, which contains all its
+    /// polygons and fills the gaps between them, computed from the constrained Delaunay
+    /// triangulation of the gaps.
     ///
-    /// Only works on `LineString` and `MultiLineString`.
+    /// `length_ratio` is the maximum edge length of the hull relative to the difference between
+    /// the longest and the shortest edges of the triangulation: `1` gives the convex hull, `0`
+    /// the most concave hull. An error is returned if it isn't between `0` and `1`. If
+    /// `is_tight` is `true`, the hull follows the outer boundaries of the input polygons. If
+    /// `allow_holes` is `true`, the hull can have holes.
+    ///
+    /// Available using the `v3_11_0` feature.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 1 1)").expect("Invalid geometry");
-    /// assert_eq!(geom.is_closed(), Ok(false));
+    /// let parcels = Geometry::new_from_wkt("MULTIPOLYGON(((0 0, 4 0, 4 4, 0 4, 0 0)), \
+    ///                                                   ((5 0, 9 0, 9 4, 5 4, 5 0)))").unwrap();
     ///
-    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 0 1, 1 1, 0 0)").expect("Invalid geometry");
-    /// assert_eq!(geom.is_closed(), Ok(true));
+    /// let hull = parcels
+    ///     .concave_hull_of_polygons(1., false, false)
+    ///     .expect("concave_hull_of_polygons failed");
+    /// assert_eq!(hull.area(), Ok(36.));
     ///
-    /// let geom = Geometry::new_from_wkt("MULTILINESTRING((0 0, 0 1, 1 1, 0 0),(0 0, 1 1))")
-    ///                     .expect("Invalid geometry");
-    /// assert_eq!(geom.is_closed(), Ok(false));
+    /// let hull = parcels
+    ///     .concave_hull_of_polygons(0., true, false)
+    ///     .expect("concave_hull_of_polygons failed");
+    /// assert_eq!(hull.covers(&parcels), Ok(true));
     /// ```
-    fn is_closed(&self) -> GResult<bool>;
-    /// Returns the length of `self`. The unit depends of the SRID.
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn concave_hull_of_polygons(
+        &self,
+        length_ratio: f64,
+        is_tight: bool,
+        allow_holes: bool,
+    ) -> GResult<Geometry>;
+    /// Simplifies `self`, a `Polygon` or a `MultiPolygon`, into a hull which is guaranteed to
+    /// contain it (if `is_outer` is `true`) or to be contained in it (otherwise), keeping
+    /// `vertex_num_fraction` of its vertices: `1` keeps all of them, `0` as few as possible.
+    /// An error is returned if `vertex_num_fraction` isn't between `0` and `1`.
+    ///
+    /// Unlike [`Geometry::simplify`] or [`Geometry::topology_preserve_simplify`], the result
+    /// never crosses the input, which makes it suitable for generalizations where containment
+    /// must be preserved. Holes are simplified the other way around, and the polygons of a
+    /// `MultiPolygon` don't overlap in the result.
+    ///
+    /// Available using the `v3_11_0` feature.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom = Geometry::new_from_wkt("LINESTRING(743238 2967416,743238 2967450)")
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 5 1, 10 0, 9 5, 10 10, 5 9, 0 10, 1 5, 0 0))")
     ///                     .expect("Invalid geometry");
     ///
-    /// assert_eq!(
-    ///     geom.length().map(|x| format!("{:.2}", x)).unwrap(),
-    ///     "34.00",
-    /// );
+    /// let outer = geom.polygon_hull_simplify(true, 0.5).expect("polygon_hull_simplify failed");
+    /// assert_eq!(outer.covers(&geom), Ok(true));
+    /// assert!(outer.get_num_coordinates().unwrap() < geom.get_num_coordinates().unwrap());
+    ///
+    /// let inner = geom.polygon_hull_simplify(false, 0.5).expect("polygon_hull_simplify failed");
+    /// assert_eq!(geom.covers(&inner), Ok(true));
+    ///
+    /// assert!(geom.polygon_hull_simplify(true, 1.5).is_err());
     /// ```
-    fn length(&self) -> GResult<f64>;
-    /// Returns the distance between `self` and `other`. The unit depends of the SRID.
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn polygon_hull_simplify(&self, is_outer: bool, vertex_num_fraction: f64) -> GResult<Geometry>;
+    /// Same as [`Geom::polygon_hull_simplify`], `parameter` being interpreted according to
+    /// `mode`. An error is returned if it is negative, or greater than `1` for
+    /// [`PolygonHullParameterMode::VertexRatio`].
+    ///
+    /// Available using the `v3_11_0` feature.
     ///
     /// # Example
     ///
     /// ```
-    /// use geos::{Geom, Geometry};
+    /// use geos::{Geom, Geometry, PolygonHullParameterMode};
     ///
-    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
-    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 5 1, 10 0, 9 5, 10 10, 5 9, 0 10, 1 5, 0 0))")
+    ///                     .expect("Invalid geometry");
     ///
-    /// assert_eq!(geom1.distance(&geom2).map(|x| format!("{:.2}", x)).unwrap(), "1.00");
+    /// // The area of the hull is at most 10% bigger than the area of `geom`.
+    /// let hull = geom
+    ///     .polygon_hull_simplify_mode(true, PolygonHullParameterMode::AreaRatio, 0.1)
+    ///     .expect("polygon_hull_simplify_mode failed");
+    /// assert_eq!(hull.covers(&geom), Ok(true));
+    /// assert!(hull.area().unwrap() <= geom.area().unwrap() * 1.1 + 1e-9);
     /// ```
-    fn distance<G: Geom>(&self, other: &G) -> GResult<f64>;
-    /// Returns the indexed distance between `self` and `other`. The unit depends of the SRID.
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn polygon_hull_simplify_mode(
+        &self,
+        is_outer: bool,
+        mode: PolygonHullParameterMode,
+        parameter: f64,
+    ) -> GResult<Geometry>;
+    /// Returns an approximation of the medial axis (the centerline) of `self`, a `Polygon` or a
+    /// `MultiPolygon`.
     ///
-    /// Available using the `v3_7_0` feature.
+    /// The boundary of `self` is densified so that its vertices are at most `densify_distance`
+    /// apart, then the edges of the Voronoi diagram of these vertices lying inside `self` are
+    /// kept and merged. The smaller `densify_distance` is, the better the approximation (and the
+    /// slower the computation): it should be small compared to the width of `self`.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
-    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    /// let road = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 2, 0 2, 0 0))").unwrap();
     ///
-    /// assert_eq!(geom1.distance_indexed(&geom2).map(|x| format!("{:.2}", x)).unwrap(), "1.00");
+    /// let axis = road.approximate_medial_axis(0.5).expect("approximate_medial_axis failed");
+    /// assert_eq!(road.contains(&axis), Ok(true));
+    /// assert!(axis.length().unwrap() > 8.);
     /// ```
-    #[cfg(any(feature = "v3_7_0", feature = "dox"))]
-    fn distance_indexed<G: Geom>(&self, other: &G) -> GResult<f64>;
-    /// Returns the hausdorff distance between `self` and `other`. The unit depends of the SRID.
+    fn approximate_medial_axis(&self, densify_distance: f64) -> GResult<Geometry>;
+    /// Returns the largest circle contained in `self`, a `Polygon` or a `MultiPolygon`, computed
+    /// up to `tolerance`. It is returned as a line going from the center of the circle to the
+    /// closest point of the boundary of `self`, so its length is the radius of the circle.
+    ///
+    /// The center of this circle (the "pole of inaccessibility") is a good place for a label.
+    ///
+    /// Available using the `v3_9_0` feature.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
-    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 4, 0 4, 0 0))").unwrap();
     ///
-    /// assert_eq!(geom1.hausdorff_distance(&geom2).map(|x| format!("{:.2}", x)).unwrap(), "1.00");
+    /// let line = geom.maximum_inscribed_circle(0.01).expect("maximum_inscribed_circle failed");
+    /// assert!((line.length().unwrap() - 2.).abs() < 0.01);
+    /// let center = line.get_start_point().expect("get_start_point failed");
+    /// assert!((center.get_y().unwrap() - 2.).abs() < 0.01);
     /// ```
-    fn hausdorff_distance<G: Geom>(&self, other: &G) -> GResult<f64>;
-    /// Returns the hausdorff distance between `self` and `other`. The unit depends of the SRID.
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn maximum_inscribed_circle(&self, tolerance: f64) -> GResult<Geometry>;
+    /// Returns the largest circle whose interior doesn't intersect the obstacles in `self`, and
+    /// whose center lies in `boundary`, computed up to `tolerance`. If `boundary` is `None`, the
+    /// convex hull of `self` is used instead.
+    ///
+    /// As with [`Geom::maximum_inscribed_circle`], the circle is returned as a line going from
+    /// its center to the closest obstacle, so its length is the radius of the circle.
+    ///
+    /// Available using the `v3_9_0` feature.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
-    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    /// let obstacles = Geometry::new_from_wkt("MULTIPOINT(0 0, 10 0, 10 10, 0 10)").unwrap();
     ///
-    /// assert_eq!(geom1.hausdorff_distance_densify(&geom2, 1.).map(|x| format!("{:.2}", x))
-    ///                                                        .unwrap(), "1.00");
+    /// let line = obstacles
+    ///     .largest_empty_circle(None::<&Geometry>, 0.01)
+    ///     .expect("largest_empty_circle failed");
+    /// assert!((line.length().unwrap() - 50f64.sqrt()).abs() < 0.01);
     /// ```
-    fn hausdorff_distance_densify<G: Geom>(&self, other: &G, distance_frac: f64) -> GResult<f64>;
-    /// Returns the frechet distance between `self` and `other`. The unit depends of the SRID.
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn largest_empty_circle<G: Geom>(
+        &self,
+        boundary: Option<&G>,
+        tolerance: f64,
+    ) -> GResult<Geometry>;
+    /// Returns where and how to draw the label of `self`, a `Polygon` or a `MultiPolygon`: the
+    /// center and radius of its maximum inscribed circle (computed up to `tolerance`), and the
+    /// orientation of its minimum rotated rectangle.
     ///
-    /// Available using the `v3_7_0` feature.
+    /// Available using the `v3_9_0` feature.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom1 = Geometry::new_from_wkt("LINESTRING (0 0, 100 0)").expect("Invalid geometry");
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 10, 9 11, -1 1, 0 0))").unwrap();
+    ///
+    /// let anchor = geom.label_anchor(0.01).expect("label_anchor failed");
+    /// assert!((anchor.max_radius - 2f64.sqrt() / 2.).abs() < 0.01);
+    /// assert!((anchor.angle - std::f64::consts::FRAC_PI_4).abs() < 1e-9);
+    /// ```
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn label_anchor(&self, tolerance: f64) -> GResult<LabelAnchor>;
+    /// Returns the smallest circle containing `self`, as a polygon approximating it along with
+    /// its exact center and radius.
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 6 8)").unwrap();
+    ///
+    /// let (polygon, circle) = geom.minimum_bounding_circle().expect("minimum_bounding_circle failed");
+    /// assert_eq!(circle.center, (3., 4.));
+    /// assert_eq!(circle.radius, 5.);
+    /// assert_eq!(polygon.covers(&geom), Ok(true));
+    /// ```
+    #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    fn minimum_bounding_circle(&self) -> GResult<(Geometry, BoundingCircle)>;
+    /// Returns the usual compactness measures of `self`, a `Polygon` or a `MultiPolygon`,
+    /// computed from its area, perimeter, convex hull and minimum bounding circle.
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let square = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap();
+    ///
+    /// let metrics = square.compactness().expect("compactness failed");
+    /// assert!((metrics.polsby_popper - std::f64::consts::FRAC_PI_4).abs() < 1e-9);
+    /// assert!((metrics.reock - 2. / std::f64::consts::PI).abs() < 1e-9);
+    /// assert_eq!(metrics.convexity, 1.);
+    /// ```
+    #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    fn compactness(&self) -> GResult<CompactnessMetrics>;
+    /// Returns the closure of the combinatorial boundary of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(1 1,0 0, -1 1)").expect("Invalid geometry");
+    /// let boundary_geom = geom.boundary().expect("boundary failed");
+    ///
+    /// assert_eq!(boundary_geom.to_wkt_precision(1).unwrap(), "MULTIPOINT (1.0 1.0, -1.0 1.0)");
+    /// ```
+    fn boundary(&self) -> GResult<Geometry>;
+    /// Returns `true` if `self` has a Z coordinate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POINT(1 2 3)").expect("Invalid geometry");
+    /// assert_eq!(geom.has_z(), Ok(true));
+    ///
+    /// let geom = Geometry::new_from_wkt("POINT(1 2)").expect("Invalid geometry");
+    /// assert_eq!(geom.has_z(), Ok(false));
+    /// ```
+    fn has_z(&self) -> GResult<bool>;
+    /// Returns `true` if start and end point are coincident.
+    ///
+    /// Only works on `LineString` and `MultiLineString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 1 1)").expect("Invalid geometry");
+    /// assert_eq!(geom.is_closed(), Ok(false));
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 0 1, 1 1, 0 0)").expect("Invalid geometry");
+    /// assert_eq!(geom.is_closed(), Ok(true));
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTILINESTRING((0 0, 0 1, 1 1, 0 0),(0 0, 1 1))")
+    ///                     .expect("Invalid geometry");
+    /// assert_eq!(geom.is_closed(), Ok(false));
+    /// ```
+    fn is_closed(&self) -> GResult<bool>;
+    /// Returns the length of `self`. The unit depends of the SRID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(743238 2967416,743238 2967450)")
+    ///                     .expect("Invalid geometry");
+    ///
+    /// assert_eq!(
+    ///     geom.length().map(|x| format!("{:.2}", x)).unwrap(),
+    ///     "34.00",
+    /// );
+    /// ```
+    fn length(&self) -> GResult<f64>;
+    /// Returns the distance between `self` and `other`. The unit depends of the SRID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
+    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    ///
+    /// assert_eq!(geom1.distance(&geom2).map(|x| format!("{:.2}", x)).unwrap(), "1.00");
+    /// ```
+    fn distance<G: Geom>(&self, other: &G) -> GResult<f64>;
+    /// Returns the indexed distance between `self` and `other`. The unit depends of the SRID.
+    ///
+    /// Available using the `v3_7_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
+    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    ///
+    /// assert_eq!(geom1.distance_indexed(&geom2).map(|x| format!("{:.2}", x)).unwrap(), "1.00");
+    /// ```
+    #[cfg(any(feature = "v3_7_0", feature = "dox"))]
+    fn distance_indexed<G: Geom>(&self, other: &G) -> GResult<f64>;
+    /// Returns `true` if the distance between `self` and `other` is less than or equal to
+    /// `distance`. It is faster than computing the actual distance since GEOS can stop as soon
+    /// as two close enough points are found.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
+    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    ///
+    /// assert_eq!(geom1.distance_within(&geom2, 1.), Ok(true));
+    /// assert_eq!(geom1.distance_within(&geom2, 0.5), Ok(false));
+    /// ```
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    fn distance_within<G: Geom>(&self, other: &G, distance: f64) -> GResult<bool>;
+    /// Returns the hausdorff distance between `self` and `other`. The unit depends of the SRID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
+    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    ///
+    /// assert_eq!(geom1.hausdorff_distance(&geom2).map(|x| format!("{:.2}", x)).unwrap(), "1.00");
+    /// ```
+    fn hausdorff_distance<G: Geom>(&self, other: &G) -> GResult<f64>;
+    /// Returns the hausdorff distance between `self` and `other`. The unit depends of the SRID.
+    ///
+    /// [`Geom::hausdorff_distance`] only considers the vertices of the geometries, so it can
+    /// badly underestimate the distance between long segments. Here, each segment is first
+    /// split into equal pieces whose length is at most `distance_frac` times the length of the
+    /// segment. `distance_frac` must be in the `(0, 1]` range: the smaller it is, the more
+    /// accurate (and the slower) the computation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POINT (1 2)").expect("Invalid geometry");
+    /// let geom2 = Geometry::new_from_wkt("POINT (2 2)").expect("Invalid geometry");
+    ///
+    /// assert_eq!(geom1.hausdorff_distance_densify(&geom2, 1.).map(|x| format!("{:.2}", x))
+    ///                                                        .unwrap(), "1.00");
+    ///
+    /// let geom1 = Geometry::new_from_wkt("LINESTRING (130 0, 0 0, 0 150)").expect("Invalid geometry");
+    /// let geom2 = Geometry::new_from_wkt("LINESTRING (10 10, 10 150, 130 10)").expect("Invalid geometry");
+    ///
+    /// assert_eq!(geom1.hausdorff_distance(&geom2).map(|x| format!("{:.2}", x)).unwrap(), "14.14");
+    /// assert_eq!(geom1.hausdorff_distance_densify(&geom2, 0.5).map(|x| format!("{:.2}", x))
+    ///                                                          .unwrap(), "70.00");
+    /// assert!(geom1.hausdorff_distance_densify(&geom2, 0.).is_err());
+    /// ```
+    fn hausdorff_distance_densify<G: Geom>(&self, other: &G, distance_frac: f64) -> GResult<f64>;
+    /// Returns the frechet distance between `self` and `other`. The unit depends of the SRID.
+    ///
+    /// Available using the `v3_7_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("LINESTRING (0 0, 100 0)").expect("Invalid geometry");
     /// let geom2 = Geometry::new_from_wkt("LINESTRING (0 0, 50 50, 100 0)").expect("Invalid geometry");
     ///
     /// assert_eq!(geom1.frechet_distance(&geom2).map(|x| format!("{:.2}", x)).unwrap(), "70.71");
@@ -842,6 +1541,11 @@ pub trait Geom:
     fn frechet_distance<G: Geom>(&self, other: &G) -> GResult<f64>;
     /// Returns the frechet distance between `self` and `other`. The unit depends of the SRID.
     ///
+    /// As with [`Geom::hausdorff_distance_densify`], segments are first split into equal pieces
+    /// whose length is at most `distance_frac` times the length of the segment, which must be
+    /// in the `(0, 1]` range. It makes the (discrete) frechet distance closer to the continuous
+    /// one, which matters when comparing trajectories with few vertices.
+    ///
     /// Available using the `v3_7_0` feature.
     ///
     /// # Example
@@ -854,6 +1558,9 @@ pub trait Geom:
     ///
     /// assert_eq!(geom1.frechet_distance_densify(&geom2, 1.).map(|x| format!("{:.2}", x))
     ///                                                      .unwrap(), "70.71");
+    /// assert_eq!(geom1.frechet_distance_densify(&geom2, 0.5).map(|x| format!("{:.2}", x))
+    ///                                                       .unwrap(), "50.00");
+    /// assert!(geom1.frechet_distance_densify(&geom2, 1.5).is_err());
     /// ```
     #[cfg(any(feature = "v3_7_0", feature = "dox"))]
     fn frechet_distance_densify<G: Geom>(&self, other: &G, distance_frac: f64) -> GResult<f64>;
@@ -1068,6 +1775,29 @@ pub trait Geom:
     /// Available using the `v3_8_0` feature.
     #[cfg(any(feature = "v3_8_0", feature = "dox"))]
     fn make_valid(&self) -> GResult<Geometry>;
+    /// Same as [`Geom::make_valid`], using the given `params` to choose the algorithm and
+    /// whether collapsed components are kept (see [`MakeValidParams`]).
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, MakeValidMethod, MakeValidParams};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))")
+    ///     .expect("Invalid geometry");
+    /// let params = MakeValidParams::builder()
+    ///     .method(MakeValidMethod::Structure)
+    ///     .build()
+    ///     .expect("failed to create MakeValidParams");
+    ///
+    /// let valid = geom.make_valid_with_params(&params).expect("make_valid_with_params failed");
+    /// assert_eq!(valid.geometry_type(), geos::GeometryTypes::MultiPolygon);
+    /// assert_eq!(valid.area(), Ok(2.));
+    /// ```
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    fn make_valid_with_params(&self, params: &MakeValidParams) -> GResult<Geometry>;
     /// Returns the number of geometries.
     ///
     /// # Example
@@ -1113,7 +1843,9 @@ pub trait Geom:
     /// ```
     #[cfg(any(feature = "v3_6_0", feature = "dox"))]
     fn get_precision(&self) -> GResult<f64>;
-    /// Returns the precision of `self`.
+    /// Returns a copy of `self` whose coordinates are rounded to a grid of size `grid_size`
+    /// (`0` meaning full floating precision), handling the topology as described by `flags`.
+    /// The grid size is then returned by [`Geom::get_precision`].
     ///
     /// Available using the `v3_6_0` feature.
     ///
@@ -1126,6 +1858,12 @@ pub trait Geom:
     ///
     /// point_geom.set_precision(1., Precision::KeepCollapsed);
     /// assert_eq!(point_geom.get_precision().map(|x| format!("{:.2}", x)).unwrap(), "0.00");
+    ///
+    /// let small = Geometry::new_from_wkt("POLYGON((0 0, 0.2 0, 0.2 0.2, 0 0.2, 0 0))")
+    ///                      .expect("Invalid geometry");
+    /// let reduced = small.set_precision(1., Precision::ValidOutput).expect("set_precision failed");
+    /// assert_eq!(reduced.is_empty(), Ok(true));
+    /// assert_eq!(reduced.get_precision(), Ok(1.));
     /// ```
     #[cfg(any(feature = "v3_6_0", feature = "dox"))]
     fn set_precision(&self, grid_size: f64, flags: Precision) -> GResult<Geometry>;
@@ -1186,7 +1924,11 @@ pub trait Geom:
     #[cfg(any(feature = "v3_7_0", feature = "dox"))]
     fn get_y_min(&self) -> GResult<f64>;
     /// Returns the smallest distance by which a vertex of `self` could be moved to produce an
-    /// invalid geometry.
+    /// invalid geometry. It measures how robust `self` is to a precision reduction: rounding
+    /// its coordinates to a grid whose size is less than half of it keeps `self` valid.
+    ///
+    /// If `self` has less than two distinct vertices (a point for instance), its minimum
+    /// clearance is infinite. Use [`Geom::minimum_clearance_line`] to see where it is reached.
     ///
     /// Available using the `v3_6_0` feature.
     ///
@@ -1197,10 +1939,14 @@ pub trait Geom:
     ///
     /// let geom = Geometry::new_from_wkt("LINESTRING(1 3 4, 5 6 7)").expect("Invalid WKT");
     /// assert_eq!(geom.minimum_clearance().map(|x| format!("{:.8}", x)).unwrap(), "5.00000000");
+    ///
+    /// let point = Geometry::new_from_wkt("POINT(1 3)").expect("Invalid WKT");
+    /// assert_eq!(point.minimum_clearance(), Ok(f64::INFINITY));
     /// ```
     #[cfg(any(feature = "v3_6_0", feature = "dox"))]
     fn minimum_clearance(&self) -> GResult<f64>;
-    /// Returns the two-point LineString spanning of `self`'s minimum clearance.
+    /// Returns the two-point LineString spanning `self`'s minimum clearance (see
+    /// [`Geom::minimum_clearance`]), an empty LineString if the minimum clearance is infinite.
     ///
     /// Available using the `v3_6_0` feature.
     ///
@@ -1216,24 +1962,73 @@ pub trait Geom:
     /// ```
     #[cfg(any(feature = "v3_6_0", feature = "dox"))]
     fn minimum_clearance_line(&self) -> GResult<Geometry>;
-    /// Returns the minimum rotated rectangle inside of `self`.
+    /// Returns the smallest rectangle (of any orientation) enclosing `self`, also known as its
+    /// oriented bounding box. If `self` is a point or has no area, the result is degenerate: a
+    /// `Point` or a `LineString`.
     ///
     /// Available using the `v3_6_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 2, 1 3, 0.5 2, -1 1, 0 0))").unwrap();
+    ///
+    /// let rectangle = geom.minimum_rotated_rectangle().expect("minimum_rotated_rectangle failed");
+    /// assert!((rectangle.area().unwrap() - 4.).abs() < 1e-9);
+    /// ```
     #[cfg(any(feature = "v3_6_0", feature = "dox"))]
     fn minimum_rotated_rectangle(&self) -> GResult<Geometry>;
-    /// Returns the minimum width inside of `self`.
+    /// Returns the minimum width of `self`, as the shortest line across it: the smallest
+    /// distance between two parallel lines enclosing `self`. A small width compared to the area
+    /// of a polygon denotes a sliver.
     ///
     /// Available using the `v3_6_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 2, 0 2, 0 0))").unwrap();
+    ///
+    /// let line = geom.minimum_width().expect("minimum_width failed");
+    /// assert!((line.length().unwrap() - 2.).abs() < 1e-9);
+    /// ```
     #[cfg(any(feature = "v3_6_0", feature = "dox"))]
     fn minimum_width(&self) -> GResult<Geometry>;
     /// Returns a [delaunay triangulation](https://en.wikipedia.org/wiki/Delaunay_triangulation)
     /// around the vertices of `self`.
     ///
+    /// Vertices closer than `tolerance` are snapped together (`0` disables snapping). The
+    /// triangles are returned as a `GeometryCollection` of `Polygon`s, or as a
+    /// `MultiLineString` of their edges if `only_edges` is `true`. Z values of the vertices
+    /// are kept, so it can be used to build a TIN from survey points.
+    ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
+    /// let points = Geometry::new_from_wkt("MULTIPOINT Z(0 0 1, 10 0 2, 0 10 3, 10 10 4)")
+    ///                       .expect("Invalid WKT");
+    ///
+    /// let triangles = points.delaunay_triangulation(0., false)
+    ///                       .expect("delaunay_triangulation failed");
+    /// assert_eq!(triangles.get_num_geometries(), Ok(2));
+    /// assert_eq!(triangles.area(), Ok(100.));
+    ///
+    /// let edges = points.delaunay_triangulation(0., true)
+    ///                   .expect("delaunay_triangulation failed");
+    /// assert_eq!(edges.get_num_geometries(), Ok(5));
+    /// ```
+    ///
+    /// The vertices of any geometry can be triangulated:
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
     /// let geom1 = Geometry::new_from_wkt("POLYGON((175 150, 20 40, 50 60, 125 100, 175 150))")
     ///                      .expect("Invalid WKT");
     /// let geom2 = Geometry::new_from_wkt("POINT(110 170)").expect("Invalid WKT");
@@ -1242,46 +2037,202 @@ pub trait Geom:
     /// let geom = geom1.union(&geom2).expect("union failed");
     ///
     /// let final_geom = geom.delaunay_triangulation(0.001, false).expect("delaunay_triangulation failed");
+    /// assert_eq!(final_geom.geometry_type(), geos::GeometryTypes::GeometryCollection);
     /// ```
     fn delaunay_triangulation(&self, tolerance: f64, only_edges: bool) -> GResult<Geometry>;
+    /// Returns the point at the distance `d` along `self`, which must be a `LineString`. A
+    /// negative `d` is measured from the end of the line, and `d` is clamped to the length of
+    /// the line.
+    ///
+    /// See the [`linear_referencing`](crate::linear_referencing) module for `MultiLineString`s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)").expect("Invalid WKT");
+    ///
+    /// let point = line.interpolate(15.).expect("interpolate failed");
+    /// assert_eq!(point.to_wkt_precision(0).unwrap(), "POINT (10 5)");
+    /// let point = line.interpolate(-15.).expect("interpolate failed");
+    /// assert_eq!(point.to_wkt_precision(0).unwrap(), "POINT (5 0)");
+    /// ```
     fn interpolate(&self, d: f64) -> GResult<Geometry>;
+    /// Same as [`Geom::interpolate`], `d` being a fraction of the length of `self` (between `0`
+    /// and `1`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)").expect("Invalid WKT");
+    ///
+    /// let point = line.interpolate_normalized(0.75).expect("interpolate_normalized failed");
+    /// assert_eq!(point.to_wkt_precision(0).unwrap(), "POINT (10 5)");
+    /// ```
     fn interpolate_normalized(&self, d: f64) -> GResult<Geometry>;
-    fn project<G: Geom>(&self, p: &G) -> GResult<f64>;
-    fn project_normalized<G: Geom>(&self, p: &G) -> GResult<f64>;
-    fn node(&self) -> GResult<Geometry>;
-    ///  Return an offset line at a given distance and side from an input line. All points of the
-    /// returned geometries are not further than the given distance from the input geometry.
+    /// Returns the part of `self`, which must be a `LineString`, between the points at
+    /// `start_fraction` and `end_fraction` of its length (see
+    /// [`Geom::interpolate_normalized`]). Both fractions must be between `0` and `1`, and
+    /// `start_fraction` must be less than or equal to `end_fraction`. If they are equal, the
+    /// result is a `Point`.
     ///
-    /// ### Parameters description:
+    /// # Example
     ///
-    /// #### width
+    /// ```
+    /// use geos::{Geom, Geometry};
     ///
-    /// * If `width` is positive, the offset will be at the left side of the input line and retain
-    ///   the same direction.
-    /// * If `width` is negative, it'll be at the right side and in the opposite direction.
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)").expect("Invalid WKT");
     ///
-    /// #### quadrant_segments
+    /// let part = line.line_substring(0.25, 0.75).expect("line_substring failed");
+    /// assert_eq!(part.to_wkt_precision(0).unwrap(), "LINESTRING (5 0, 10 0, 10 5)");
+    /// assert!(line.line_substring(0.75, 0.25).is_err());
+    /// ```
+    fn line_substring(&self, start_fraction: f64, end_fraction: f64) -> GResult<Geometry>;
+    /// Returns the distance along `self` of the point of `self` the closest to `p`, which must
+    /// be a `Point`. It is the inverse of [`Geom::interpolate`].
     ///
-    /// * If `quadrant_segments` is >= 1, joins are round, and `quadrant_segments` indicates the
-    ///   number of segments to use to approximate a quarter-circle.
-    /// * If `quadrant_segments` == 0, joins are bevelled (flat).
-    /// * If `quadrant_segments` < 0, joins are mitred, and the value of `quadrant_segments`
-    ///   indicates the mitre ration limit as `mitre_limit = |quadrant_segments|`
+    /// # Example
     ///
-    /// #### mitre_limit
+    /// ```
+    /// use geos::{Geom, Geometry};
     ///
-    /// The mitre ratio is the ratio of the distance from the corner to the end of the mitred offset
-    /// corner. When two line segments meet at a sharp angle, a miter join will extend far beyond
-    /// the original geometry (and in the extreme case will be infinitely far). To prevent
-    /// unreasonable geometry, the mitre limit allows controlling the maximum length of the join
-    /// corner. Corners with a ratio which exceed the limit will be beveled.
-    fn offset_curve(
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)").expect("Invalid WKT");
+    /// let point = Geometry::new_from_wkt("POINT(12 5)").expect("Invalid WKT");
+    ///
+    /// assert_eq!(line.project(&point), Ok(15.));
+    /// ```
+    fn project<G: Geom>(&self, p: &G) -> GResult<f64>;
+    /// Same as [`Geom::project`], the distance being returned as a fraction of the length of
+    /// `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)").expect("Invalid WKT");
+    /// let point = Geometry::new_from_wkt("POINT(12 5)").expect("Invalid WKT");
+    ///
+    /// assert_eq!(line.project_normalized(&point), Ok(0.75));
+    /// ```
+    fn project_normalized<G: Geom>(&self, p: &G) -> GResult<f64>;
+    /// Returns the linework of `self` fully noded: lines are split at every point where they
+    /// intersect (or touch) each other, and duplicated segments are merged. The result is a
+    /// `MultiLineString`.
+    ///
+    /// Raw linework has to be noded before being given to [`Geometry::polygonize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let lines = Geometry::new_from_wkt("MULTILINESTRING((0 0, 10 0, 10 10, 0 10, 0 0), \
+    ///                                                     (5 -5, 5 15))")
+    ///                      .expect("Invalid WKT");
+    ///
+    /// let noded = lines.node().expect("node failed");
+    /// assert_eq!(noded.length(), lines.length());
+    ///
+    /// let polygons = Geometry::polygonize(&[noded]).expect("polygonize failed");
+    /// assert_eq!(polygons.get_num_geometries(), Ok(2));
+    /// assert_eq!(polygons.area(), Ok(100.));
+    /// ```
+    fn node(&self) -> GResult<Geometry>;
+    ///  Return an offset line at a given distance and side from an input line. All points of the
+    /// returned geometries are not further than the given distance from the input geometry.
+    ///
+    /// ### Parameters description:
+    ///
+    /// #### width
+    ///
+    /// * If `width` is positive, the offset will be at the left side of the input line and retain
+    ///   the same direction.
+    /// * If `width` is negative, it'll be at the right side and in the opposite direction.
+    ///
+    /// #### quadrant_segments
+    ///
+    /// * If `quadrant_segments` is >= 1, joins are round, and `quadrant_segments` indicates the
+    ///   number of segments to use to approximate a quarter-circle.
+    /// * If `quadrant_segments` == 0, joins are bevelled (flat).
+    /// * If `quadrant_segments` < 0, joins are mitred, and the value of `quadrant_segments`
+    ///   indicates the mitre ration limit as `mitre_limit = |quadrant_segments|`
+    ///
+    /// #### mitre_limit
+    ///
+    /// The mitre ratio is the ratio of the distance from the corner to the end of the mitred offset
+    /// corner. When two line segments meet at a sharp angle, a miter join will extend far beyond
+    /// the original geometry (and in the extreme case will be infinitely far). To prevent
+    /// unreasonable geometry, the mitre limit allows controlling the maximum length of the join
+    /// corner. Corners with a ratio which exceed the limit will be beveled.
+    ///
+    /// `self` must be a `LineString` or a `MultiLineString`, whose parts are offset separately
+    /// (the result being a `MultiLineString`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, JoinStyle};
+    ///
+    /// let centerline = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)")
+    ///     .expect("Invalid geometry");
+    /// let lane = centerline
+    ///     .offset_curve(1., 8, JoinStyle::Mitre, 5.)
+    ///     .expect("offset_curve failed");
+    ///
+    /// assert_eq!(lane.length(), Ok(18.));
+    /// assert_eq!(lane.distance(&centerline), Ok(1.));
+    /// ```
+    fn offset_curve(
         &self,
         width: f64,
         quadrant_segments: i32,
         join_style: JoinStyle,
         mitre_limit: f64,
     ) -> GResult<Geometry>;
+    /// Returns a copy of `self` with vertices added so that no segment is longer than
+    /// `tolerance`. Segments are split into equal parts.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 3)").expect("Invalid geometry");
+    /// let densified = line.densify(4.).expect("densify failed");
+    ///
+    /// assert_eq!(
+    ///     densified.to_wkt_precision(1).unwrap(),
+    ///     "LINESTRING (0.0 0.0, 3.3 0.0, 6.7 0.0, 10.0 0.0, 10.0 3.0)",
+    /// );
+    /// ```
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    fn densify(&self, tolerance: f64) -> GResult<Geometry>;
+    /// Returns a copy of `self` without the consecutive vertices which are within `tolerance`
+    /// of each other. With a `tolerance` of 0, only exactly repeated vertices are removed.
+    ///
+    /// Available using the `v3_11_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 0 0, 1 0, 1.01 0, 2 0)")
+    ///     .expect("Invalid geometry");
+    ///
+    /// let cleaned = line.remove_repeated_points(0.).expect("remove_repeated_points failed");
+    /// assert_eq!(cleaned.get_num_points(), Ok(4));
+    /// let cleaned = line.remove_repeated_points(0.1).expect("remove_repeated_points failed");
+    /// assert_eq!(cleaned.to_wkt_precision(0).unwrap(), "LINESTRING (0 0, 1 0, 2 0)");
+    /// ```
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn remove_repeated_points(&self, tolerance: f64) -> GResult<Geometry>;
     fn point_on_surface(&self) -> GResult<Geometry>;
     /// Returns, in the tuple elements order:
     ///
@@ -1298,6 +2249,25 @@ pub trait Geom:
         Option<Geometry>,
         Option<Geometry>,
     )>;
+    /// Returns the paths shared by `self` and `other`, two lineal geometries, as a
+    /// `GeometryCollection` of two `MultiLineString`s: the first one contains the paths having
+    /// the same direction in both geometries, the second one the paths having opposite
+    /// directions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let road = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)").expect("Invalid geometry");
+    /// let other = Geometry::new_from_wkt("LINESTRING(5 0, 15 0)").expect("Invalid geometry");
+    ///
+    /// let shared = road.shared_paths(&other).expect("shared_paths failed");
+    /// let forward = shared.get_geometry_n(0).expect("get_geometry_n failed");
+    /// let backward = shared.get_geometry_n(1).expect("get_geometry_n failed");
+    /// assert_eq!(forward.length(), Ok(5.));
+    /// assert_eq!(backward.is_empty(), Ok(true));
+    /// ```
     fn shared_paths<G: Geom>(&self, other: &G) -> GResult<Geometry>;
     /// Converts a [`Geometry`] to the HEX format. For more control over the generated output,
     /// use the [`WKBWriter`](crate::WKBWriter) type.
@@ -1325,6 +2295,26 @@ pub trait Geom:
     /// let wkb_buf = point_geom.to_wkb().expect("conversion to WKB failed");
     /// ```
     fn to_wkb(&self) -> GResult<CVec<u8>>;
+    /// Appends the WKB representation of `self` to `buffer`.
+    ///
+    /// Unlike [`Geom::to_wkb`], the output is copied into a caller-provided buffer which can be
+    /// reused across calls (clear it between geometries if needed), avoiding an allocation per
+    /// geometry on the Rust side.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)")
+    ///                           .expect("Invalid geometry");
+    /// let mut buffer = Vec::new();
+    /// point_geom.to_wkb_into(&mut buffer).expect("conversion to WKB failed");
+    /// assert_eq!(buffer.len(), 21);
+    /// point_geom.to_wkb_into(&mut buffer).expect("conversion to WKB failed");
+    /// assert_eq!(buffer.len(), 42);
+    /// ```
+    fn to_wkb_into(&self, buffer: &mut Vec<u8>) -> GResult<()>;
     /// Creates a new [`PreparedGeometry`] from the current `Geometry`.
     ///
     /// # Example
@@ -1402,6 +2392,312 @@ pub trait Geom:
     ///                         0.0000000000000000 0.0000000000000000)");
     /// ```
     fn get_exterior_ring(&self) -> GResult<ConstGeometry>;
+    /// Recursively splits `self` into pieces having at most `max_vertices` vertices, like the
+    /// PostGIS `ST_Subdivide` function. Pieces are obtained by cutting the geometry in half
+    /// along the longest side of its envelope. Collections are split part by part, except
+    /// `MultiPoint`s which are kept whole when they have at most `max_vertices` points and
+    /// are otherwise split into `MultiPoint`s of nearby points.
+    ///
+    /// Subdivided geometries make point-in-polygon checks and overlays against huge geometries
+    /// (coastlines, country borders...) much faster when combined with a spatial index.
+    ///
+    /// `max_vertices` must be at least 5.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POINT (0 0)").expect("Invalid geometry")
+    ///                     .buffer(10., 8).expect("buffer failed");
+    /// let pieces = geom.subdivide(10).expect("subdivide failed");
+    ///
+    /// assert!(pieces.len() > 1);
+    /// assert!(pieces.iter().all(|p| p.get_num_coordinates().unwrap() <= 10));
+    /// ```
+    fn subdivide(&self, max_vertices: usize) -> GResult<Vec<Geometry>>;
+    /// Splits `self` by `blade` and returns the pieces in a `GeometryCollection`:
+    ///
+    /// * polygons are split by lines (or the boundary of polygons),
+    /// * lines are split by points, lines or the boundary of polygons.
+    ///
+    /// If `blade` doesn't split `self`, the collection only contains a copy of `self`. Parts of
+    /// a line overlapping a line blade are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let polygon = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")
+    ///                        .expect("Invalid geometry");
+    /// let blade = Geometry::new_from_wkt("LINESTRING(1 -1, 1 3)").expect("Invalid geometry");
+    ///
+    /// let pieces = polygon.split(&blade).expect("split failed");
+    /// assert_eq!(pieces.get_num_geometries(), Ok(2));
+    /// assert_eq!(pieces.get_geometry_n(0).unwrap().area(), Ok(2.));
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 2 0, 2 2)").expect("Invalid geometry");
+    /// let points = Geometry::new_from_wkt("MULTIPOINT(1 0, 2 0)").expect("Invalid geometry");
+    ///
+    /// let pieces = line.split(&points).expect("split failed");
+    /// assert_eq!(pieces.to_wkt_precision(0).unwrap(),
+    ///            "GEOMETRYCOLLECTION (LINESTRING (0 0, 1 0), LINESTRING (1 0, 2 0), \
+    ///                                 LINESTRING (2 0, 2 2))");
+    /// ```
+    fn split<G: Geom>(&self, blade: &G) -> GResult<Geometry>;
+    /// Returns the geohash of `self`, which must be a point (in longitude/latitude), using
+    /// `precision` characters (between 1 and 12).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let point = Geometry::new_from_wkt("POINT (-5.6 42.6)").expect("Invalid geometry");
+    /// assert_eq!(point.geohash(5), Ok("ezs42".to_owned()));
+    /// ```
+    fn geohash(&self, precision: usize) -> GResult<String>;
+    /// Returns the (sorted) geohashes of `precision` characters of the cells intersecting
+    /// `self`.
+    ///
+    /// Cells are refined hierarchically using a [`PreparedGeometry`] of `self`, so only the
+    /// cells crossing the boundary of `self` are checked at each level.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let polygon = Geometry::new_from_wkt("POLYGON((-5.6 42.6, -5.59 42.6, -5.59 42.61, \
+    ///                                                -5.6 42.61, -5.6 42.6))")
+    ///                        .expect("Invalid geometry");
+    /// let cells = polygon.geohash_cover(5).expect("geohash_cover failed");
+    /// assert_eq!(cells, vec!["ezs42"]);
+    ///
+    /// let cells = polygon.geohash_cover(6).expect("geohash_cover failed");
+    /// assert!(cells.len() > 1);
+    /// assert!(cells.iter().all(|c| c.starts_with("ezs42")));
+    /// ```
+    fn geohash_cover(&self, precision: usize) -> GResult<Vec<String>>;
+    /// Scales the coordinates of `self` to the integer space of a tile of `extent` pixels wide
+    /// (with the Y axis pointing down, as in MVT), removing the consecutive duplicated points
+    /// and the parts collapsing because of the rounding. Polygons made invalid by the rounding
+    /// are fixed.
+    ///
+    /// The result is ready to be encoded in a Mapbox Vector Tile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::tile::TileBounds;
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let bounds = TileBounds { xmin: 0., ymin: 0., xmax: 100., ymax: 100. };
+    /// let geom = Geometry::new_from_wkt("LINESTRING(10.1 10, 10.2 10, 50 50)").unwrap();
+    /// let quantized = geom.quantize_to_tile(&bounds, 10).expect("quantize_to_tile failed");
+    /// assert_eq!(quantized.to_wkt_precision(0).unwrap(), "LINESTRING (1 9, 5 5)");
+    ///
+    /// // This polygon is too small to be kept.
+    /// let geom = Geometry::new_from_wkt("POLYGON((1 1, 2 1, 2 2, 1 1))").unwrap();
+    /// let quantized = geom.quantize_to_tile(&bounds, 10).expect("quantize_to_tile failed");
+    /// assert!(quantized.is_empty().unwrap());
+    /// ```
+    fn quantize_to_tile(&self, tile_bounds: &TileBounds, extent: u32) -> GResult<Geometry>;
+    /// Repairs `self` using the given `strategy`, returning the repaired geometry along with a
+    /// [`RepairReport`] describing what changed. Valid geometries are returned untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, RepairStrategy};
+    ///
+    /// let bow_tie = Geometry::new_from_wkt("POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))").unwrap();
+    /// let (repaired, report) = bow_tie
+    ///     .repair(RepairStrategy::Chain(vec![
+    ///         RepairStrategy::BufferZero,
+    ///         RepairStrategy::BufferZero,
+    ///     ]))
+    ///     .expect("repair failed");
+    /// assert!(repaired.is_valid());
+    /// assert!(!report.was_valid);
+    /// assert!(report.is_valid);
+    /// // The second strategy isn't needed.
+    /// assert_eq!(report.applied, vec![RepairStrategy::BufferZero]);
+    ///
+    /// let square = Geometry::new_from_wkt("POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+    /// let (_, report) = square.repair(RepairStrategy::BufferZero).expect("repair failed");
+    /// assert!(report.was_valid);
+    /// assert!(report.applied.is_empty());
+    /// assert_eq!(report.area_delta, 0.);
+    /// ```
+    fn repair(&self, strategy: RepairStrategy) -> GResult<(Geometry, RepairReport)>;
+    /// Returns the parts of `self`, a measured (XYM or XYZM) line or point, whose M values are
+    /// between `m_start` and `m_end` (equivalent of PostGIS `ST_LocateBetween`). Coordinates are
+    /// interpolated at the bounds of the range.
+    ///
+    /// Lines give a `MultiLineString` (parts only touching the range are dropped) and points a
+    /// `MultiPoint`.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, GeometryTypes};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING M (0 0 0, 10 0 10, 10 10 20)").unwrap();
+    ///
+    /// let events = line.locate_between_measures(5., 15.).expect("locate_between_measures failed");
+    /// assert_eq!(events.geometry_type(), GeometryTypes::MultiLineString);
+    /// assert_eq!(events.length(), Ok(10.));
+    /// ```
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn locate_between_measures(&self, m_start: f64, m_end: f64) -> GResult<Geometry>;
+    /// Returns a copy of `self`, a `LineString` or a `MultiLineString`, with M values linearly
+    /// interpolated from `start` to `end` along its length (equivalent of PostGIS
+    /// `ST_AddMeasure`). Existing M values are replaced and Z values are kept.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)").unwrap();
+    ///
+    /// let measured = line.add_measure(0., 100.).expect("add_measure failed");
+    /// let events = measured.locate_between_measures(25., 75.).expect("locate_between_measures failed");
+    /// assert_eq!(events.length(), Ok(10.));
+    /// ```
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn add_measure(&self, start: f64, end: f64) -> GResult<Geometry>;
+    /// Returns the azimuth of each segment of `self`, a `LineString`, in radians clockwise from
+    /// the positive Y axis (see [`azimuth`](crate::azimuth)). Zero-length segments give `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 0 10, 10 10, 10 0)").unwrap();
+    ///
+    /// assert_eq!(line.segment_bearings(), Ok(vec![0., FRAC_PI_2, PI]));
+    /// ```
+    fn segment_bearings(&self) -> GResult<Vec<f64>>;
+    /// Returns the point at `distance` from `self`, a `Point`, in the direction of `bearing`
+    /// (in radians clockwise from the positive Y axis). Coordinates are planar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let point = Geometry::new_from_wkt("POINT(1 2)").unwrap();
+    ///
+    /// let destination = point.destination(FRAC_PI_2, 10.).expect("destination failed");
+    /// assert_eq!(destination.to_wkt_precision(0).unwrap(), "POINT (11 2)");
+    /// ```
+    fn destination(&self, bearing: f64, distance: f64) -> GResult<Geometry>;
+    /// Returns the (sorted) H3 cells of the given `resolution` intersecting `self`, which must
+    /// be in longitude/latitude.
+    ///
+    /// Cells are found by flood filling from the cell containing a point of each part of `self`,
+    /// checking each cell boundary against the prepared geometry. Geometries crossing the
+    /// antimeridian aren't supported.
+    ///
+    /// Available using the `h3` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::h3o::Resolution;
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let point = Geometry::new_from_wkt("POINT(2.35 48.85)").unwrap();
+    /// let cells = point.h3_cells(Resolution::Nine).expect("h3_cells failed");
+    /// assert_eq!(cells.len(), 1);
+    ///
+    /// let polygon = Geometry::new_from_wkt("POLYGON((2.3 48.8, 2.4 48.8, 2.4 48.9, 2.3 48.9, 2.3 48.8))")
+    ///     .unwrap();
+    /// let cells = polygon.h3_cells(Resolution::Seven).expect("h3_cells failed");
+    /// assert!(cells.len() > 1);
+    /// ```
+    #[cfg(feature = "h3")]
+    fn h3_cells(&self, resolution: h3o::Resolution) -> GResult<Vec<h3o::CellIndex>>;
+    /// Returns the nth (0-based) geometry of `self` if it exists, `None` otherwise.
+    ///
+    /// Unlike [`Geom::get_geometry_n`], the index is checked against
+    /// [`Geom::get_num_geometries`] before calling GEOS.
+    ///
+    /// Note that `std::ops::Index` cannot be implemented on geometries since sub-geometries are
+    /// borrowed views created on demand, not references into `self`. Use this method or
+    /// [`Geom::geometry`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT(1 1, 2 2, 3 3)")
+    ///                     .expect("Invalid geometry");
+    /// assert_eq!(geom.get(1).unwrap().to_wkt_precision(0).unwrap(), "POINT (2 2)");
+    /// assert!(geom.get(3).is_none());
+    /// ```
+    fn get(&self, n: usize) -> Option<ConstGeometry>;
+    /// Returns the nth (0-based) geometry of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is out of bounds. See [`Geom::get`] for a non-panicking version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT(1 1, 2 2, 3 3)")
+    ///                     .expect("Invalid geometry");
+    /// assert_eq!(geom.geometry(2).to_wkt_precision(0).unwrap(), "POINT (3 3)");
+    /// ```
+    fn geometry(&self, n: usize) -> ConstGeometry;
+    /// Returns the nth (0-based) interior ring of `self` if it exists, `None` otherwise (also
+    /// when `self` isn't a polygon).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0),\
+    ///                                            (1 1, 2 1, 2 5, 1 5, 1 1))")
+    ///                     .expect("Invalid geometry");
+    /// assert!(geom.get_interior_ring(0).is_some());
+    /// assert!(geom.get_interior_ring(1).is_none());
+    /// ```
+    fn get_interior_ring(&self, n: usize) -> Option<ConstGeometry>;
+    /// Returns the nth (0-based) interior ring of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` isn't a polygon or if `n` is out of bounds. See
+    /// [`Geom::get_interior_ring`] for a non-panicking version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0),\
+    ///                                            (1 1, 2 1, 2 5, 1 5, 1 1))")
+    ///                     .expect("Invalid geometry");
+    /// assert_eq!(geom.interior_ring(0).to_wkt_precision(0).unwrap(),
+    ///            "LINEARRING (1 1, 2 1, 2 5, 1 5, 1 1)");
+    /// ```
+    fn interior_ring(&self, n: usize) -> ConstGeometry;
     /// Apply XY coordinate transform callback to all coordinates in a copy of input geometry.
     /// If the callback returns an error, the function will return an Err.
     /// Z values, if present, are not modified by this function.
@@ -1457,6 +2753,11 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         }
     }
 
+    fn is_valid_detail(&self, flags: ValidityFlags) -> GResult<Option<ValidityDetail>> {
+        Ok(is_valid_detail(self, flags.bits())?
+            .map(|(reason, location)| ValidityDetail { reason, location }))
+    }
+
     fn get_coord_seq(&self) -> GResult<CoordSeq> {
         let type_geom = self.geometry_type();
         match type_geom {
@@ -1493,13 +2794,32 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
     }
 
     fn to_wkt(&self) -> GResult<String> {
+        trace_span!("to_wkt", self);
         match WKTWriter::new_with_context(self.clone_context()) {
             Ok(mut w) => w.write(self),
             Err(e) => Err(e),
         }
     }
 
+    fn write_wkt<W: std::fmt::Write>(&self, out: &mut W) -> GResult<()> {
+        WKTWriter::new_with_context(self.clone_context())?.write_to(self, out)
+    }
+
+    fn write_wkt_io<W: std::io::Write>(&self, out: &mut W) -> GResult<()> {
+        WKTWriter::new_with_context(self.clone_context())?.write_to_io(self, out)
+    }
+
+    fn to_ewkt(&self) -> GResult<String> {
+        let mut out = match self.get_srid() {
+            Ok(srid) => format!("SRID={srid};"),
+            Err(_) => String::new(),
+        };
+        self.write_wkt(&mut out)?;
+        Ok(out)
+    }
+
     fn to_wkt_precision(&self, precision: u32) -> GResult<String> {
+        trace_span!("to_wkt", self);
         unsafe {
             let writer = GEOSWKTWriter_create_r(self.get_raw_context());
             GEOSWKTWriter_setRoundingPrecision_r(self.get_raw_context(), writer, precision as _);
@@ -1570,6 +2890,27 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         check_geos_predicate(ret_val as _, PredicateType::EqualsExact)
     }
 
+    fn equals_with<G: Geom>(&self, other: &G, mode: EqualityMode) -> GResult<bool> {
+        match mode {
+            EqualityMode::Topological => self.equals(other),
+            EqualityMode::Exact { tolerance } => self.equals_exact(other, tolerance),
+            EqualityMode::Normalized => {
+                let mut a = Geom::clone(self);
+                let mut b = Geom::clone(other);
+                a.normalize()?;
+                b.normalize()?;
+                a.equals_exact(&b, 0.)
+            }
+            #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+            EqualityMode::Identical => {
+                let ret_val = unsafe {
+                    GEOSEqualsIdentical_r(self.get_raw_context(), self.as_raw(), other.as_raw())
+                };
+                check_geos_predicate(ret_val as _, PredicateType::EqualsIdentical)
+            }
+        }
+    }
+
     fn covers<G: Geom>(&self, other: &G) -> GResult<bool> {
         let ret_val = unsafe {
             GEOSCovers_r(self.get_raw_context(), self.as_raw(), other.as_raw())
@@ -1584,6 +2925,39 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         check_geos_predicate(ret_val as _, PredicateType::CoveredBy)
     }
 
+    fn relate<G: Geom>(&self, other: &G) -> GResult<IntersectionMatrix> {
+        unsafe {
+            let ptr = GEOSRelate_r(self.get_raw_context(), self.as_raw(), other.as_raw());
+            managed_string(ptr, self.get_context_handle(), "relate")?.parse()
+        }
+    }
+
+    fn relate_with_rule<G: Geom>(
+        &self,
+        other: &G,
+        rule: BoundaryNodeRule,
+    ) -> GResult<IntersectionMatrix> {
+        let rule: GEOSRelateBoundaryNodeRules = rule.into();
+        unsafe {
+            let ptr = GEOSRelateBoundaryNodeRule_r(
+                self.get_raw_context(),
+                self.as_raw(),
+                other.as_raw(),
+                rule as _,
+            );
+            managed_string(ptr, self.get_context_handle(), "relate_with_rule")?.parse()
+        }
+    }
+
+    fn relate_pattern<G: Geom>(&self, other: &G, pattern: &str) -> GResult<bool> {
+        parse_pattern(pattern)?;
+        let pattern = CString::new(pattern).expect("pattern was checked to be ASCII");
+        let ret_val = unsafe {
+            GEOSRelatePattern_r(self.get_raw_context(), self.as_raw(), other.as_raw(), pattern.as_ptr())
+        };
+        check_geos_predicate(ret_val as _, PredicateType::RelatePattern)
+    }
+
     fn contains<G: Geom>(&self, other: &G) -> GResult<bool> {
         let ret_val = unsafe {
             GEOSContains_r(self.get_raw_context(), self.as_raw(), other.as_raw())
@@ -1592,6 +2966,7 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
     }
 
     fn buffer(&self, width: f64, quadsegs: i32) -> GResult<Geometry> {
+        trace_span!("buffer", self);
         assert!(quadsegs > 0);
         unsafe {
             let ptr = GEOSBuffer_r(
@@ -1605,6 +2980,7 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
     }
 
     fn buffer_with_params(&self, width: f64, buffer_params: &BufferParams) -> GResult<Geometry> {
+        trace_span!("buffer", self);
         unsafe {
             let ptr = GEOSBufferWithParams_r(
                 self.get_raw_context(),
@@ -1617,6 +2993,7 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
     }
 
     fn buffer_with_style(&self, width: f64, quadsegs: i32, end_cap_style: CapStyle, join_style: JoinStyle, mitre_limit: f64) -> GResult<Geometry> {
+        trace_span!("buffer", self);
         unsafe {
             let ptr = GEOSBufferWithStyle_r(
                 self.get_raw_context(),
@@ -1631,92 +3008,329 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         }
     }
 
+    fn single_sided_buffer(&self, width: f64, side: Side) -> GResult<Geometry> {
+        let params = BufferParams::builder().single_sided(true).build()?;
+        let width = match side {
+            Side::Left => width.abs(),
+            Side::Right => -width.abs(),
+        };
+        self.buffer_with_params(width, &params)
+    }
+
     fn is_empty(&self) -> GResult<bool> {
         let ret_val = unsafe { GEOSisEmpty_r(self.get_raw_context(), self.as_raw()) };
         check_geos_predicate(ret_val as _, PredicateType::IsEmpty)
     }
 
-    fn is_simple(&self) -> GResult<bool> {
-        let ret_val = unsafe { GEOSisSimple_r(self.get_raw_context(), self.as_raw()) };
-        check_geos_predicate(ret_val as _, PredicateType::IsSimple)
+    fn is_simple(&self) -> GResult<bool> {
+        let ret_val = unsafe { GEOSisSimple_r(self.get_raw_context(), self.as_raw()) };
+        check_geos_predicate(ret_val as _, PredicateType::IsSimple)
+    }
+
+    fn difference<G: Geom>(&self, other: &G) -> GResult<Geometry> {
+        trace_span!("difference", self, other);
+        unsafe {
+            let ptr = GEOSDifference_r(self.get_raw_context(), self.as_raw(), other.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "difference")
+        }
+    }
+
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn difference_prec<G: Geom>(&self, other: &G, grid_size: f64) -> GResult<Geometry> {
+        trace_span!("difference_prec", self, other);
+        unsafe {
+            let ptr = GEOSDifferencePrec_r(self.get_raw_context(), self.as_raw(), other.as_raw(), grid_size);
+            Geometry::new_from_raw(ptr, self.clone_context(), "difference_prec")
+        }
+    }
+
+    fn envelope(&self) -> GResult<Geometry> {
+        unsafe {
+            let ptr = GEOSEnvelope_r(self.get_raw_context(), self.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "envelope")
+        }
+    }
+
+    fn sym_difference<G: Geom>(&self, other: &G) -> GResult<Geometry> {
+        trace_span!("sym_difference", self, other);
+        unsafe {
+            let ptr = GEOSSymDifference_r(self.get_raw_context(), self.as_raw(), other.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "sym_difference")
+        }
+    }
+
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn sym_difference_prec<G: Geom>(&self, other: &G, grid_size: f64) -> GResult<Geometry> {
+        trace_span!("sym_difference_prec", self, other);
+        unsafe {
+            let ptr = GEOSSymDifferencePrec_r(self.get_raw_context(), self.as_raw(), other.as_raw(), grid_size);
+            Geometry::new_from_raw(ptr, self.clone_context(), "sym_difference_prec")
+        }
+    }
+
+    fn union<G: Geom>(&self, other: &G) -> GResult<Geometry> {
+        trace_span!("union", self, other);
+        unsafe {
+            let ptr = GEOSUnion_r(self.get_raw_context(), self.as_raw(), other.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "union")
+        }
+    }
+
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn union_prec<G: Geom>(&self, other: &G, grid_size: f64) -> GResult<Geometry> {
+        trace_span!("union_prec", self, other);
+        unsafe {
+            let ptr = GEOSUnionPrec_r(self.get_raw_context(), self.as_raw(), other.as_raw(), grid_size);
+            Geometry::new_from_raw(ptr, self.clone_context(), "union_prec")
+        }
+    }
+
+    fn get_centroid(&self) -> GResult<Geometry> {
+        unsafe {
+            let ptr = GEOSGetCentroid_r(self.get_raw_context(), self.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "get_centroid")
+        }
+    }
+
+    fn unary_union(&self) -> GResult<Geometry> {
+        trace_span!("unary_union", self);
+        unsafe {
+            let ptr = GEOSUnaryUnion_r(self.get_raw_context(), self.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "unary_union")
+        }
+    }
+
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn unary_union_prec(&self, grid_size: f64) -> GResult<Geometry> {
+        trace_span!("unary_union_prec", self);
+        unsafe {
+            let ptr = GEOSUnaryUnionPrec_r(self.get_raw_context(), self.as_raw(), grid_size);
+            Geometry::new_from_raw(ptr, self.clone_context(), "unary_union_prec")
+        }
+    }
+
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn disjoint_subset_union(&self) -> GResult<Geometry> {
+        trace_span!("disjoint_subset_union", self);
+        unsafe {
+            let ptr = GEOSDisjointSubsetUnion_r(self.get_raw_context(), self.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "disjoint_subset_union")
+        }
+    }
+
+    #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    fn coverage_union(&self) -> GResult<Geometry> {
+        trace_span!("coverage_union", self);
+        unsafe {
+            let ptr = GEOSCoverageUnion_r(self.get_raw_context(), self.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "coverage_union")
+        }
+    }
+
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn coverage_simplify(&self, tolerance: f64, preserve_boundary: bool) -> GResult<Geometry> {
+        trace_span!("coverage_simplify", self);
+        unsafe {
+            let ptr = GEOSCoverageSimplifyVW_r(
+                self.get_raw_context(),
+                self.as_raw(),
+                tolerance,
+                preserve_boundary as _,
+            );
+            Geometry::new_from_raw(ptr, self.clone_context(), "coverage_simplify")
+        }
+    }
+
+    fn voronoi<G: Geom>(
+        &self,
+        envelope: Option<&G>,
+        tolerance: f64,
+        only_edges: bool,
+    ) -> GResult<Geometry> {
+        unsafe {
+            let raw_voronoi = GEOSVoronoiDiagram_r(
+                self.get_raw_context(),
+                self.as_raw(),
+                envelope
+                    .map(|e| e.as_raw())
+                    .unwrap_or(std::ptr::null_mut()),
+                tolerance,
+                only_edges as _,
+            );
+            Geometry::new_from_raw(raw_voronoi, self.clone_context(), "voronoi")
+        }
+    }
+
+    fn intersection<G: Geom>(&self, other: &G) -> GResult<Geometry> {
+        trace_span!("intersection", self, other);
+        unsafe {
+            let ptr = GEOSIntersection_r(self.get_raw_context(), self.as_raw(), other.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "intersection")
+        }
+    }
+
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn intersection_prec<G: Geom>(&self, other: &G, grid_size: f64) -> GResult<Geometry> {
+        trace_span!("intersection_prec", self, other);
+        unsafe {
+            let ptr = GEOSIntersectionPrec_r(self.get_raw_context(), self.as_raw(), other.as_raw(), grid_size);
+            Geometry::new_from_raw(ptr, self.clone_context(), "intersection_prec")
+        }
+    }
+
+    fn clip_by_rect(&self, xmin: f64, ymin: f64, xmax: f64, ymax: f64) -> GResult<Geometry> {
+        unsafe {
+            let ptr = GEOSClipByRect_r(self.get_raw_context(), self.as_raw(), xmin, ymin, xmax, ymax);
+            Geometry::new_from_raw(ptr, self.clone_context(), "clip_by_rect")
+        }
     }
 
-    fn difference<G: Geom>(&self, other: &G) -> GResult<Geometry> {
+    fn convex_hull(&self) -> GResult<Geometry> {
         unsafe {
-            let ptr = GEOSDifference_r(self.get_raw_context(), self.as_raw(), other.as_raw());
-            Geometry::new_from_raw(ptr, self.clone_context(), "difference")
+            let ptr = GEOSConvexHull_r(self.get_raw_context(), self.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "convex_hull")
         }
     }
 
-    fn envelope(&self) -> GResult<Geometry> {
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn concave_hull(&self, ratio: f64, allow_holes: bool) -> GResult<Geometry> {
+        if !(0. ..=1.).contains(&ratio) {
+            return Err(Error::GenericError("ratio must be between 0 and 1".to_owned()));
+        }
         unsafe {
-            let ptr = GEOSEnvelope_r(self.get_raw_context(), self.as_raw());
-            Geometry::new_from_raw(ptr, self.clone_context(), "envelope")
+            let ptr = GEOSConcaveHull_r(self.get_raw_context(), self.as_raw(), ratio, allow_holes as _);
+            Geometry::new_from_raw(ptr, self.clone_context(), "concave_hull")
         }
     }
 
-    fn sym_difference<G: Geom>(&self, other: &G) -> GResult<Geometry> {
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn concave_hull_by_length(&self, length: f64, allow_holes: bool) -> GResult<Geometry> {
         unsafe {
-            let ptr = GEOSSymDifference_r(self.get_raw_context(), self.as_raw(), other.as_raw());
-            Geometry::new_from_raw(ptr, self.clone_context(), "sym_difference")
+            let ptr = GEOSConcaveHullByLength_r(self.get_raw_context(), self.as_raw(), length, allow_holes as _);
+            Geometry::new_from_raw(ptr, self.clone_context(), "concave_hull_by_length")
         }
     }
 
-    fn union<G: Geom>(&self, other: &G) -> GResult<Geometry> {
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn concave_hull_of_polygons(
+        &self,
+        length_ratio: f64,
+        is_tight: bool,
+        allow_holes: bool,
+    ) -> GResult<Geometry> {
+        if !matches!(self.geometry_type(), GeometryTypes::Polygon | GeometryTypes::MultiPolygon) {
+            return Err(Error::GenericError(
+                "Geometry must be a Polygon or a MultiPolygon".to_owned(),
+            ));
+        }
+        if !(0. ..=1.).contains(&length_ratio) {
+            return Err(Error::GenericError("length_ratio must be between 0 and 1".to_owned()));
+        }
         unsafe {
-            let ptr = GEOSUnion_r(self.get_raw_context(), self.as_raw(), other.as_raw());
-            Geometry::new_from_raw(ptr, self.clone_context(), "union")
+            let ptr = GEOSConcaveHullOfPolygons_r(self.get_raw_context(), self.as_raw(),
+                                                  length_ratio, is_tight as _, allow_holes as _);
+            Geometry::new_from_raw(ptr, self.clone_context(), "concave_hull_of_polygons")
         }
     }
 
-    fn get_centroid(&self) -> GResult<Geometry> {
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn polygon_hull_simplify(&self, is_outer: bool, vertex_num_fraction: f64) -> GResult<Geometry> {
+        self.polygon_hull_simplify_mode(is_outer, PolygonHullParameterMode::VertexRatio, vertex_num_fraction)
+    }
+
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn polygon_hull_simplify_mode(
+        &self,
+        is_outer: bool,
+        mode: PolygonHullParameterMode,
+        parameter: f64,
+    ) -> GResult<Geometry> {
+        if !matches!(self.geometry_type(), GeometryTypes::Polygon | GeometryTypes::MultiPolygon) {
+            return Err(Error::GenericError(
+                "Geometry must be a Polygon or a MultiPolygon".to_owned(),
+            ));
+        }
+        match mode {
+            PolygonHullParameterMode::VertexRatio if !(0. ..=1.).contains(&parameter) => {
+                return Err(Error::GenericError("parameter must be between 0 and 1".to_owned()));
+            }
+            PolygonHullParameterMode::AreaRatio if !(0. ..).contains(&parameter) => {
+                return Err(Error::GenericError("parameter must be positive".to_owned()));
+            }
+            _ => {}
+        }
         unsafe {
-            let ptr = GEOSGetCentroid_r(self.get_raw_context(), self.as_raw());
-            Geometry::new_from_raw(ptr, self.clone_context(), "get_centroid")
+            let ptr = GEOSPolygonHullSimplifyMode_r(self.get_raw_context(), self.as_raw(),
+                                                    is_outer as _, mode.into(), parameter);
+            Geometry::new_from_raw(ptr, self.clone_context(), "polygon_hull_simplify_mode")
         }
     }
 
-    fn unary_union(&self) -> GResult<Geometry> {
+    fn approximate_medial_axis(&self, densify_distance: f64) -> GResult<Geometry> {
+        crate::medial_axis::approximate_medial_axis(self, densify_distance)
+    }
+
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn maximum_inscribed_circle(&self, tolerance: f64) -> GResult<Geometry> {
         unsafe {
-            let ptr = GEOSUnaryUnion_r(self.get_raw_context(), self.as_raw());
-            Geometry::new_from_raw(ptr, self.clone_context(), "unary_union")
+            let ptr = GEOSMaximumInscribedCircle_r(self.get_raw_context(), self.as_raw(), tolerance);
+            Geometry::new_from_raw(ptr, self.clone_context(), "maximum_inscribed_circle")
         }
     }
 
-    fn voronoi<G: Geom>(
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn largest_empty_circle<G: Geom>(
         &self,
-        envelope: Option<&G>,
+        boundary: Option<&G>,
         tolerance: f64,
-        only_edges: bool,
     ) -> GResult<Geometry> {
         unsafe {
-            let raw_voronoi = GEOSVoronoiDiagram_r(
+            let ptr = GEOSLargestEmptyCircle_r(
                 self.get_raw_context(),
                 self.as_raw(),
-                envelope
-                    .map(|e| e.as_raw())
-                    .unwrap_or(std::ptr::null_mut()),
+                boundary.map(|b| b.as_raw()).unwrap_or(std::ptr::null_mut()),
                 tolerance,
-                only_edges as _,
             );
-            Geometry::new_from_raw(raw_voronoi, self.clone_context(), "voronoi")
+            Geometry::new_from_raw(ptr, self.clone_context(), "largest_empty_circle")
         }
     }
 
-    fn intersection<G: Geom>(&self, other: &G) -> GResult<Geometry> {
-        unsafe {
-            let ptr = GEOSIntersection_r(self.get_raw_context(), self.as_raw(), other.as_raw());
-            Geometry::new_from_raw(ptr, self.clone_context(), "intersection")
-        }
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    fn label_anchor(&self, tolerance: f64) -> GResult<LabelAnchor> {
+        crate::label::label_anchor(self, tolerance)
     }
 
-    fn convex_hull(&self) -> GResult<Geometry> {
+    #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    fn minimum_bounding_circle(&self) -> GResult<(Geometry, BoundingCircle)> {
         unsafe {
-            let ptr = GEOSConvexHull_r(self.get_raw_context(), self.as_raw());
-            Geometry::new_from_raw(ptr, self.clone_context(), "convex_hull")
+            let mut radius = 0.;
+            let mut center = std::ptr::null_mut();
+            let ptr = GEOSMinimumBoundingCircle_r(
+                self.get_raw_context(),
+                self.as_raw(),
+                &mut radius,
+                &mut center,
+            );
+            let polygon = Geometry::new_from_raw(ptr, self.clone_context(), "minimum_bounding_circle")?;
+            if center.is_null() {
+                return Err(Error::GenericError(
+                    "GEOSMinimumBoundingCircle_r failed".to_owned(),
+                ));
+            }
+            let center = Geometry::new_from_raw(center, self.clone_context(), "minimum_bounding_circle")?;
+            let circle = BoundingCircle {
+                center: (center.get_x()?, center.get_y()?),
+                radius,
+            };
+            Ok((polygon, circle))
         }
     }
 
+    #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    fn compactness(&self) -> GResult<CompactnessMetrics> {
+        crate::compactness::compactness(self)
+    }
+
     fn boundary(&self) -> GResult<Geometry> {
         unsafe {
             let ptr = GEOSBoundary_r(self.get_raw_context(), self.as_raw());
@@ -1773,6 +3387,14 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         }
     }
 
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    fn distance_within<G: Geom>(&self, other: &G, distance: f64) -> GResult<bool> {
+        let ret_val = unsafe {
+            GEOSDistanceWithin_r(self.get_raw_context(), self.as_raw(), other.as_raw(), distance)
+        };
+        check_geos_predicate(ret_val as _, PredicateType::DistanceWithin)
+    }
+
     fn hausdorff_distance<G: Geom>(&self, other: &G) -> GResult<f64> {
         let mut distance = 0.;
         unsafe {
@@ -1786,6 +3408,11 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
     }
 
     fn hausdorff_distance_densify<G: Geom>(&self, other: &G, distance_frac: f64) -> GResult<f64> {
+        if !(distance_frac > 0. && distance_frac <= 1.) {
+            return Err(Error::GenericError(format!(
+                "distance_frac must be in the (0, 1] range, got {distance_frac}"
+            )));
+        }
         let mut distance = 0.;
         unsafe {
             let ret = GEOSHausdorffDistanceDensify_r(
@@ -1813,6 +3440,11 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
 
     #[cfg(any(feature = "v3_7_0", feature = "dox"))]
     fn frechet_distance_densify<G: Geom>(&self, other: &G, distance_frac: f64) -> GResult<f64> {
+        if !(distance_frac > 0. && distance_frac <= 1.) {
+            return Err(Error::GenericError(format!(
+                "distance_frac must be in the (0, 1] range, got {distance_frac}"
+            )));
+        }
         let mut distance = 0.;
         unsafe {
             let ret = GEOSFrechetDistanceDensify_r(
@@ -2006,6 +3638,15 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         }
     }
 
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    fn make_valid_with_params(&self, params: &MakeValidParams) -> GResult<Geometry> {
+        unsafe {
+            let ptr = GEOSMakeValidWithParams_r(self.get_raw_context(), self.as_raw(),
+                                                params.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "make_valid_with_params")
+        }
+    }
+
     fn get_num_geometries(&self) -> GResult<usize> {
         unsafe {
             let ret = GEOSGetNumGeometries_r(self.get_raw_context(), self.as_raw());
@@ -2167,6 +3808,10 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         }
     }
 
+    fn line_substring(&self, start_fraction: f64, end_fraction: f64) -> GResult<Geometry> {
+        crate::linear_referencing::line_substring(self, start_fraction, end_fraction)
+    }
+
     fn project<G: Geom>(&self, p: &G) -> GResult<f64> {
         if p.geometry_type() != GeometryTypes::Point {
             return Err(Error::GenericError("Second geometry must be a Point".to_owned()));
@@ -2209,8 +3854,29 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         join_style: JoinStyle,
         mitre_limit: f64,
     ) -> GResult<Geometry> {
-        if self.geometry_type() != GeometryTypes::LineString {
-            return Err(Error::GenericError("Geometry must be a LineString".to_owned()));
+        match self.geometry_type() {
+            GeometryTypes::LineString => {}
+            GeometryTypes::MultiLineString => {
+                // Each part is offset separately, the offset of a part possibly being multiple
+                // lines.
+                let mut lines = Vec::new();
+                for i in 0..self.get_num_geometries()? {
+                    let offset = self.get_geometry_n(i)?.offset_curve(width, quadrant_segments,
+                                                                      join_style, mitre_limit)?;
+                    for j in 0..offset.get_num_geometries()? {
+                        let line = Geom::clone(&offset.get_geometry_n(j)?);
+                        if !line.is_empty()? {
+                            lines.push(line);
+                        }
+                    }
+                }
+                return Geometry::create_multiline_string(lines);
+            }
+            _ => {
+                return Err(Error::GenericError(
+                    "Geometry must be a LineString or a MultiLineString".to_owned(),
+                ))
+            }
         }
         unsafe {
             let ptr = GEOSOffsetCurve_r(self.get_raw_context(), self.as_raw(), width,
@@ -2219,6 +3885,22 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         }
     }
 
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    fn densify(&self, tolerance: f64) -> GResult<Geometry> {
+        unsafe {
+            let ptr = GEOSDensify_r(self.get_raw_context(), self.as_raw(), tolerance);
+            Geometry::new_from_raw(ptr, self.clone_context(), "densify")
+        }
+    }
+
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    fn remove_repeated_points(&self, tolerance: f64) -> GResult<Geometry> {
+        unsafe {
+            let ptr = GEOSRemoveRepeatedPoints_r(self.get_raw_context(), self.as_raw(), tolerance);
+            Geometry::new_from_raw(ptr, self.clone_context(), "remove_repeated_points")
+        }
+    }
+
     fn point_on_surface(&self) -> GResult<Geometry> {
         unsafe {
             let ptr = GEOSPointOnSurface_r(self.get_raw_context(), self.as_raw());
@@ -2269,6 +3951,7 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
     }
 
     fn to_hex(&self) -> GResult<CVec<u8>> {
+        trace_span!("to_hex", self);
         let mut size = 0;
         unsafe {
             let ptr = GEOSGeomToHEX_buf_r(self.get_raw_context(), self.as_raw(), &mut size);
@@ -2283,6 +3966,7 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
     }
 
     fn to_wkb(&self) -> GResult<CVec<u8>> {
+        trace_span!("to_wkb", self);
         let mut size = 0;
         unsafe {
             let ptr = GEOSGeomToWKB_buf_r(self.get_raw_context(), self.as_raw(), &mut size);
@@ -2296,6 +3980,22 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         }
     }
 
+    fn to_wkb_into(&self, buffer: &mut Vec<u8>) -> GResult<()> {
+        trace_span!("to_wkb", self);
+        let mut size = 0;
+        unsafe {
+            let ptr = GEOSGeomToWKB_buf_r(self.get_raw_context(), self.as_raw(), &mut size);
+            if ptr.is_null() {
+                return Err(Error::NoConstructionFromNullPtr(
+                    "Geometry::to_wkb_into failed: GEOSGeomToWKB_buf_r returned null pointer".to_owned())
+                );
+            }
+            buffer.extend_from_slice(std::slice::from_raw_parts(ptr, size as _));
+            GEOSFree_r(self.get_raw_context(), ptr as *mut _);
+            Ok(())
+        }
+    }
+
     #[allow(clippy::needless_lifetimes)]
     fn to_prepared_geom<'c>(&'c self) -> GResult<PreparedGeometry> {
         PreparedGeometry::new(self)
@@ -2334,6 +4034,89 @@ impl$(<$lt>)? Geom for $ty_name$(<$lt>)? {
         }
     }
 
+    fn subdivide(&self, max_vertices: usize) -> GResult<Vec<Geometry>> {
+        crate::subdivide::subdivide(self, max_vertices)
+    }
+
+    fn split<G: Geom>(&self, blade: &G) -> GResult<Geometry> {
+        crate::split::split(self, blade)
+    }
+
+    fn geohash(&self, precision: usize) -> GResult<String> {
+        crate::geohash::geohash(self, precision)
+    }
+
+    fn geohash_cover(&self, precision: usize) -> GResult<Vec<String>> {
+        crate::geohash::geohash_cover(self, precision)
+    }
+
+    fn quantize_to_tile(&self, tile_bounds: &TileBounds, extent: u32) -> GResult<Geometry> {
+        crate::tile::quantize(self, tile_bounds, extent)
+    }
+
+    fn repair(&self, strategy: RepairStrategy) -> GResult<(Geometry, RepairReport)> {
+        crate::repair::repair(self, &strategy)
+    }
+
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn locate_between_measures(&self, m_start: f64, m_end: f64) -> GResult<Geometry> {
+        crate::measures::locate_between_measures(self, m_start, m_end)
+    }
+
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    fn add_measure(&self, start: f64, end: f64) -> GResult<Geometry> {
+        crate::measures::add_measure(self, start, end)
+    }
+
+    fn segment_bearings(&self) -> GResult<Vec<f64>> {
+        crate::bearing::segment_bearings(self)
+    }
+
+    fn destination(&self, bearing: f64, distance: f64) -> GResult<Geometry> {
+        crate::bearing::destination(self, bearing, distance)
+    }
+
+    #[cfg(feature = "h3")]
+    fn h3_cells(&self, resolution: h3o::Resolution) -> GResult<Vec<h3o::CellIndex>> {
+        crate::h3::h3_cells(self, resolution)
+    }
+
+    fn get(&self, n: usize) -> Option<ConstGeometry> {
+        if n < self.get_num_geometries().unwrap_or(0) {
+            self.get_geometry_n(n).ok()
+        } else {
+            None
+        }
+    }
+
+    fn geometry(&self, n: usize) -> ConstGeometry {
+        match self.get(n) {
+            Some(g) => g,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {n}",
+                self.get_num_geometries().unwrap_or(0),
+            ),
+        }
+    }
+
+    fn get_interior_ring(&self, n: usize) -> Option<ConstGeometry> {
+        if n < self.get_num_interior_rings().unwrap_or(0) {
+            self.get_interior_ring_n(n as _).ok()
+        } else {
+            None
+        }
+    }
+
+    fn interior_ring(&self, n: usize) -> ConstGeometry {
+        match self.get_interior_ring(n) {
+            Some(g) => g,
+            None => panic!(
+                "interior ring index out of bounds: the len is {} but the index is {n}",
+                self.get_num_interior_rings().unwrap_or(0),
+            ),
+        }
+    }
+
     #[cfg(feature = "v3_11_0")]
     fn transform_xy<F: Fn(f64, f64) -> Option<(f64, f64)>>(&self, on_transform_point: F) -> GResult<Geometry> {
         unsafe {
@@ -2406,6 +4189,7 @@ impl Geometry {
     /// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)").expect("Invalid geometry");
     /// ```
     pub fn new_from_wkt(wkt: &str) -> GResult<Geometry> {
+        trace_span!("new_from_wkt", bytes = wkt.len());
         match ContextHandle::init_e(Some("Geometry::new_from_wkt")) {
             Ok(context_handle) => match CString::new(wkt) {
                 Ok(c_str) => unsafe {
@@ -2438,6 +4222,7 @@ impl Geometry {
     /// assert_eq!(point_geom.equals(&new_geom), Ok(true));
     /// ```
     pub fn new_from_hex(hex: &[u8]) -> GResult<Geometry> {
+        trace_span!("new_from_hex", bytes = hex.len());
         match ContextHandle::init_e(Some("Geometry::new_from_hex")) {
             Ok(context) => unsafe {
                 let ptr = GEOSGeomFromHEX_buf_r(context.as_raw(), hex.as_ptr(), hex.len());
@@ -2463,6 +4248,7 @@ impl Geometry {
     /// assert_eq!(point_geom.equals(&new_geom), Ok(true));
     /// ```
     pub fn new_from_wkb(wkb: &[u8]) -> GResult<Geometry> {
+        trace_span!("new_from_wkb", bytes = wkb.len());
         match ContextHandle::init_e(Some("Geometry::new_from_wkb")) {
             Ok(context) => unsafe {
                 let ptr = GEOSGeomFromWKB_buf_r(context.as_raw(), wkb.as_ptr(), wkb.len());
@@ -2472,6 +4258,56 @@ impl Geometry {
         }
     }
 
+    /// Same as [`Geometry::new_from_wkt`] but first checks the given `limits` on `wkt`,
+    /// returning [`Error::LimitExceeded`] if one of them is exceeded. Useful to parse untrusted
+    /// input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Error, Geometry, LimitKind, ReaderLimits};
+    ///
+    /// let limits = ReaderLimits {
+    ///     max_bytes: Some(16),
+    ///     ..Default::default()
+    /// };
+    /// assert!(Geometry::new_from_wkt_with_limits("POINT (2.5 2.5)", &limits).is_ok());
+    /// assert_eq!(
+    ///     Geometry::new_from_wkt_with_limits("POINT (2.5 2.5 2.5)", &limits).err(),
+    ///     Some(Error::LimitExceeded(LimitKind::Bytes, 16)),
+    /// );
+    /// ```
+    pub fn new_from_wkt_with_limits(wkt: &str, limits: &ReaderLimits) -> GResult<Geometry> {
+        limits.check_wkt(wkt)?;
+        Geometry::new_from_wkt(wkt)
+    }
+
+    /// Same as [`Geometry::new_from_wkb`] but first checks the given `limits` on `wkb`,
+    /// returning [`Error::LimitExceeded`] if one of them is exceeded. Useful to parse untrusted
+    /// input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Error, Geom, Geometry, LimitKind, ReaderLimits};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT (1 1, 2 2, 3 3)").expect("Invalid geometry");
+    /// let wkb = geom.to_wkb().expect("conversion to WKB failed");
+    ///
+    /// let limits = ReaderLimits {
+    ///     max_coordinates: Some(2),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     Geometry::new_from_wkb_with_limits(wkb.as_ref(), &limits).err(),
+    ///     Some(Error::LimitExceeded(LimitKind::Coordinates, 2)),
+    /// );
+    /// ```
+    pub fn new_from_wkb_with_limits(wkb: &[u8], limits: &ReaderLimits) -> GResult<Geometry> {
+        limits.check_wkb(wkb)?;
+        Geometry::new_from_wkb(wkb)
+    }
+
     /// Creates an areal geometry formed by the constituent linework of given geometry.
     ///
     /// You can find new illustrations on [postgis](https://postgis.net/docs/ST_BuildArea.html)
@@ -2494,6 +4330,21 @@ impl Geometry {
     /// assert_eq!(build_area_geom.to_wkt_precision(0).unwrap(),
     ///            "POLYGON ((0 0, 0 4, 4 4, 4 0, 0 0), (1 1, 3 1, 3 3, 1 3, 1 1))");
     /// ```
+    ///
+    /// Rings don't need to be closed by a single line, nor to be tagged as outer or inner, which
+    /// makes it suitable to build OpenStreetMap multipolygon relations from their ways:
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let ways = Geometry::new_from_wkt("MULTILINESTRING((0 0, 4 0, 4 4), \
+    ///                                                     (4 4, 0 4, 0 0), \
+    ///                                                     (1 1, 1 3, 3 3, 3 1, 1 1))")
+    ///             .expect("Invalid geometry");
+    ///
+    /// let area = ways.build_area().expect("build_area failed");
+    /// assert_eq!(area.area(), Ok(12.));
+    /// ```
     #[cfg(any(feature = "v3_8_0", feature = "dox"))]
     pub fn build_area(&self) -> GResult<Geometry> {
         unsafe {
@@ -2558,6 +4409,46 @@ impl Geometry {
         }
     }
 
+    /// Same as [`Geometry::polygonize`], but returns a single valid polygonal geometry: a
+    /// `Polygon`, a `MultiPolygon` or an empty `GeometryCollection` if no polygon could be built.
+    /// Polygons which would share an edge with another one are dropped, so the linework should
+    /// be correctly noded.
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, GeometryTypes};
+    ///
+    /// let lines = vec![
+    ///     Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)").unwrap(),
+    ///     Geometry::new_from_wkt("LINESTRING(10 10, 0 10, 0 0)").unwrap(),
+    /// ];
+    ///
+    /// let polygon = Geometry::polygonize_valid(&lines).expect("polygonize_valid failed");
+    /// assert_eq!(polygon.geometry_type(), GeometryTypes::Polygon);
+    /// assert_eq!(polygon.area(), Ok(100.));
+    /// ```
+    #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    pub fn polygonize_valid<T: Borrow<Geometry>>(geometries: &[T]) -> GResult<Geometry> {
+        unsafe {
+            let context = match geometries.first() {
+                Some(g) => g.borrow().clone_context(),
+                None => match ContextHandle::init_e(Some("Geometry::polygonize_valid")) {
+                    Ok(context) => Arc::new(context),
+                    Err(e) => return Err(e),
+                },
+            };
+            let geoms = geometries
+                .iter()
+                .map(|g| g.borrow().as_raw() as *const _)
+                .collect::<Vec<_>>();
+            let ptr = GEOSPolygonize_valid_r(context.as_raw(), geoms.as_ptr(), geoms.len() as _);
+            Geometry::new_from_raw(ptr, context, "polygonize_valid")
+        }
+    }
+
     pub fn polygonizer_get_cut_edges<T: Borrow<Geometry>>(
         &self,
         geometries: &[T],
@@ -2580,6 +4471,30 @@ impl Geometry {
         }
     }
 
+    /// Polygonizes the linework of `geometries` (as [`Geometry::polygonize`] does), also
+    /// returning the lines which couldn't be used: cut edges, dangles and invalid rings. They
+    /// are what to look at when cleaning the topology of the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let lines = vec![
+    ///     Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10, 0 10, 0 0)").unwrap(),
+    ///     Geometry::new_from_wkt("LINESTRING(10 10, 15 15)").unwrap(),
+    /// ];
+    ///
+    /// let polygonized = Geometry::polygonize_detailed(&lines).expect("polygonize_detailed failed");
+    /// assert_eq!(polygonized.polygons().area(), Ok(100.));
+    /// assert_eq!(polygonized.dangles().length(), Ok(50f64.sqrt()));
+    /// assert_eq!(polygonized.cut_edges().is_empty(), Ok(true));
+    /// assert_eq!(polygonized.invalid_rings().is_empty(), Ok(true));
+    /// ```
+    pub fn polygonize_detailed<T: Borrow<Geometry>>(geometries: &[T]) -> GResult<Polygonized> {
+        crate::polygonize::polygonize_detailed(geometries)
+    }
+
     /// Merges `Multi Line String` geometry into a (set of) `Line String`.
     ///
     /// ### Warning
@@ -2608,7 +4523,36 @@ impl Geometry {
         }
     }
 
-    /// Reverses the order of the vertexes.
+    /// Same as [`Geometry::line_merge`], but only merges lines which have the same direction:
+    /// the end of a line can only be joined to the start of another one.
+    ///
+    /// Available using the `v3_11_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, GeometryTypes};
+    ///
+    /// let lines = Geometry::new_from_wkt("MULTILINESTRING((0 0, 1 0), (2 0, 1 0), (2 0, 3 0))")
+    ///                      .expect("Invalid geometry");
+    ///
+    /// let merged = lines.line_merge().expect("line merge failed");
+    /// assert_eq!(merged.geometry_type(), GeometryTypes::LineString);
+    ///
+    /// let merged = lines.line_merge_directed().expect("directed line merge failed");
+    /// assert_eq!(merged.geometry_type(), GeometryTypes::MultiLineString);
+    /// assert_eq!(merged.get_num_geometries(), Ok(3));
+    /// ```
+    #[cfg(any(feature = "v3_11_0", feature = "dox"))]
+    pub fn line_merge_directed(&self) -> GResult<Geometry> {
+        unsafe {
+            let ptr = GEOSLineMergeDirected_r(self.get_raw_context(), self.as_raw());
+            Geometry::new_from_raw(ptr, self.clone_context(), "line_merge_directed")
+        }
+    }
+
+    /// Reverses the order of the vertexes. For polygons, it reverses the orientation of their
+    /// rings (see [`Geometry::orient_polygons`] to enforce a given orientation).
     ///
     /// Available using the `v3_7_0` feature.
     ///
@@ -2634,6 +4578,40 @@ impl Geometry {
         }
     }
 
+    /// Orients the rings of the polygons of `self` in place: exterior rings are made clockwise
+    /// if `exterior_cw` is `true` and counter-clockwise otherwise, interior rings get the
+    /// opposite orientation. Other geometries are left untouched.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let mut geom = Geometry::new_from_wkt("POLYGON((0 0, 0 10, 10 10, 10 0, 0 0))")
+    ///                         .expect("invalid geometry");
+    /// geom.orient_polygons(false).expect("orient_polygons failed");
+    ///
+    /// assert_eq!(
+    ///     geom.to_wkt_precision(0).unwrap(),
+    ///     "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0))",
+    /// );
+    /// ```
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    pub fn orient_polygons(&mut self, exterior_cw: bool) -> GResult<()> {
+        let ret_val = unsafe {
+            GEOSOrientPolygons_r(self.get_raw_context(), self.as_raw_mut(), exterior_cw as _)
+        };
+        if ret_val == -1 {
+            Err(Error::GenericError(
+                "GEOSOrientPolygons_r failed".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Returns a simplified version of the given geometry.
     pub fn simplify(&self, tolerance: f64) -> GResult<Geometry> {
         unsafe {
@@ -2652,6 +4630,42 @@ impl Geometry {
         }
     }
 
+    /// Returns a version of `self` simplified with [`Geometry::topology_preserve_simplify`] for
+    /// each of the given `tolerances`, in the same order, as needed for zoom-dependent rendering.
+    ///
+    /// Levels are computed by increasing tolerance, each one from the previous (smaller) level
+    /// instead of from `self`, which is much faster for detailed geometries. Because of this, a
+    /// level can be slightly different from simplifying `self` directly, but it stays within the
+    /// sum of the tolerances of the levels it was computed from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 1 0.4, 2 0, 3 3, 4 0)").unwrap();
+    ///
+    /// let levels = geom.simplify_pyramid(&[5., 0.5]).expect("simplify_pyramid failed");
+    /// assert_eq!(levels.len(), 2);
+    /// assert_eq!(levels[0].to_wkt_precision(0).unwrap(), "LINESTRING (0 0, 4 0)");
+    /// assert_eq!(levels[1].to_wkt_precision(0).unwrap(), "LINESTRING (0 0, 2 0, 3 3, 4 0)");
+    /// ```
+    pub fn simplify_pyramid(&self, tolerances: &[f64]) -> GResult<Vec<Geometry>> {
+        let mut order: Vec<usize> = (0..tolerances.len()).collect();
+        order.sort_by(|&a, &b| tolerances[a].total_cmp(&tolerances[b]));
+        let mut levels: Vec<Option<Geometry>> = (0..tolerances.len()).map(|_| None).collect();
+        let mut previous: Option<usize> = None;
+        for index in order {
+            let source = match previous {
+                Some(previous) => levels[previous].as_ref().unwrap_or(self),
+                None => self,
+            };
+            levels[index] = Some(source.topology_preserve_simplify(tolerances[index])?);
+            previous = Some(index);
+        }
+        Ok(levels.into_iter().flatten().collect())
+    }
+
     pub(crate) unsafe fn new_from_raw(
         ptr: *mut GEOSGeometry,
         context: Arc<ContextHandle>,
@@ -0,0 +1,279 @@
+use crate::error::{Error, GResult};
+use crate::{CoordSeq, Geometry, GeometryTypes};
+
+type Coords = Vec<Vec<f64>>;
+
+/// Fluent API to assemble a [`Geometry`] from raw coordinates.
+///
+/// Coordinates are given as slices of `[x, y]` or `[x, y, z]` values. The structure of the
+/// geometry is only checked when calling [`GeometryBuilder::build`].
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, GeometryBuilder};
+///
+/// let polygon = GeometryBuilder::polygon()
+///     .exterior(&[[0., 0.], [10., 0.], [10., 10.], [0., 10.], [0., 0.]])
+///     .hole(&[[1., 1.], [2., 1.], [2., 2.], [1., 1.]])
+///     .srid(4326)
+///     .build()
+///     .expect("build failed");
+///
+/// assert_eq!(polygon.to_wkt_precision(0).unwrap(),
+///            "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (1 1, 2 1, 2 2, 1 1))");
+/// assert_eq!(polygon.get_srid(), Ok(4326));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GeometryBuilder {
+    type_: GeometryTypes,
+    // One entry per part (a polygon, a line...), each part being a list of coordinate lists
+    // (the rings of a polygon for example).
+    parts: Vec<Vec<Coords>>,
+    srid: Option<usize>,
+    error: Option<String>,
+}
+
+impl GeometryBuilder {
+    fn new(type_: GeometryTypes) -> GeometryBuilder {
+        GeometryBuilder {
+            type_,
+            parts: Vec::new(),
+            srid: None,
+            error: None,
+        }
+    }
+
+    /// Starts building a `Point`. Use [`GeometryBuilder::coords`] to set its coordinate.
+    pub fn point() -> GeometryBuilder {
+        GeometryBuilder::new(GeometryTypes::Point)
+    }
+
+    /// Starts building a `LineString`. Use [`GeometryBuilder::coords`] to set its coordinates.
+    pub fn line_string() -> GeometryBuilder {
+        GeometryBuilder::new(GeometryTypes::LineString)
+    }
+
+    /// Starts building a `LinearRing`. Use [`GeometryBuilder::coords`] to set its coordinates.
+    pub fn linear_ring() -> GeometryBuilder {
+        GeometryBuilder::new(GeometryTypes::LinearRing)
+    }
+
+    /// Starts building a `Polygon`. Use [`GeometryBuilder::exterior`] and
+    /// [`GeometryBuilder::hole`] to set its rings.
+    pub fn polygon() -> GeometryBuilder {
+        GeometryBuilder::new(GeometryTypes::Polygon)
+    }
+
+    /// Starts building a `MultiPoint`. Use [`GeometryBuilder::coords`] to set its points.
+    pub fn multi_point() -> GeometryBuilder {
+        GeometryBuilder::new(GeometryTypes::MultiPoint)
+    }
+
+    /// Starts building a `MultiLineString`. Use [`GeometryBuilder::line`] to add lines.
+    pub fn multi_line_string() -> GeometryBuilder {
+        GeometryBuilder::new(GeometryTypes::MultiLineString)
+    }
+
+    /// Starts building a `MultiPolygon`. Each call to [`GeometryBuilder::exterior`] starts a new
+    /// polygon, [`GeometryBuilder::hole`] adds a hole to the last one.
+    pub fn multi_polygon() -> GeometryBuilder {
+        GeometryBuilder::new(GeometryTypes::MultiPolygon)
+    }
+
+    fn check_type(&mut self, method: &str, allowed: &[GeometryTypes]) -> bool {
+        if self.error.is_none() && !allowed.contains(&self.type_) {
+            self.error = Some(format!(
+                "`{method}` cannot be used to build a {:?}",
+                self.type_
+            ));
+        }
+        self.error.is_none()
+    }
+
+    /// Sets the coordinates of a `Point` (a single coordinate), a `LineString`, a `LinearRing`
+    /// or a `MultiPoint` (one point per coordinate).
+    pub fn coords<T: AsRef<[f64]>>(mut self, coords: &[T]) -> GeometryBuilder {
+        if self.check_type(
+            "coords",
+            &[
+                GeometryTypes::Point,
+                GeometryTypes::LineString,
+                GeometryTypes::LinearRing,
+                GeometryTypes::MultiPoint,
+            ],
+        ) {
+            self.parts = vec![vec![to_coords(coords)]];
+        }
+        self
+    }
+
+    /// Adds a line to a `MultiLineString`.
+    pub fn line<T: AsRef<[f64]>>(mut self, coords: &[T]) -> GeometryBuilder {
+        if self.check_type("line", &[GeometryTypes::MultiLineString]) {
+            self.parts.push(vec![to_coords(coords)]);
+        }
+        self
+    }
+
+    /// Sets the exterior ring of a `Polygon`, or starts a new polygon in a `MultiPolygon`.
+    pub fn exterior<T: AsRef<[f64]>>(mut self, coords: &[T]) -> GeometryBuilder {
+        if self.check_type(
+            "exterior",
+            &[GeometryTypes::Polygon, GeometryTypes::MultiPolygon],
+        ) {
+            if self.type_ == GeometryTypes::Polygon && !self.parts.is_empty() {
+                self.error = Some("the exterior ring of a Polygon can only be set once".to_owned());
+            } else {
+                self.parts.push(vec![to_coords(coords)]);
+            }
+        }
+        self
+    }
+
+    /// Adds a hole to the `Polygon` (or to the last polygon of a `MultiPolygon`).
+    pub fn hole<T: AsRef<[f64]>>(mut self, coords: &[T]) -> GeometryBuilder {
+        if self.check_type(
+            "hole",
+            &[GeometryTypes::Polygon, GeometryTypes::MultiPolygon],
+        ) {
+            match self.parts.last_mut() {
+                Some(rings) => rings.push(to_coords(coords)),
+                None => self.error = Some("`exterior` must be set before `hole`".to_owned()),
+            }
+        }
+        self
+    }
+
+    /// Sets the SRID of the built geometry.
+    pub fn srid(mut self, srid: usize) -> GeometryBuilder {
+        self.srid = Some(srid);
+        self
+    }
+
+    /// Creates the [`Geometry`]. If no coordinates were given, an empty geometry is returned.
+    ///
+    /// Fails if the builder methods were misused, if coordinates don't all have the same
+    /// dimensions or if a part doesn't have enough coordinates (lines need at least 2 and rings
+    /// at least 4, with the same first and last coordinate). Topological validity isn't checked,
+    /// use [`Geom::is_valid`](crate::Geom::is_valid) for that.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::GeometryBuilder;
+    ///
+    /// // The ring isn't closed.
+    /// assert!(GeometryBuilder::polygon()
+    ///     .exterior(&[[0., 0.], [10., 0.], [10., 10.], [0., 10.]])
+    ///     .build()
+    ///     .is_err());
+    /// // A line string has no holes.
+    /// assert!(GeometryBuilder::line_string()
+    ///     .hole(&[[0., 0.], [10., 0.], [10., 10.], [0., 0.]])
+    ///     .build()
+    ///     .is_err());
+    /// ```
+    pub fn build(self) -> GResult<Geometry> {
+        if let Some(e) = self.error {
+            return Err(Error::ImpossibleOperation(e));
+        }
+        let mut geom = if self.parts.is_empty() {
+            match self.type_ {
+                GeometryTypes::Point => Geometry::create_empty_point()?,
+                GeometryTypes::LineString => Geometry::create_empty_line_string()?,
+                GeometryTypes::Polygon => Geometry::create_empty_polygon()?,
+                GeometryTypes::LinearRing => {
+                    return Err(Error::InvalidGeometry(
+                        "a LinearRing cannot be empty".to_owned(),
+                    ))
+                }
+                type_ => Geometry::create_empty_collection(type_)?,
+            }
+        } else {
+            build_parts(self.type_, &self.parts)?
+        };
+        if let Some(srid) = self.srid {
+            geom.set_srid(srid);
+        }
+        Ok(geom)
+    }
+}
+
+fn to_coords<T: AsRef<[f64]>>(coords: &[T]) -> Coords {
+    coords.iter().map(|c| c.as_ref().to_vec()).collect()
+}
+
+fn build_parts(type_: GeometryTypes, parts: &[Vec<Coords>]) -> GResult<Geometry> {
+    match type_ {
+        GeometryTypes::Point => {
+            let coords = &parts[0][0];
+            if coords.len() != 1 {
+                return Err(Error::InvalidGeometry(format!(
+                    "a Point needs exactly 1 coordinate, got {}",
+                    coords.len()
+                )));
+            }
+            CoordSeq::new_from_vec(coords)?.create_point()
+        }
+        GeometryTypes::LineString => build_line_string(&parts[0][0]),
+        GeometryTypes::LinearRing => build_linear_ring(&parts[0][0]),
+        GeometryTypes::Polygon => build_polygon(&parts[0]),
+        GeometryTypes::MultiPoint => {
+            let points = parts[0][0]
+                .iter()
+                .map(|c| CoordSeq::new_from_vec(&[c])?.create_point())
+                .collect::<GResult<Vec<_>>>()?;
+            Geometry::create_multipoint(points)
+        }
+        GeometryTypes::MultiLineString => {
+            let lines = parts
+                .iter()
+                .map(|p| build_line_string(&p[0]))
+                .collect::<GResult<Vec<_>>>()?;
+            Geometry::create_multiline_string(lines)
+        }
+        GeometryTypes::MultiPolygon => {
+            let polygons = parts
+                .iter()
+                .map(|p| build_polygon(p))
+                .collect::<GResult<Vec<_>>>()?;
+            Geometry::create_multipolygon(polygons)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn build_line_string(coords: &Coords) -> GResult<Geometry> {
+    if coords.len() < 2 {
+        return Err(Error::InvalidGeometry(format!(
+            "a LineString needs at least 2 coordinates, got {}",
+            coords.len()
+        )));
+    }
+    CoordSeq::new_from_vec(coords)?.create_line_string()
+}
+
+fn build_linear_ring(coords: &Coords) -> GResult<Geometry> {
+    if coords.len() < 4 {
+        return Err(Error::InvalidGeometry(format!(
+            "a LinearRing needs at least 4 coordinates, got {}",
+            coords.len()
+        )));
+    }
+    if coords.first() != coords.last() {
+        return Err(Error::InvalidGeometry(
+            "a LinearRing must be closed".to_owned(),
+        ));
+    }
+    CoordSeq::new_from_vec(coords)?.create_linear_ring()
+}
+
+fn build_polygon(rings: &[Coords]) -> GResult<Geometry> {
+    let exterior = build_linear_ring(&rings[0])?;
+    let holes = rings[1..]
+        .iter()
+        .map(build_linear_ring)
+        .collect::<GResult<Vec<_>>>()?;
+    Geometry::create_polygon(exterior, holes)
+}
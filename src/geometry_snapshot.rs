@@ -0,0 +1,88 @@
+use crate::error::GResult;
+use crate::functions::bounds;
+use crate::{Geom, Geometry, GeometryTypes, OutputDimension, WKBWriter};
+use std::sync::Arc;
+
+/// Immutable copy of a geometry, stored as WKB along with its envelope, SRID and type.
+///
+/// Unlike [`Geometry`], `GeometrySnapshot` doesn't hold any GEOS pointer: it is `Send + Sync`,
+/// cheap to clone (the WKB is shared) and can be converted back to a [`Geometry`] with
+/// [`GeometrySnapshot::to_geometry`]. It is meant to be stored in caches or sent through
+/// channels.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry, GeometrySnapshot, GeometryTypes};
+///
+/// let mut geom = Geometry::new_from_wkt("LINESTRING(0 0, 2 1)").unwrap();
+/// geom.set_srid(4326);
+/// let snapshot = GeometrySnapshot::new(&geom).expect("failed to create snapshot");
+///
+/// let handle = std::thread::spawn(move || {
+///     assert_eq!(snapshot.geometry_type(), GeometryTypes::LineString);
+///     assert_eq!(snapshot.envelope(), Some((0., 0., 2., 1.)));
+///     let geom = snapshot.to_geometry().expect("failed to create geometry");
+///     assert_eq!(geom.get_srid(), Ok(4326));
+///     geom.to_wkt_precision(0)
+/// });
+/// assert_eq!(handle.join().unwrap(), Ok("LINESTRING (0 0, 2 1)".to_owned()));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeometrySnapshot {
+    wkb: Arc<[u8]>,
+    envelope: Option<(f64, f64, f64, f64)>,
+    srid: Option<usize>,
+    geometry_type: GeometryTypes,
+}
+
+impl GeometrySnapshot {
+    /// Creates a snapshot of `g`. Z coordinates are kept.
+    pub fn new<G: Geom>(g: &G) -> GResult<GeometrySnapshot> {
+        let mut writer = WKBWriter::new_with_context(g.clone_context())?;
+        writer.set_output_dimension(OutputDimension::ThreeD);
+        let wkb = writer.write_wkb(g)?;
+        Ok(GeometrySnapshot {
+            wkb: Arc::from(wkb.as_ref()),
+            envelope: bounds(g)?,
+            srid: g.get_srid().ok(),
+            geometry_type: g.geometry_type(),
+        })
+    }
+
+    /// Creates a new [`Geometry`] from the snapshot.
+    pub fn to_geometry(&self) -> GResult<Geometry> {
+        let mut geom = Geometry::new_from_wkb(&self.wkb)?;
+        if let Some(srid) = self.srid {
+            geom.set_srid(srid);
+        }
+        Ok(geom)
+    }
+
+    /// Returns the WKB of the geometry (without SRID).
+    pub fn wkb(&self) -> &[u8] {
+        &self.wkb
+    }
+
+    /// Returns the `(xmin, ymin, xmax, ymax)` envelope of the geometry, `None` if it is empty.
+    pub fn envelope(&self) -> Option<(f64, f64, f64, f64)> {
+        self.envelope
+    }
+
+    /// Returns the SRID of the geometry, `None` if it has none.
+    pub fn srid(&self) -> Option<usize> {
+        self.srid
+    }
+
+    pub fn geometry_type(&self) -> GeometryTypes {
+        self.geometry_type
+    }
+}
+
+impl TryFrom<&GeometrySnapshot> for Geometry {
+    type Error = crate::Error;
+
+    fn try_from(snapshot: &GeometrySnapshot) -> GResult<Geometry> {
+        snapshot.to_geometry()
+    }
+}
@@ -0,0 +1,49 @@
+use crate::error::{Error, GResult};
+use crate::functions::create_polygon_with_context;
+use crate::{ContextHandling, Geom, Geometry, PreparedGeometry};
+use h3o::{CellIndex, LatLng, Resolution};
+use std::collections::{HashSet, VecDeque};
+
+fn cell_polygon(prepared: &PreparedGeometry, cell: CellIndex) -> GResult<Geometry> {
+    let boundary = cell.boundary();
+    let mut exterior = boundary
+        .iter()
+        .map(|ll| (ll.lng(), ll.lat()))
+        .collect::<Vec<_>>();
+    exterior.push(exterior[0]);
+    create_polygon_with_context(prepared.clone_context(), &exterior)
+}
+
+/// Flood fills the cells intersecting `g`, starting from the cell containing a point on the
+/// surface of each of its parts (cells intersecting a connected geometry are connected).
+pub(crate) fn h3_cells<G: Geom>(g: &G, resolution: Resolution) -> GResult<Vec<CellIndex>> {
+    if g.is_empty()? {
+        return Ok(Vec::new());
+    }
+    let prepared = PreparedGeometry::new(g)?;
+    let mut queue = VecDeque::new();
+    for i in 0..g.get_num_geometries()? {
+        let point = g.get_geometry_n(i)?.point_on_surface()?;
+        if point.is_empty()? {
+            continue;
+        }
+        let seed = LatLng::new(point.get_y()?, point.get_x()?)
+            .map_err(|e| Error::GenericError(format!("invalid position for H3: {e}")))?;
+        queue.push_back(seed.to_cell(resolution));
+    }
+    let mut visited = queue.iter().copied().collect::<HashSet<_>>();
+    let mut cells = Vec::new();
+    while let Some(cell) = queue.pop_front() {
+        if !prepared.intersects(&cell_polygon(&prepared, cell)?)? {
+            continue;
+        }
+        cells.push(cell);
+        for neighbour in cell.grid_disk::<Vec<_>>(1) {
+            if visited.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    cells.sort_unstable();
+    Ok(cells)
+}
@@ -0,0 +1,197 @@
+//! Batch parsing, validation and repair of geometries coming from WKT or WKB records.
+//!
+//! # Example
+//!
+//! ```
+//! use geos::ingest::{self, IngestOptions, Record};
+//! use geos::RepairStrategy;
+//!
+//! let records = vec![
+//!     Record::Wkt("POINT(1 2)"),
+//!     Record::Wkt("POINT(1"),
+//!     Record::Wkt("POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))"),
+//! ];
+//! let options = IngestOptions {
+//!     repair: Some(RepairStrategy::BufferZero),
+//!     ..Default::default()
+//! };
+//! let (geometries, report) = ingest::process(records, &options);
+//!
+//! assert_eq!(geometries.len(), 2);
+//! assert_eq!(report.source_indices, vec![0, 2]);
+//! assert_eq!(report.parse_errors.len(), 1);
+//! assert_eq!(report.parse_errors[0].index, 1);
+//! assert_eq!(report.validity_problems.len(), 1);
+//! assert_eq!(report.validity_problems[0].index, 2);
+//! assert_eq!(report.repairs.len(), 1);
+//! ```
+use crate::error::{Error, GResult};
+use crate::functions::is_valid_detail;
+use crate::{Geom, Geometry, RepairReport, RepairStrategy};
+
+/// A record to ingest.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Record<'a> {
+    Wkt(&'a str),
+    Wkb(&'a [u8]),
+}
+
+impl<'a> From<&'a str> for Record<'a> {
+    fn from(wkt: &'a str) -> Record<'a> {
+        Record::Wkt(wkt)
+    }
+}
+
+impl<'a> From<&'a String> for Record<'a> {
+    fn from(wkt: &'a String) -> Record<'a> {
+        Record::Wkt(wkt)
+    }
+}
+
+impl<'a> From<&'a [u8]> for Record<'a> {
+    fn from(wkb: &'a [u8]) -> Record<'a> {
+        Record::Wkb(wkb)
+    }
+}
+
+impl<'a> From<&'a Vec<u8>> for Record<'a> {
+    fn from(wkb: &'a Vec<u8>) -> Record<'a> {
+        Record::Wkb(wkb)
+    }
+}
+
+/// Options of [`process`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct IngestOptions {
+    /// Strategy used to repair the invalid geometries. If `None`, they aren't repaired.
+    pub repair: Option<RepairStrategy>,
+    /// Whether the geometries which are still invalid after the repair are dropped.
+    pub drop_invalid: bool,
+    /// Whether the empty geometries (including the ones emptied by the repair) are dropped.
+    pub drop_empty: bool,
+}
+
+impl Default for IngestOptions {
+    fn default() -> IngestOptions {
+        IngestOptions {
+            repair: None,
+            drop_invalid: true,
+            drop_empty: false,
+        }
+    }
+}
+
+/// A record which couldn't be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// Index of the record in the input.
+    pub index: usize,
+    pub error: Error,
+}
+
+/// An invalid geometry, as reported by GEOS before any repair.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidityProblem {
+    /// Index of the record in the input.
+    pub index: usize,
+    /// Why the geometry is invalid.
+    pub reason: String,
+    /// Location of the problem, if known.
+    pub location: Option<(f64, f64)>,
+}
+
+/// A repair applied to a geometry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppliedRepair {
+    /// Index of the record in the input.
+    pub index: usize,
+    pub report: RepairReport,
+}
+
+/// Everything which happened while ingesting records with [`process`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IngestReport {
+    /// Number of records read.
+    pub records: usize,
+    /// Index in the input of each returned geometry.
+    pub source_indices: Vec<usize>,
+    pub parse_errors: Vec<ParseError>,
+    pub validity_problems: Vec<ValidityProblem>,
+    pub repairs: Vec<AppliedRepair>,
+    /// Index in the input of the geometries dropped because of the options.
+    pub dropped: Vec<usize>,
+}
+
+fn parse(record: Record) -> GResult<Geometry> {
+    match record {
+        Record::Wkt(wkt) => Geometry::new_from_wkt(wkt),
+        Record::Wkb(wkb) => Geometry::new_from_wkb(wkb),
+    }
+}
+
+fn location(point: Option<Geometry>) -> Option<(f64, f64)> {
+    let point = point?;
+    if point.is_empty().ok()? {
+        return None;
+    }
+    Some((point.get_x().ok()?, point.get_y().ok()?))
+}
+
+/// Returns the geometry to keep (if any) for the given record, updating `report`.
+fn process_record(
+    index: usize,
+    record: Record,
+    options: &IngestOptions,
+    report: &mut IngestReport,
+) -> GResult<Option<Geometry>> {
+    let mut geometry = parse(record)?;
+    if let Some((reason, point)) = is_valid_detail(&geometry, 0)? {
+        report.validity_problems.push(ValidityProblem {
+            index,
+            reason,
+            location: location(point),
+        });
+        if let Some(strategy) = &options.repair {
+            let (repaired, repair_report) = geometry.repair(strategy.clone())?;
+            report.repairs.push(AppliedRepair {
+                index,
+                report: repair_report,
+            });
+            geometry = repaired;
+        }
+        if options.drop_invalid && !geometry.is_valid() {
+            return Ok(None);
+        }
+    }
+    if options.drop_empty && geometry.is_empty()? {
+        return Ok(None);
+    }
+    Ok(Some(geometry))
+}
+
+/// Parses each record, checks its validity and repairs it if needed, as configured by
+/// `options`. Returns the resulting geometries along with a report of everything which
+/// happened.
+///
+/// Records which can't be parsed (or processed) are listed in
+/// [`IngestReport::parse_errors`] and skipped.
+pub fn process<'a, I, R>(records: I, options: &IngestOptions) -> (Vec<Geometry>, IngestReport)
+where
+    I: IntoIterator<Item = R>,
+    R: Into<Record<'a>>,
+{
+    let mut geometries = Vec::new();
+    let mut report = IngestReport::default();
+    for (index, record) in records.into_iter().enumerate() {
+        report.records += 1;
+        match process_record(index, record.into(), options, &mut report) {
+            Ok(Some(geometry)) => {
+                geometries.push(geometry);
+                report.source_indices.push(index);
+            }
+            Ok(None) => report.dropped.push(index),
+            Err(error) => report.parse_errors.push(ParseError { index, error }),
+        }
+    }
+    (geometries, report)
+}
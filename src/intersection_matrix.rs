@@ -0,0 +1,86 @@
+use crate::error::{Error, GResult};
+use crate::Location;
+use std::fmt;
+use std::str::FromStr;
+
+/// A DE-9IM intersection matrix, as returned by [`Geom::relate`](crate::Geom::relate).
+///
+/// Each cell gives the dimension of the intersection between the interior, boundary or exterior
+/// of a first geometry (the row) and the ones of a second geometry (the column), or `F` if they
+/// don't intersect.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry, IntersectionMatrix, Location};
+///
+/// let polygon = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+/// let point = Geometry::new_from_wkt("POINT(5 5)").unwrap();
+///
+/// let matrix = polygon.relate(&point).expect("relate failed");
+/// assert_eq!(matrix.to_string(), "0F2FF1FF2");
+/// assert_eq!(matrix.get(Location::Interior, Location::Interior), Some(0));
+/// assert_eq!(matrix.get(Location::Boundary, Location::Interior), None);
+/// assert_eq!(matrix.matches("T*****FF*"), Ok(true));
+/// assert_eq!("0F2FF1FF2".parse::<IntersectionMatrix>(), Ok(matrix));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntersectionMatrix([u8; 9]);
+
+impl IntersectionMatrix {
+    /// Returns the dimension of the intersection between the `a` part of the first geometry and
+    /// the `b` part of the second one, `None` if they don't intersect.
+    pub fn get(&self, a: Location, b: Location) -> Option<u8> {
+        match self.0[a.index() * 3 + b.index()] {
+            b'F' => None,
+            d => Some(d - b'0'),
+        }
+    }
+
+    /// Returns `true` if the matrix matches the DE-9IM `pattern`, made of 9 characters among
+    /// `T` (any non-empty intersection), `F`, `0`, `1`, `2` and `*` (anything).
+    pub fn matches(&self, pattern: &str) -> GResult<bool> {
+        Ok(self.matches_pattern(&parse_pattern(pattern)?))
+    }
+
+    pub(crate) fn matches_pattern(&self, pattern: &[u8; 9]) -> bool {
+        pattern.iter().zip(&self.0).all(|(p, m)| match p {
+            b'*' => true,
+            b'T' => *m != b'F',
+            _ => p == m,
+        })
+    }
+}
+
+impl FromStr for IntersectionMatrix {
+    type Err = Error;
+
+    fn from_str(s: &str) -> GResult<IntersectionMatrix> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 9 || !bytes.iter().all(|b| b"F012".contains(b)) {
+            return Err(Error::GenericError(format!("invalid DE-9IM matrix: {s:?}")));
+        }
+        let mut res = [0; 9];
+        res.copy_from_slice(bytes);
+        Ok(IntersectionMatrix(res))
+    }
+}
+
+impl fmt::Display for IntersectionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Only ASCII characters are accepted by `from_str`.
+        f.write_str(std::str::from_utf8(&self.0).map_err(|_| fmt::Error)?)
+    }
+}
+
+pub(crate) fn parse_pattern(pattern: &str) -> GResult<[u8; 9]> {
+    let bytes = pattern.as_bytes();
+    if bytes.len() != 9 || !bytes.iter().all(|b| b"TF012*".contains(b)) {
+        return Err(Error::GenericError(format!(
+            "invalid DE-9IM pattern: {pattern:?}"
+        )));
+    }
+    let mut res = [0; 9];
+    res.copy_from_slice(bytes);
+    Ok(res)
+}
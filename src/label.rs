@@ -0,0 +1,75 @@
+use crate::error::{Error, GResult};
+use crate::{Geom, GeometryTypes};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Where and how to draw the label of a polygon, as returned by [`Geom::label_anchor`].
+///
+/// Available using the `v3_9_0` feature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LabelAnchor {
+    /// Center of the maximum inscribed circle: the point of the polygon the farthest from its
+    /// boundary.
+    pub point: (f64, f64),
+    /// Radius of the maximum inscribed circle, the maximum size the label can have around
+    /// `point` without going out of the polygon.
+    pub max_radius: f64,
+    /// Angle of the long side of the minimum rotated rectangle of the polygon, in radians
+    /// counterclockwise from the X axis, in the `(-π/2, π/2]` range (so the label is never upside
+    /// down).
+    pub angle: f64,
+}
+
+/// Returns the angle of the `(x0, y0) -> (x1, y1)` direction, normalized to `(-π/2, π/2]`.
+fn direction_angle((x0, y0): (f64, f64), (x1, y1): (f64, f64)) -> f64 {
+    let angle = (y1 - y0).atan2(x1 - x0);
+    if angle > FRAC_PI_2 {
+        angle - PI
+    } else if angle <= -FRAC_PI_2 {
+        angle + PI
+    } else {
+        angle
+    }
+}
+
+fn orientation<G: Geom>(g: &G) -> GResult<f64> {
+    let rectangle = g.minimum_rotated_rectangle()?;
+    let coords = match rectangle.geometry_type() {
+        GeometryTypes::Polygon => rectangle.get_exterior_ring()?.get_coord_seq()?,
+        GeometryTypes::LineString => rectangle.get_coord_seq()?,
+        _ => return Ok(0.),
+    };
+    let mut xy = Vec::with_capacity(3);
+    for i in 0..coords.size()?.min(3) {
+        xy.push((coords.get_x(i)?, coords.get_y(i)?));
+    }
+    let length = |a: (f64, f64), b: (f64, f64)| (b.0 - a.0).hypot(b.1 - a.1);
+    Ok(match xy[..] {
+        [a, b, c] if length(b, c) > length(a, b) => direction_angle(b, c),
+        [a, b, ..] => direction_angle(a, b),
+        _ => 0.,
+    })
+}
+
+pub(crate) fn label_anchor<G: Geom>(g: &G, tolerance: f64) -> GResult<LabelAnchor> {
+    match g.geometry_type() {
+        GeometryTypes::Polygon | GeometryTypes::MultiPolygon => {}
+        t => {
+            return Err(Error::GenericError(format!(
+                "Geometry must be a Polygon or a MultiPolygon, got a {t:?}"
+            )))
+        }
+    }
+    if g.is_empty()? {
+        return Err(Error::GenericError(
+            "cannot place a label on an empty geometry".to_owned(),
+        ));
+    }
+    // The result is a line going from the center of the circle to the closest boundary point.
+    let radius_line = g.maximum_inscribed_circle(tolerance)?;
+    let center = radius_line.get_start_point()?;
+    Ok(LabelAnchor {
+        point: (center.get_x()?, center.get_y()?),
+        max_radius: radius_line.length()?,
+        angle: orientation(g)?,
+    })
+}
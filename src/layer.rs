@@ -0,0 +1,910 @@
+//! Boolean operations between two layers (lists) of geometries, similar to the PostGIS/QGIS
+//! overlay tools.
+//!
+//! Pairs of geometries are found using a [`STRtree`] so only geometries whose envelopes
+//! intersect are actually computed. Each resulting [`LayerPiece`] keeps the indices of the
+//! input geometries it comes from.
+//!
+//! # Example
+//!
+//! ```
+//! use geos::{layer, Geom, Geometry};
+//!
+//! let a = vec![Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap()];
+//! let b = vec![
+//!     Geometry::new_from_wkt("POLYGON((1 1, 3 1, 3 3, 1 3, 1 1))").unwrap(),
+//!     Geometry::new_from_wkt("POLYGON((10 10, 11 10, 11 11, 10 10))").unwrap(),
+//! ];
+//!
+//! let pieces = layer::intersection(&a, &b).expect("intersection failed");
+//! assert_eq!(pieces.len(), 1);
+//! assert_eq!((pieces[0].a, pieces[0].b), (Some(0), Some(0)));
+//! assert_eq!(pieces[0].geometry.area(), Ok(1.));
+//! ```
+use crate::error::{Error, GResult};
+use crate::functions::{bounds, rectangle, union_all};
+#[cfg(any(feature = "v3_9_0", feature = "dox"))]
+use crate::Precision;
+use crate::{
+    AsRaw, ContextHandle, ContextInteractions, Geom, Geometry, GeometryTypes, PreparedGeometry,
+    STRtree, SpatialIndex,
+};
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::thread;
+
+/// A geometry resulting from a layer operation, along with the indices of the geometries of
+/// the two input layers it comes from.
+#[derive(Clone)]
+pub struct LayerPiece {
+    pub geometry: Geometry,
+    /// Index of the contributing geometry of the first layer, if any.
+    pub a: Option<usize>,
+    /// Index of the contributing geometry of the second layer, if any.
+    pub b: Option<usize>,
+}
+
+pub(crate) fn build_tree<T: Borrow<Geometry>>(geometries: &[T]) -> GResult<STRtree<usize>> {
+    let mut tree = STRtree::with_capacity(10)?;
+    for (i, g) in geometries.iter().enumerate() {
+        tree.insert(g.borrow(), i)?;
+    }
+    Ok(tree)
+}
+
+/// Returns the (sorted) indices of the items of `tree` intersecting `geometry`.
+pub(crate) fn intersecting<T: Borrow<Geometry>>(
+    tree: &STRtree<usize>,
+    items: &[T],
+    geometry: &Geometry,
+) -> GResult<Vec<usize>> {
+    let mut candidates = Vec::new();
+    tree.query(geometry, |i| candidates.push(*i));
+    candidates.sort_unstable();
+    let mut res = Vec::with_capacity(candidates.len());
+    for i in candidates {
+        if geometry.intersects(items[i].borrow())? {
+            res.push(i);
+        }
+    }
+    Ok(res)
+}
+
+/// Returns the part of each geometry of `items` which isn't covered by any geometry of
+/// `others`.
+fn difference_pieces<A: Borrow<Geometry>, B: Borrow<Geometry>>(
+    items: &[A],
+    others: &[B],
+    is_a: bool,
+) -> GResult<Vec<LayerPiece>> {
+    let tree = build_tree(others)?;
+    let mut pieces = Vec::with_capacity(items.len());
+    for (i, g) in items.iter().enumerate() {
+        let g = g.borrow();
+        let overlapping: Vec<&Geometry> = intersecting(&tree, others, g)?
+            .into_iter()
+            .map(|j| others[j].borrow())
+            .collect();
+        let geometry = if overlapping.is_empty() {
+            Geom::clone(g)
+        } else {
+            g.difference(&union_all(&overlapping)?)?
+        };
+        if !geometry.is_empty()? {
+            let (a, b) = if is_a {
+                (Some(i), None)
+            } else {
+                (None, Some(i))
+            };
+            pieces.push(LayerPiece { geometry, a, b });
+        }
+    }
+    Ok(pieces)
+}
+
+/// Computes the intersection of each pair of intersecting geometries of `a` and `b`.
+///
+/// Empty intersections are skipped. Every returned piece has both `a` and `b` set.
+pub fn intersection<A: Borrow<Geometry>, B: Borrow<Geometry>>(
+    a: &[A],
+    b: &[B],
+) -> GResult<Vec<LayerPiece>> {
+    let tree = build_tree(b)?;
+    let mut pieces = Vec::new();
+    for (i, g) in a.iter().enumerate() {
+        let g = g.borrow();
+        for j in intersecting(&tree, b, g)? {
+            let geometry = g.intersection(b[j].borrow())?;
+            if !geometry.is_empty()? {
+                pieces.push(LayerPiece {
+                    geometry,
+                    a: Some(i),
+                    b: Some(j),
+                });
+            }
+        }
+    }
+    Ok(pieces)
+}
+
+/// Returns, for each geometry of `a`, its part not covered by any geometry of `b`.
+///
+/// Geometries of `a` which are entirely covered are skipped. Every returned piece has `a` set
+/// and `b` unset.
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geom, Geometry};
+///
+/// let a = vec![Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap()];
+/// let b = vec![
+///     Geometry::new_from_wkt("POLYGON((1 0, 2 0, 2 1, 1 1, 1 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((1 1, 2 1, 2 2, 1 2, 1 1))").unwrap(),
+/// ];
+///
+/// let pieces = layer::difference(&a, &b).expect("difference failed");
+/// assert_eq!(pieces.len(), 1);
+/// assert_eq!(pieces[0].geometry.area(), Ok(2.));
+/// ```
+pub fn difference<A: Borrow<Geometry>, B: Borrow<Geometry>>(
+    a: &[A],
+    b: &[B],
+) -> GResult<Vec<LayerPiece>> {
+    difference_pieces(a, b, true)
+}
+
+/// Computes the union of the two layers: the pieces of [`intersection`] followed by the parts
+/// of `a` not covered by `b`, then the parts of `b` not covered by `a`.
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geom, Geometry};
+///
+/// let a = vec![Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap()];
+/// let b = vec![Geometry::new_from_wkt("POLYGON((1 1, 3 1, 3 3, 1 3, 1 1))").unwrap()];
+///
+/// let pieces = layer::union(&a, &b).expect("union failed");
+/// let origins = pieces.iter().map(|p| (p.a, p.b)).collect::<Vec<_>>();
+/// assert_eq!(origins, vec![(Some(0), Some(0)), (Some(0), None), (None, Some(0))]);
+/// ```
+pub fn union<A: Borrow<Geometry>, B: Borrow<Geometry>>(
+    a: &[A],
+    b: &[B],
+) -> GResult<Vec<LayerPiece>> {
+    let mut pieces = intersection(a, b)?;
+    pieces.extend(difference_pieces(a, b, true)?);
+    pieces.extend(difference_pieces(b, a, false)?);
+    Ok(pieces)
+}
+
+// Number of clippers unioned together before being subtracted from a subject.
+const ERASE_CHUNK_SIZE: usize = 64;
+
+/// Subtracts all the `clippers` from each geometry of `subjects`, returning one geometry per
+/// subject (in the same order). Fully erased subjects are returned as empty geometries.
+///
+/// Unlike computing `subject.difference(&union_of_all_clippers)`, only the clippers
+/// intersecting a subject (found using a [`STRtree`] and a [`PreparedGeometry`] of the
+/// subject) are used. Subjects covered by a single clipper are skipped and the relevant
+/// clippers are unioned by chunks, keeping memory usage bounded on large clip layers.
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geom, Geometry};
+///
+/// let subjects = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((10 10, 11 10, 11 11, 10 11, 10 10))").unwrap(),
+/// ];
+/// let clippers = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 4, 0 4, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((9 9, 12 9, 12 12, 9 12, 9 9))").unwrap(),
+/// ];
+///
+/// let res = layer::erase(&subjects, &clippers).expect("erase failed");
+/// assert_eq!(res[0].area(), Ok(8.));
+/// assert!(res[1].is_empty().unwrap());
+/// ```
+pub fn erase<A: Borrow<Geometry>, B: Borrow<Geometry>>(
+    subjects: &[A],
+    clippers: &[B],
+) -> GResult<Vec<Geometry>> {
+    let tree = build_tree(clippers)?;
+    let mut res = Vec::with_capacity(subjects.len());
+    for subject in subjects {
+        let subject = subject.borrow();
+        let prepared = PreparedGeometry::new(subject)?;
+        let mut candidates = Vec::new();
+        tree.query(subject, |i| candidates.push(*i));
+        candidates.sort_unstable();
+        let mut relevant = Vec::with_capacity(candidates.len());
+        let mut covered = false;
+        for i in candidates {
+            let clipper = clippers[i].borrow();
+            if prepared.covered_by(clipper)? {
+                covered = true;
+                break;
+            }
+            if prepared.intersects(clipper)? {
+                relevant.push(clipper);
+            }
+        }
+        if covered {
+            res.push(Geometry::create_empty_collection(
+                GeometryTypes::GeometryCollection,
+            )?);
+            continue;
+        }
+        let mut erased = Geom::clone(subject);
+        for chunk in relevant.chunks(ERASE_CHUNK_SIZE) {
+            erased = erased.difference(&union_all(chunk)?)?;
+            if erased.is_empty()? {
+                break;
+            }
+        }
+        res.push(erased);
+    }
+    Ok(res)
+}
+
+fn group_by_key<K: Eq + Hash, I: IntoIterator<Item = (K, Geometry)>>(
+    items: I,
+) -> HashMap<K, Vec<Geometry>> {
+    let mut groups: HashMap<K, Vec<Geometry>> = HashMap::new();
+    for (key, geometry) in items {
+        groups.entry(key).or_default().push(geometry);
+    }
+    groups
+}
+
+fn union_group(geometries: Vec<Geometry>) -> GResult<Geometry> {
+    Geometry::create_geometry_collection(geometries)?.unary_union()
+}
+
+/// Groups the geometries by key and computes the (cascaded) union of each group, like a SQL
+/// `GROUP BY key` with `ST_Union`.
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geom, Geometry};
+///
+/// let items = vec![
+///     ("a", Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap()),
+///     ("b", Geometry::new_from_wkt("POLYGON((5 5, 6 5, 6 6, 5 6, 5 5))").unwrap()),
+///     ("a", Geometry::new_from_wkt("POLYGON((1 0, 3 0, 3 2, 1 2, 1 0))").unwrap()),
+/// ];
+///
+/// let dissolved = layer::dissolve(items).expect("dissolve failed");
+/// assert_eq!(dissolved.len(), 2);
+/// assert_eq!(dissolved["a"].area(), Ok(6.));
+/// assert_eq!(dissolved["b"].area(), Ok(1.));
+/// ```
+pub fn dissolve<K, I>(items: I) -> GResult<HashMap<K, Geometry>>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = (K, Geometry)>,
+{
+    group_by_key(items)
+        .into_iter()
+        .map(|(key, geometries)| Ok((key, union_group(geometries)?)))
+        .collect()
+}
+
+/// Same as [`dissolve`], but the groups are unioned in parallel using one thread per available
+/// CPU. Each group is computed using a new [`ContextHandle`] so threads don't share contexts.
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geom, Geometry};
+///
+/// let items = (0..10).map(|i| {
+///     let wkt = format!("POINT ({} 0)", i);
+///     (i % 2, Geometry::new_from_wkt(&wkt).unwrap().buffer(1., 8).unwrap())
+/// });
+///
+/// let dissolved = layer::dissolve_parallel(items).expect("dissolve failed");
+/// assert_eq!(dissolved.len(), 2);
+/// assert_eq!(dissolved[&0].get_num_geometries(), Ok(1));
+/// ```
+pub fn dissolve_parallel<K, I>(items: I) -> GResult<HashMap<K, Geometry>>
+where
+    K: Eq + Hash + Send,
+    I: IntoIterator<Item = (K, Geometry)>,
+{
+    let groups = group_by_key(items);
+    let nb_threads = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(groups.len());
+    let queue = Mutex::new(groups.into_iter());
+    let queue = &queue;
+
+    thread::scope(|s| {
+        let workers = (0..nb_threads)
+            .map(|_| {
+                s.spawn(move || -> GResult<Vec<(K, Geometry)>> {
+                    let mut res = Vec::new();
+                    loop {
+                        let next = queue.lock().expect("poisoned mutex").next();
+                        let Some((key, mut geometries)) = next else {
+                            return Ok(res);
+                        };
+                        // The collection created from the group uses the context of its first
+                        // geometry.
+                        geometries[0].set_context_handle(ContextHandle::init_e(Some(
+                            "layer::dissolve_parallel",
+                        ))?);
+                        res.push((key, union_group(geometries)?));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut dissolved = HashMap::new();
+        for worker in workers {
+            dissolved.extend(worker.join().expect("dissolve worker panicked")?);
+        }
+        Ok(dissolved)
+    })
+}
+
+/// Returns the indices of the geometries of `tree` whose envelope is at most `distance` away
+/// from the envelope of `geometry`.
+pub(crate) fn query_within_distance<G: Geom, T: SpatialIndex<usize>>(
+    tree: &T,
+    geometry: &G,
+    distance: f64,
+) -> GResult<Vec<usize>> {
+    let mut candidates = Vec::new();
+    if let Some((xmin, ymin, xmax, ymax)) = bounds(geometry)? {
+        let search = rectangle(
+            geometry.clone_context(),
+            xmin - distance,
+            ymin - distance,
+            xmax + distance,
+            ymax + distance,
+        )?;
+        tree.query(&search, |i| candidates.push(*i));
+        candidates.sort_unstable();
+    }
+    Ok(candidates)
+}
+
+/// Returns `true` if `a` and `b` are at most `distance` away.
+pub(crate) fn is_within_distance<A: Geom, B: Geom>(a: &A, b: &B, distance: f64) -> GResult<bool> {
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    {
+        a.distance_within(b, distance)
+    }
+    #[cfg(not(any(feature = "v3_10_0", feature = "dox")))]
+    {
+        Ok(a.distance(b)? <= distance)
+    }
+}
+
+/// Returns all the `(i, j, distance)` such as the distance between `left[i]` and `right[j]` is
+/// less than or equal to `max_distance`.
+///
+/// Candidates are found by querying a [`STRtree`] of `right` with the envelope of each `left`
+/// geometry expanded by `max_distance`. When the `v3_10_0` feature is enabled, candidates are
+/// then checked with [`Geom::distance_within`] so the actual distance is only computed for
+/// matching pairs.
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geometry};
+///
+/// let left = vec![Geometry::new_from_wkt("POINT (0 0)").unwrap()];
+/// let right = vec![
+///     Geometry::new_from_wkt("POINT (0 3)").unwrap(),
+///     Geometry::new_from_wkt("POINT (10 10)").unwrap(),
+///     Geometry::new_from_wkt("LINESTRING (1 -5, 1 5)").unwrap(),
+/// ];
+///
+/// let pairs = layer::within_distance_join(&left, &right, 5.).expect("join failed");
+/// assert_eq!(pairs, vec![(0, 0, 3.), (0, 2, 1.)]);
+/// ```
+pub fn within_distance_join<A: Borrow<Geometry>, B: Borrow<Geometry>>(
+    left: &[A],
+    right: &[B],
+    max_distance: f64,
+) -> GResult<Vec<(usize, usize, f64)>> {
+    let tree = build_tree(right)?;
+    let mut pairs = Vec::new();
+    for (i, g) in left.iter().enumerate() {
+        let g = g.borrow();
+        for j in query_within_distance(&tree, g, max_distance)? {
+            let other = right[j].borrow();
+            if is_within_distance(g, other, max_distance)? {
+                pairs.push((i, j, g.distance(other)?));
+            }
+        }
+    }
+    Ok(pairs)
+}
+
+/// Returns the (sorted) indices of the `candidates` which are at most `distance` away from
+/// `target`.
+///
+/// It builds a [`STRtree`] of the candidates, queries it with the envelope of `target` expanded
+/// by `distance` then confirms each match with an exact check ([`Geom::distance_within`] when
+/// the `v3_10_0` feature is enabled).
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geometry};
+///
+/// let candidates = vec![
+///     Geometry::new_from_wkt("POINT (0 3)").unwrap(),
+///     // Its envelope is close enough but not the point itself.
+///     Geometry::new_from_wkt("POINT (3 3)").unwrap(),
+///     Geometry::new_from_wkt("POINT (10 10)").unwrap(),
+/// ];
+/// let target = Geometry::new_from_wkt("POINT (0 0)").unwrap();
+///
+/// let close = layer::filter_within_distance(&candidates, &target, 3.5).expect("filter failed");
+/// assert_eq!(close, vec![0]);
+/// ```
+pub fn filter_within_distance<T: Borrow<Geometry>, G: Geom>(
+    candidates: &[T],
+    target: &G,
+    distance: f64,
+) -> GResult<Vec<usize>> {
+    let tree = build_tree(candidates)?;
+    let mut res = Vec::new();
+    for i in query_within_distance(&tree, target, distance)? {
+        if is_within_distance(target, candidates[i].borrow(), distance)? {
+            res.push(i);
+        }
+    }
+    Ok(res)
+}
+
+// Above this number of coordinates, the indexed distance is used.
+const INDEXED_DISTANCE_THRESHOLD: usize = 100;
+
+fn distance_with_context(
+    context: &ContextHandle,
+    a: &Geometry,
+    b: &Geometry,
+    indexed: bool,
+) -> GResult<f64> {
+    let mut distance = 0.;
+    let ret = unsafe {
+        #[cfg(any(feature = "v3_7_0", feature = "dox"))]
+        {
+            if indexed {
+                geos_sys::GEOSDistanceIndexed_r(
+                    context.as_raw(),
+                    a.as_raw(),
+                    b.as_raw(),
+                    &mut distance,
+                )
+            } else {
+                geos_sys::GEOSDistance_r(context.as_raw(), a.as_raw(), b.as_raw(), &mut distance)
+            }
+        }
+        #[cfg(not(any(feature = "v3_7_0", feature = "dox")))]
+        {
+            let _ = indexed;
+            geos_sys::GEOSDistance_r(context.as_raw(), a.as_raw(), b.as_raw(), &mut distance)
+        }
+    };
+    if ret != 1 {
+        return Err(Error::GenericError("GEOSDistance_r failed".to_owned()));
+    }
+    Ok(distance)
+}
+
+/// Computes the distance between each geometry of `a` and each geometry of `b`. The result is
+/// stored in row-major order: the distance between `a[i]` and `b[j]` is at index
+/// `i * b.len() + j`.
+///
+/// Rows are computed in parallel, using one thread (and one [`ContextHandle`]) per available
+/// CPU. When the `v3_7_0` feature is enabled, the indexed distance is used for geometries with
+/// many coordinates.
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geometry};
+///
+/// let a = vec![
+///     Geometry::new_from_wkt("POINT (0 0)").unwrap(),
+///     Geometry::new_from_wkt("POINT (0 1)").unwrap(),
+/// ];
+/// let b = vec![
+///     Geometry::new_from_wkt("POINT (3 0)").unwrap(),
+///     Geometry::new_from_wkt("POINT (0 4)").unwrap(),
+///     Geometry::new_from_wkt("POINT (0 0)").unwrap(),
+/// ];
+///
+/// let matrix = layer::distance_matrix(&a, &b).expect("distance_matrix failed");
+/// assert_eq!(matrix, vec![3., 4., 0., 10f64.sqrt(), 3., 1.]);
+/// ```
+pub fn distance_matrix<A, B>(a: &[A], b: &[B]) -> GResult<Vec<f64>>
+where
+    A: Borrow<Geometry> + Sync,
+    B: Borrow<Geometry> + Sync,
+{
+    let mut matrix = vec![0.; a.len() * b.len()];
+    if matrix.is_empty() {
+        return Ok(matrix);
+    }
+    let is_large = |g: &Geometry| -> GResult<bool> {
+        Ok(g.get_num_coordinates()? > INDEXED_DISTANCE_THRESHOLD)
+    };
+    let large_b = b
+        .iter()
+        .map(|g| is_large(g.borrow()))
+        .collect::<GResult<Vec<_>>>()?;
+    let nb_threads = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(a.len());
+    let rows_per_thread = (a.len() + nb_threads - 1) / nb_threads;
+    let large_b = &large_b;
+
+    thread::scope(|s| {
+        let workers = matrix
+            .chunks_mut(rows_per_thread * b.len())
+            .zip(a.chunks(rows_per_thread))
+            .map(|(out, rows)| {
+                s.spawn(move || -> GResult<()> {
+                    let context = ContextHandle::init_e(Some("layer::distance_matrix"))?;
+                    for (out_row, g) in out.chunks_mut(b.len()).zip(rows) {
+                        let g = g.borrow();
+                        let large_g = is_large(g)?;
+                        for ((d, other), large_other) in out_row.iter_mut().zip(b).zip(large_b) {
+                            *d = distance_with_context(
+                                &context,
+                                g,
+                                other.borrow(),
+                                large_g || *large_other,
+                            )?;
+                        }
+                    }
+                    Ok(())
+                })
+            })
+            .collect::<Vec<_>>();
+        for worker in workers {
+            worker.join().expect("distance_matrix worker panicked")?;
+        }
+        Ok(())
+    })?;
+    Ok(matrix)
+}
+
+/// How [`dedup_geometries`] compares geometries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DedupMode {
+    /// Geometries must have exactly the same coordinates, in the same order.
+    Exact,
+    /// Geometries are [normalized](Geometry::normalize) before being compared, so the order of
+    /// the vertices of a ring or of the parts of a collection doesn't matter.
+    Normalized,
+    /// Normalized geometries are considered the same if all their coordinates are at most this
+    /// far apart (see [`Geom::equals_exact`]).
+    WithinTolerance(f64),
+}
+
+fn wkb_hash(g: &Geometry, buffer: &mut Vec<u8>) -> GResult<u64> {
+    buffer.clear();
+    g.to_wkb_into(buffer)?;
+    let mut hasher = DefaultHasher::new();
+    buffer.hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+/// Returns the indices of the distinct geometries of `geometries`: for each group of
+/// duplicates, only the index of the first one is kept.
+///
+/// For [`DedupMode::Exact`] and [`DedupMode::Normalized`], geometries are grouped by the hash
+/// of their (normalized) WKB then compared using [`Geom::equals_exact`]. For
+/// [`DedupMode::WithinTolerance`], close candidates are found using a [`STRtree`].
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geometry};
+/// use geos::layer::DedupMode;
+///
+/// let geometries = vec![
+///     Geometry::new_from_wkt("LINESTRING (0 0, 1 1)").unwrap(),
+///     Geometry::new_from_wkt("LINESTRING (1 1, 0 0)").unwrap(),
+///     Geometry::new_from_wkt("LINESTRING (0 0, 1 1.0001)").unwrap(),
+///     Geometry::new_from_wkt("LINESTRING (0 0, 1 1)").unwrap(),
+/// ];
+///
+/// let exact = layer::dedup_geometries(&geometries, DedupMode::Exact).unwrap();
+/// assert_eq!(exact, vec![0, 1, 2]);
+/// let normalized = layer::dedup_geometries(&geometries, DedupMode::Normalized).unwrap();
+/// assert_eq!(normalized, vec![0, 2]);
+/// let close = layer::dedup_geometries(&geometries, DedupMode::WithinTolerance(0.01)).unwrap();
+/// assert_eq!(close, vec![0]);
+/// ```
+pub fn dedup_geometries<T: Borrow<Geometry>>(
+    geometries: &[T],
+    mode: DedupMode,
+) -> GResult<Vec<usize>> {
+    let normalized = match mode {
+        DedupMode::Exact => None,
+        DedupMode::Normalized | DedupMode::WithinTolerance(_) => Some(
+            geometries
+                .iter()
+                .map(|g| {
+                    let mut g = Geom::clone(g.borrow());
+                    g.normalize()?;
+                    Ok(g)
+                })
+                .collect::<GResult<Vec<_>>>()?,
+        ),
+    };
+    let get = |i: usize| -> &Geometry {
+        match normalized {
+            Some(ref normalized) => &normalized[i],
+            None => geometries[i].borrow(),
+        }
+    };
+
+    let mut representatives = Vec::new();
+    if let DedupMode::WithinTolerance(tolerance) = mode {
+        let normalized = normalized.as_deref().unwrap_or_default();
+        let tree = build_tree(normalized)?;
+        let mut is_duplicate = vec![false; geometries.len()];
+        for i in 0..geometries.len() {
+            if is_duplicate[i] {
+                continue;
+            }
+            representatives.push(i);
+            for j in query_within_distance(&tree, get(i), tolerance)? {
+                if j > i && !is_duplicate[j] && get(i).equals_exact(get(j), tolerance)? {
+                    is_duplicate[j] = true;
+                }
+            }
+        }
+    } else {
+        let mut buffer = Vec::new();
+        let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+        for i in 0..geometries.len() {
+            let same_hash = seen.entry(wkb_hash(get(i), &mut buffer)?).or_default();
+            let mut is_duplicate = false;
+            for &j in same_hash.iter() {
+                if get(i).equals_exact(get(j), 0.)? {
+                    is_duplicate = true;
+                    break;
+                }
+            }
+            if !is_duplicate {
+                same_hash.push(i);
+                representatives.push(i);
+            }
+        }
+    }
+    Ok(representatives)
+}
+
+#[cfg(any(feature = "v3_9_0", feature = "dox"))]
+fn is_polygonal(g: &Geometry) -> bool {
+    matches!(
+        g.geometry_type(),
+        GeometryTypes::Polygon | GeometryTypes::MultiPolygon
+    )
+}
+
+#[cfg(any(feature = "v3_9_0", feature = "dox"))]
+fn is_lineal(g: &Geometry) -> bool {
+    matches!(
+        g.geometry_type(),
+        GeometryTypes::LineString | GeometryTypes::LinearRing | GeometryTypes::MultiLineString
+    )
+}
+
+/// Snaps the vertices and segments of each geometry of `geoms` to the vertices of the
+/// `reference` geometries which are at most `tolerance` away (see [`Geom::snap`]). It is meant
+/// to be used before an overlay of the two layers, to avoid slivers along their nearly
+/// coincident edges.
+///
+/// Only the reference geometries close to each geometry are used, found with a [`STRtree`].
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geom, Geometry};
+///
+/// let reference = vec![Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap()];
+/// let mut geoms = vec![
+///     Geometry::new_from_wkt("POLYGON((10.1 0, 20 0, 20 10, 10.1 10, 10.1 0))").unwrap(),
+///     Geometry::new_from_wkt("POINT(30 30)").unwrap(),
+/// ];
+///
+/// layer::snap_to_layer(&mut geoms, &reference, 0.5).expect("snap_to_layer failed");
+/// assert_eq!(geoms[0].area(), Ok(100.));
+/// assert_eq!(geoms[1].to_wkt_precision(0).unwrap(), "POINT (30 30)");
+/// ```
+pub fn snap_to_layer<T: Borrow<Geometry>>(
+    geoms: &mut [Geometry],
+    reference: &[T],
+    tolerance: f64,
+) -> GResult<()> {
+    let tree = build_tree(reference)?;
+    for g in geoms.iter_mut() {
+        let candidates = query_within_distance(&tree, g, tolerance)?;
+        if candidates.is_empty() {
+            continue;
+        }
+        let targets = candidates
+            .into_iter()
+            .map(|i| Geom::clone(reference[i].borrow()))
+            .collect();
+        *g = g.snap(&Geometry::create_geometry_collection(targets)?, tolerance)?;
+    }
+    Ok(())
+}
+
+/// Returns the parts of `g`, or `g` itself if it isn't a collection.
+#[cfg(any(feature = "v3_9_0", feature = "dox"))]
+fn parts(g: &Geometry) -> GResult<Vec<Geometry>> {
+    match g.geometry_type() {
+        GeometryTypes::MultiPoint
+        | GeometryTypes::MultiLineString
+        | GeometryTypes::MultiPolygon
+        | GeometryTypes::GeometryCollection => (0..g.get_num_geometries()?)
+            .map(|i| g.get_geometry_n(i).map(|p| Geom::clone(&p)))
+            .collect(),
+        _ => Ok(vec![Geom::clone(g)]),
+    }
+}
+
+/// Rounds the coordinates of all the geometries of `geoms` to a grid of size `grid_size`, using
+/// snap rounding on the linework of the whole layer so edges shared by neighbouring geometries
+/// stay coincident (reducing each geometry independently opens slivers between them).
+///
+/// The noded and rounded linework of all the polygons and lines is computed once, then:
+///
+/// * each polygon is rebuilt from the faces of this linework lying inside it, so overlapping
+///   polygons keep overlapping,
+/// * each line is rebuilt from the edges of this linework its own snap rounding goes through,
+/// * points are rounded to the grid.
+///
+/// Geometries collapsing to nothing become empty. `GeometryCollection`s aren't supported.
+///
+/// Available using the `v3_9_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{layer, Geom, Geometry};
+///
+/// let mut geoms = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 10.2 0, 9.8 10, 0 10, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((10.2 0, 20 0, 20 10, 9.8 10, 10.2 0))").unwrap(),
+/// ];
+///
+/// layer::snap_round(&mut geoms, 1.).expect("snap_round failed");
+/// assert_eq!(geoms[0].area(), Ok(100.));
+/// assert_eq!(geoms[1].area(), Ok(100.));
+/// assert_eq!(geoms[0].intersection(&geoms[1]).unwrap().length(), Ok(10.));
+/// ```
+#[cfg(any(feature = "v3_9_0", feature = "dox"))]
+pub fn snap_round(geoms: &mut [Geometry], grid_size: f64) -> GResult<()> {
+    let mut linework = Vec::new();
+    for g in geoms.iter() {
+        match g.geometry_type() {
+            GeometryTypes::GeometryCollection => {
+                return Err(Error::ImpossibleOperation(
+                    "snap_round doesn't support GeometryCollections".to_owned(),
+                ))
+            }
+            _ if g.is_empty()? => {}
+            _ if is_polygonal(g) => linework.push(g.boundary()?),
+            _ if is_lineal(g) => linework.push(Geom::clone(g)),
+            _ => {}
+        }
+    }
+    let (faces, edges) = if linework.is_empty() {
+        (Vec::new(), Vec::new())
+    } else {
+        let linework = Geometry::create_geometry_collection(linework)?;
+        let noded = linework.unary_union_prec(grid_size)?;
+        (parts(&Geometry::polygonize(&[&noded])?)?, parts(&noded)?)
+    };
+
+    let tree = build_tree(geoms)?;
+    let mut rebuilt: Vec<Vec<Geometry>> = geoms.iter().map(|_| Vec::new()).collect();
+    for face in faces {
+        let point = face.point_on_surface()?;
+        let mut candidates = Vec::new();
+        tree.query(&point, |i| candidates.push(*i));
+        candidates.sort_unstable();
+        for i in candidates {
+            if is_polygonal(&geoms[i]) && geoms[i].contains(&point)? {
+                rebuilt[i].push(Geom::clone(&face));
+            }
+        }
+    }
+    // Snap rounding a line along with the edges around it gives back the edges of the layer it
+    // goes through, and only them: pieces of crossing lines or neighbouring polygon edges
+    // aren't part of its own rounding.
+    let edge_tree = build_tree(&edges)?;
+    for (g, pieces) in geoms.iter().zip(rebuilt.iter_mut()) {
+        if !is_lineal(g) || g.is_empty()? {
+            continue;
+        }
+        let candidates = query_within_distance(&edge_tree, g, grid_size)?;
+        if candidates.is_empty() {
+            continue;
+        }
+        let nearby = Geometry::create_multiline_string(
+            candidates.iter().map(|j| Geom::clone(&edges[*j])).collect(),
+        )?;
+        for piece in parts(&g.intersection_prec(&nearby, grid_size)?)? {
+            if piece.geometry_type() == GeometryTypes::LineString && !piece.is_empty()? {
+                pieces.push(piece);
+            }
+        }
+    }
+
+    for (g, pieces) in geoms.iter_mut().zip(rebuilt) {
+        if g.is_empty()? {
+            continue;
+        }
+        *g = if is_polygonal(g) {
+            match pieces.len() {
+                0 => Geometry::create_empty_polygon()?,
+                _ => union_all(&pieces)?,
+            }
+        } else if is_lineal(g) {
+            match pieces.len() {
+                0 => Geometry::create_empty_line_string()?,
+                _ => Geometry::create_multiline_string(pieces)?.line_merge()?,
+            }
+        } else {
+            g.set_precision(grid_size, Precision::ValidOutput)?
+        };
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(any(feature = "v3_9_0", feature = "dox"))]
+    #[test]
+    fn test_snap_round() {
+        use super::snap_round;
+        use crate::{Geom, Geometry};
+
+        let wkt = |wkt: &str| Geometry::new_from_wkt(wkt).unwrap();
+
+        // Lines don't get the pieces of the lines crossing them, nor the polygon edges running
+        // alongside them.
+        let mut geoms = vec![
+            wkt("LINESTRING(0 0, 10 0)"),
+            wkt("LINESTRING(5 -0.6, 5 5)"),
+            wkt("POLYGON((0 1, 10 1, 10 5, 0 5, 0 1))"),
+        ];
+        snap_round(&mut geoms, 1.).unwrap();
+        assert_eq!(geoms[0].length(), Ok(10.));
+        assert_eq!(geoms[1].length(), Ok(6.));
+        assert_eq!(geoms[2].area(), Ok(40.));
+
+        // Overlapping polygons keep overlapping.
+        let mut geoms = vec![
+            wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))"),
+            wkt("POLYGON((5 0, 15 0, 15 10, 5 10, 5 0))"),
+        ];
+        snap_round(&mut geoms, 1.).unwrap();
+        assert_eq!(geoms[0].area(), Ok(100.));
+        assert_eq!(geoms[1].area(), Ok(100.));
+    }
+}
@@ -7,40 +7,113 @@ pub use geo_types;
 #[cfg(any(feature = "json", feature = "dox"))]
 pub use geojson;
 pub use geos_sys as sys;
+#[cfg(feature = "h3")]
+pub use h3o;
+#[cfg(feature = "rstar")]
+pub use rstar;
 #[cfg(any(feature = "geo", feature = "dox"))]
 pub use wkt;
 
+#[macro_use]
+mod trace;
+
 pub(crate) mod functions;
 
+pub use accumulators::ConvexHullAccumulator;
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+pub use accumulators::{BoundingCircle, MinimumBoundingCircleAccumulator};
+pub use algorithms::orientation_index;
+#[cfg(any(feature = "v3_7_0", feature = "dox"))]
+pub use algorithms::segment_intersection;
+pub use bearing::azimuth;
 pub use buffer_params::{BufferParams, BufferParamsBuilder};
-pub use context_handle::{ContextHandle, HandlerCallback};
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+pub use compactness::CompactnessMetrics;
+pub use context_handle::{
+    ContextHandle, DetailedHandlerCallback, GeosErrorDetail, GeosErrorKind, HandlerCallback,
+};
 pub use coord_seq::CoordSeq;
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+pub use enums::MakeValidMethod;
+#[cfg(any(feature = "v3_11_0", feature = "dox"))]
+pub use enums::PolygonHullParameterMode;
 #[cfg(any(feature = "v3_6_0", feature = "dox"))]
 pub use enums::Precision;
 pub use enums::{
-    ByteOrder, CapStyle, CoordDimensions, Dimensions, GeometryTypes, JoinStyle, Ordinate,
-    Orientation, OutputDimension,
+    BoundaryNodeRule, ByteOrder, CapStyle, CoordDimensions, Dimensions, EqualityMode,
+    GeometryTypes, JoinStyle, Location, Ordinate, Orientation, OutputDimension, Side,
 };
-#[cfg(any(feature = "v3_7_0", feature = "dox"))]
-pub use functions::segment_intersection;
-pub use functions::{orientation_index, version};
+pub use functions::{union_all, version};
 pub use geometry::{ConstGeometry, Geom, Geometry};
+pub use geometry_builder::GeometryBuilder;
+pub use geometry_snapshot::GeometrySnapshot;
+pub use intersection_matrix::IntersectionMatrix;
+#[cfg(any(feature = "v3_9_0", feature = "dox"))]
+pub use label::LabelAnchor;
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+pub use make_valid_params::{MakeValidParams, MakeValidParamsBuilder};
+pub use morph::morph;
+pub use point_in_polygon::PointInPolygonIndex;
+pub use polygonize::Polygonized;
+pub use predicate::{CompiledPredicate, Predicate};
+pub use prepared_cache::PreparedCache;
 pub use prepared_geometry::PreparedGeometry;
-pub use spatial_index::{STRtree, SpatialIndex};
+pub use reader_limits::{LimitKind, ReaderLimits};
+pub use repair::{RepairReport, RepairStrategy};
+pub use spatial_index::{STRtree, STRtreeBuilder, SpatialIndex};
+pub use validity::{ValidityDetail, ValidityFlags};
 pub use wkb_writer::WKBWriter;
 pub use wkt_writer::WKTWriter;
 
+mod accumulators;
+pub mod algorithms;
+mod bearing;
 mod buffer_params;
+#[cfg(any(feature = "v3_8_0", feature = "dox"))]
+mod compactness;
 mod context_handle;
 mod coord_seq;
+pub mod coverage;
 mod error;
 #[cfg(any(feature = "geo", feature = "dox"))]
 pub mod from_geo;
 #[cfg(feature = "json")]
 pub mod from_geojson;
+mod geohash;
 mod geometry;
+mod geometry_builder;
+mod geometry_snapshot;
+#[cfg(feature = "h3")]
+mod h3;
+pub mod ingest;
+mod intersection_matrix;
+#[cfg(any(feature = "v3_9_0", feature = "dox"))]
+mod label;
+pub mod layer;
+pub mod linear_referencing;
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+mod make_valid_params;
+#[cfg(any(feature = "v3_12_0", feature = "dox"))]
+mod measures;
+mod medial_axis;
+mod morph;
+pub mod overlay;
+mod point_in_polygon;
+mod polygonize;
+mod predicate;
+mod prepared_cache;
 mod prepared_geometry;
+pub mod rasterize;
+mod reader_limits;
+mod repair;
+#[cfg(feature = "rstar")]
+mod rstar_object;
 mod spatial_index;
+pub mod spatial_join;
+pub mod spatial_relations;
+mod split;
+mod subdivide;
+pub mod tile;
 #[cfg(any(feature = "geo", feature = "dox"))]
 pub mod to_geo;
 #[cfg(feature = "json")]
@@ -52,6 +125,7 @@ mod voronoi;
 pub use voronoi::compute_voronoi;
 mod enums;
 mod traits;
+mod validity;
 mod wkb_writer;
 mod wkt_writer;
 
@@ -0,0 +1,186 @@
+//! Linear referencing over multi-part lines.
+//!
+//! [`Geom::interpolate`] and [`Geom::project`] only handle single `LineString`s. The functions
+//! of this module treat the parts of a `MultiLineString` (in order) as one continuous measure
+//! axis: the measure at the start of a part is the total length of the previous parts, so the
+//! gaps between parts aren't measured.
+use crate::error::{Error, GResult};
+use crate::{CoordDimensions, CoordSeq, Geom, Geometry, GeometryTypes};
+
+fn check_lineal<G: Geom>(lines: &G) -> GResult<()> {
+    match lines.geometry_type() {
+        GeometryTypes::LineString | GeometryTypes::MultiLineString => Ok(()),
+        t => Err(Error::GenericError(format!(
+            "Geometry must be a LineString or a MultiLineString, got a {t:?}"
+        ))),
+    }
+}
+
+/// Returns the non-empty parts of `lines` along with the measure at their start.
+fn parts<G: Geom>(lines: &G) -> GResult<Vec<(Geometry, f64)>> {
+    check_lineal(lines)?;
+    let mut res = Vec::new();
+    if lines.is_empty()? {
+        return Ok(res);
+    }
+    let mut offset = 0.;
+    for i in 0..lines.get_num_geometries()? {
+        let part = Geom::clone(&lines.get_geometry_n(i)?);
+        if part.is_empty()? {
+            continue;
+        }
+        let length = part.length()?;
+        res.push((part, offset));
+        offset += length;
+    }
+    Ok(res)
+}
+
+/// Returns the point at the given `distance` along `lines`, a `LineString` or a
+/// `MultiLineString`. As with [`Geom::interpolate`], a negative `distance` is measured from the
+/// end, and `distance` is clamped to the length of `lines`.
+///
+/// # Example
+///
+/// ```
+/// use geos::{linear_referencing, Geom, Geometry};
+///
+/// let route = Geometry::new_from_wkt("MULTILINESTRING((0 0, 10 0), (20 0, 20 10))").unwrap();
+///
+/// let point = linear_referencing::locate_along_multiline(&route, 15.).unwrap();
+/// assert_eq!(point.to_wkt_precision(0).unwrap(), "POINT (20 5)");
+/// let point = linear_referencing::locate_along_multiline(&route, -15.).unwrap();
+/// assert_eq!(point.to_wkt_precision(0).unwrap(), "POINT (5 0)");
+/// ```
+pub fn locate_along_multiline<G: Geom>(lines: &G, distance: f64) -> GResult<Geometry> {
+    let parts = parts(lines)?;
+    let (last, last_offset) = match parts.last() {
+        Some(last) => last,
+        None => return Geometry::create_empty_point(),
+    };
+    let total = last_offset + last.length()?;
+    let distance = if distance < 0. {
+        (total + distance).max(0.)
+    } else {
+        distance.min(total)
+    };
+    let (part, offset) = parts
+        .iter()
+        .rev()
+        .find(|(_, offset)| *offset <= distance)
+        .unwrap_or(&parts[0]);
+    part.interpolate(distance - offset)
+}
+
+/// Returns the measure (the distance along `lines`, a `LineString` or a `MultiLineString`) of
+/// the projection of `point` on the closest part of `lines`.
+///
+/// # Example
+///
+/// ```
+/// use geos::{linear_referencing, Geometry};
+///
+/// let route = Geometry::new_from_wkt("MULTILINESTRING((0 0, 10 0), (20 0, 20 10))").unwrap();
+/// let point = Geometry::new_from_wkt("POINT(21 5)").unwrap();
+///
+/// assert_eq!(linear_referencing::measure_of_point(&route, &point), Ok(15.));
+/// ```
+pub fn measure_of_point<G: Geom, P: Geom>(lines: &G, point: &P) -> GResult<f64> {
+    let mut closest: Option<(f64, &Geometry, f64)> = None;
+    let parts = parts(lines)?;
+    for (part, offset) in &parts {
+        let distance = part.distance(point)?;
+        if closest.map_or(true, |(d, _, _)| distance < d) {
+            closest = Some((distance, part, *offset));
+        }
+    }
+    match closest {
+        Some((_, part, offset)) => Ok(offset + part.project(point)?),
+        None => Err(Error::GenericError(
+            "cannot compute a measure on an empty geometry".to_owned(),
+        )),
+    }
+}
+
+fn lerp(a: &[f64], b: &[f64], t: f64) -> Vec<f64> {
+    a.iter().zip(b).map(|(a, b)| a + t * (b - a)).collect()
+}
+
+/// Pushes `coord` unless it is the same as the last one.
+fn push_coord(coords: &mut Vec<Vec<f64>>, coord: Vec<f64>) {
+    if coords.last() != Some(&coord) {
+        coords.push(coord);
+    }
+}
+
+pub(crate) fn line_substring<G: Geom>(
+    line: &G,
+    start_fraction: f64,
+    end_fraction: f64,
+) -> GResult<Geometry> {
+    if line.geometry_type() != GeometryTypes::LineString {
+        return Err(Error::GenericError(
+            "Geometry must be a LineString".to_owned(),
+        ));
+    }
+    if !(0. ..=1.).contains(&start_fraction)
+        || !(0. ..=1.).contains(&end_fraction)
+        || start_fraction > end_fraction
+    {
+        return Err(Error::GenericError(format!(
+            "invalid fractions {start_fraction} and {end_fraction}: they must be between 0 and 1, \
+             the start one being less than or equal to the end one"
+        )));
+    }
+    if line.is_empty()? {
+        return Ok(Geom::clone(line));
+    }
+    if start_fraction == end_fraction {
+        return line.interpolate_normalized(start_fraction);
+    }
+    let coord_seq = line.get_coord_seq()?;
+    let has_z = coord_seq.dimensions()? == CoordDimensions::ThreeD;
+    let mut coords = Vec::with_capacity(coord_seq.size()?);
+    for i in 0..coord_seq.size()? {
+        let mut coord = vec![coord_seq.get_x(i)?, coord_seq.get_y(i)?];
+        if has_z {
+            coord.push(coord_seq.get_z(i)?);
+        }
+        coords.push(coord);
+    }
+    let total: f64 = coords
+        .windows(2)
+        .map(|w| (w[1][0] - w[0][0]).hypot(w[1][1] - w[0][1]))
+        .sum();
+    if total == 0. {
+        return line.interpolate_normalized(start_fraction);
+    }
+    let (start, end) = (start_fraction * total, end_fraction * total);
+
+    let mut res = Vec::new();
+    let mut distance = 0.;
+    for w in coords.windows(2) {
+        let (a, b) = (&w[0], &w[1]);
+        let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+        let t = |d: f64| {
+            if length > 0. {
+                (d - distance) / length
+            } else {
+                0.
+            }
+        };
+        let next = distance + length;
+        if res.is_empty() && next >= start {
+            push_coord(&mut res, lerp(a, b, t(start)));
+        }
+        if !res.is_empty() {
+            if next >= end {
+                push_coord(&mut res, lerp(a, b, t(end)));
+                break;
+            }
+            push_coord(&mut res, b.clone());
+        }
+        distance = next;
+    }
+    Geometry::create_line_string(CoordSeq::new_from_vec(&res)?)
+}
@@ -0,0 +1,178 @@
+use crate::context_handle::PtrWrap;
+use crate::{
+    AsRaw, AsRawMut, ContextHandle, ContextHandling, ContextInteractions, Error, GResult,
+    MakeValidMethod,
+};
+
+use geos_sys::*;
+use std::sync::Arc;
+
+/// Contains the parameters which describe how a [Geometry](crate::Geometry) should be made
+/// valid using [make_valid_with_params](crate::Geom::make_valid_with_params).
+///
+/// Available using the `v3_10_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry, MakeValidMethod, MakeValidParams};
+///
+/// // A polygon whose hole collapses to a line.
+/// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 4 4, 3 3, 2 2))")
+///     .expect("Invalid geometry");
+///
+/// let params = MakeValidParams::builder()
+///     .method(MakeValidMethod::Structure)
+///     .keep_collapsed(false)
+///     .build()
+///     .expect("failed to create MakeValidParams");
+/// let valid = geom.make_valid_with_params(&params).expect("make_valid_with_params failed");
+/// assert_eq!(valid.is_valid(), true);
+/// assert_eq!(valid.area(), Ok(100.));
+/// ```
+pub struct MakeValidParams {
+    ptr: PtrWrap<*mut GEOSMakeValidParams>,
+    context: Arc<ContextHandle>,
+}
+
+/// Build options for a [`MakeValidParams`] object
+#[derive(Default)]
+pub struct MakeValidParamsBuilder {
+    method: Option<MakeValidMethod>,
+    keep_collapsed: Option<bool>,
+}
+
+impl MakeValidParams {
+    pub fn new() -> GResult<MakeValidParams> {
+        match ContextHandle::init_e(Some("MakeValidParams::new")) {
+            Ok(context) => unsafe {
+                let ptr = GEOSMakeValidParams_create_r(context.as_raw());
+                if ptr.is_null() {
+                    return Err(Error::NoConstructionFromNullPtr(
+                        "MakeValidParams::new".to_owned(),
+                    ));
+                }
+                Ok(MakeValidParams {
+                    ptr: PtrWrap(ptr),
+                    context: Arc::new(context),
+                })
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn builder() -> MakeValidParamsBuilder {
+        MakeValidParamsBuilder::default()
+    }
+
+    /// Sets the algorithm used to make the geometry valid. Default is
+    /// [`MakeValidMethod::Linework`].
+    pub fn set_method(&mut self, method: MakeValidMethod) -> GResult<()> {
+        unsafe {
+            let ret = GEOSMakeValidParams_setMethod_r(
+                self.get_raw_context(),
+                self.as_raw_mut_override(),
+                method.into(),
+            );
+            if ret == 0 {
+                Err(Error::GeosError("GEOSMakeValidParams_setMethod_r".into()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Sets whether the components which collapse to a lower dimension (a ring collapsing to a
+    /// line for example) are kept in the result. Only used by [`MakeValidMethod::Structure`].
+    /// Default is `false`.
+    pub fn set_keep_collapsed(&mut self, keep_collapsed: bool) -> GResult<()> {
+        unsafe {
+            let ret = GEOSMakeValidParams_setKeepCollapsed_r(
+                self.get_raw_context(),
+                self.as_raw_mut_override(),
+                keep_collapsed as _,
+            );
+            if ret == 0 {
+                Err(Error::GeosError(
+                    "GEOSMakeValidParams_setKeepCollapsed_r".into(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+unsafe impl Send for MakeValidParams {}
+unsafe impl Sync for MakeValidParams {}
+
+impl Drop for MakeValidParams {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { GEOSMakeValidParams_destroy_r(self.get_raw_context(), self.as_raw_mut()) };
+        }
+    }
+}
+
+impl AsRaw for MakeValidParams {
+    type RawType = GEOSMakeValidParams;
+
+    fn as_raw(&self) -> *const Self::RawType {
+        *self.ptr
+    }
+}
+
+impl AsRawMut for MakeValidParams {
+    type RawType = GEOSMakeValidParams;
+
+    unsafe fn as_raw_mut_override(&self) -> *mut Self::RawType {
+        *self.ptr
+    }
+}
+
+impl ContextInteractions for MakeValidParams {
+    fn set_context_handle(&mut self, context: ContextHandle) {
+        self.context = Arc::new(context);
+    }
+
+    fn get_context_handle(&self) -> &ContextHandle {
+        &self.context
+    }
+}
+
+impl ContextHandling for MakeValidParams {
+    type Context = Arc<ContextHandle>;
+
+    fn get_raw_context(&self) -> GEOSContextHandle_t {
+        self.context.as_raw()
+    }
+
+    fn clone_context(&self) -> Arc<ContextHandle> {
+        Arc::clone(&self.context)
+    }
+}
+
+impl MakeValidParamsBuilder {
+    /// See [`MakeValidParams::set_method`].
+    pub fn method(mut self, method: MakeValidMethod) -> MakeValidParamsBuilder {
+        self.method = Some(method);
+        self
+    }
+    /// See [`MakeValidParams::set_keep_collapsed`].
+    pub fn keep_collapsed(mut self, keep_collapsed: bool) -> MakeValidParamsBuilder {
+        self.keep_collapsed = Some(keep_collapsed);
+        self
+    }
+    /// Creates the [`MakeValidParams`], the options which weren't set keeping their default
+    /// value.
+    pub fn build(self) -> GResult<MakeValidParams> {
+        let mut params = MakeValidParams::new()?;
+        if let Some(method) = self.method {
+            params.set_method(method)?;
+        }
+        if let Some(keep_collapsed) = self.keep_collapsed {
+            params.set_keep_collapsed(keep_collapsed)?;
+        }
+        Ok(params)
+    }
+}
@@ -0,0 +1,252 @@
+use crate::error::{Error, GResult};
+use crate::{ContextHandle, Geom, Geometry, GeometryTypes};
+use geos_sys::*;
+use std::sync::Arc;
+
+/// `[x, y, z, m]`, `z` and `m` being NaN when missing.
+type Coord = [f64; 4];
+
+struct Coords {
+    coords: Vec<Coord>,
+    has_z: bool,
+}
+
+/// Reads the coordinates of a `Point` or a `LineString`, including their M values.
+fn read_coords<G: Geom>(geom: &G) -> GResult<Coords> {
+    unsafe {
+        let context = geom.get_raw_context();
+        let cs = GEOSGeom_getCoordSeq_r(context, geom.as_raw());
+        if cs.is_null() {
+            return Err(Error::GenericError(
+                "GEOSGeom_getCoordSeq_r failed".to_owned(),
+            ));
+        }
+        let mut size = 0;
+        if GEOSCoordSeq_getSize_r(context, cs, &mut size) == 0 {
+            return Err(Error::GenericError(
+                "GEOSCoordSeq_getSize_r failed".to_owned(),
+            ));
+        }
+        let has_z = GEOSCoordSeq_hasZ_r(context, cs as *mut _) == 1;
+        let has_m = GEOSCoordSeq_hasM_r(context, cs as *mut _) == 1;
+        let mut coords = Vec::with_capacity(size as _);
+        for i in 0..size {
+            let mut coord = [f64::NAN; 4];
+            for (ordinate, value) in coord.iter_mut().enumerate() {
+                if (ordinate == 2 && !has_z) || (ordinate == 3 && !has_m) {
+                    continue;
+                }
+                if GEOSCoordSeq_getOrdinate_r(context, cs, i, ordinate as _, value) == 0 {
+                    return Err(Error::GenericError(
+                        "failed to read coordinate sequence".to_owned(),
+                    ));
+                }
+            }
+            coords.push(coord);
+        }
+        Ok(Coords { coords, has_z })
+    }
+}
+
+/// Creates a `LineString` with M values.
+fn create_line_string(
+    context: Arc<ContextHandle>,
+    coords: &[Coord],
+    has_z: bool,
+) -> GResult<Geometry> {
+    unsafe {
+        let raw = context.as_raw();
+        let cs = GEOSCoordSeq_createWithDimensions_r(raw, coords.len() as _, has_z as _, 1);
+        if cs.is_null() {
+            return Err(Error::NoConstructionFromNullPtr(
+                "GEOSCoordSeq_createWithDimensions_r failed".to_owned(),
+            ));
+        }
+        for (i, coord) in coords.iter().enumerate() {
+            for (ordinate, value) in coord.iter().enumerate() {
+                if ordinate == 2 && !has_z {
+                    continue;
+                }
+                GEOSCoordSeq_setOrdinate_r(raw, cs, i as _, ordinate as _, *value);
+            }
+        }
+        let ptr = GEOSGeom_createLineString_r(raw, cs);
+        Geometry::new_from_raw(ptr, context, "create_line_string")
+    }
+}
+
+fn parts<G: Geom>(g: &G) -> GResult<Vec<Geometry>> {
+    if g.is_empty()? {
+        return Ok(Vec::new());
+    }
+    (0..g.get_num_geometries()?)
+        .map(|i| g.get_geometry_n(i).map(|p| Geom::clone(&p)))
+        .collect()
+}
+
+fn has_m<G: Geom>(g: &G) -> GResult<bool> {
+    match unsafe { GEOSHasM_r(g.get_raw_context(), g.as_raw()) } {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(Error::GenericError("GEOSHasM_r failed".to_owned())),
+    }
+}
+
+fn lerp(a: &Coord, b: &Coord, t: f64) -> Coord {
+    if t == 0. {
+        return *a;
+    } else if t == 1. {
+        return *b;
+    }
+    let mut res = [0.; 4];
+    for (i, value) in res.iter_mut().enumerate() {
+        *value = a[i] + t * (b[i] - a[i]);
+    }
+    res
+}
+
+/// Splits the line into the pieces whose M values are between `low` and `high`.
+fn line_between(coords: &[Coord], low: f64, high: f64) -> Vec<Vec<Coord>> {
+    let mut pieces = Vec::new();
+    let mut current: Vec<Coord> = Vec::new();
+    for w in coords.windows(2) {
+        let (a, b) = (&w[0], &w[1]);
+        let (t0, t1) = if a[3] == b[3] {
+            if low <= a[3] && a[3] <= high {
+                (0., 1.)
+            } else {
+                (1., 0.)
+            }
+        } else {
+            let t_low = (low - a[3]) / (b[3] - a[3]);
+            let t_high = (high - a[3]) / (b[3] - a[3]);
+            (t_low.min(t_high).max(0.), t_low.max(t_high).min(1.))
+        };
+        if t0 > t1 {
+            pieces.push(std::mem::take(&mut current));
+            continue;
+        }
+        let (start, end) = (lerp(a, b, t0), lerp(a, b, t1));
+        if current.last() != Some(&start) {
+            pieces.push(std::mem::replace(&mut current, vec![start]));
+        }
+        if current.last() != Some(&end) {
+            current.push(end);
+        }
+        if t1 < 1. {
+            pieces.push(std::mem::take(&mut current));
+        }
+    }
+    pieces.push(current);
+    pieces.retain(|piece| piece.len() > 1);
+    pieces
+}
+
+pub(crate) fn locate_between_measures<G: Geom>(
+    g: &G,
+    m_start: f64,
+    m_end: f64,
+) -> GResult<Geometry> {
+    if !g.is_empty()? && !has_m(g)? {
+        return Err(Error::GenericError("Geometry has no M values".to_owned()));
+    }
+    let (low, high) = (m_start.min(m_end), m_start.max(m_end));
+    match g.geometry_type() {
+        GeometryTypes::Point | GeometryTypes::MultiPoint => {
+            let mut points = Vec::new();
+            for point in parts(g)? {
+                // Empty points have no M value to compare.
+                let m = match read_coords(&point)?.coords.first() {
+                    Some(coord) => coord[3],
+                    None => continue,
+                };
+                if low <= m && m <= high {
+                    points.push(point);
+                }
+            }
+            Geometry::create_multipoint(points)
+        }
+        GeometryTypes::LineString | GeometryTypes::MultiLineString => {
+            let mut lines = Vec::new();
+            for line in parts(g)? {
+                let coords = read_coords(&line)?;
+                for piece in line_between(&coords.coords, low, high) {
+                    lines.push(create_line_string(g.clone_context(), &piece, coords.has_z)?);
+                }
+            }
+            Geometry::create_multiline_string(lines)
+        }
+        t => Err(Error::ImpossibleOperation(format!(
+            "cannot locate measures on a {t:?}"
+        ))),
+    }
+}
+
+pub(crate) fn add_measure<G: Geom>(g: &G, start: f64, end: f64) -> GResult<Geometry> {
+    let geometry_type = g.geometry_type();
+    if !matches!(
+        geometry_type,
+        GeometryTypes::LineString | GeometryTypes::MultiLineString
+    ) {
+        return Err(Error::ImpossibleOperation(format!(
+            "cannot add measures to a {geometry_type:?}"
+        )));
+    }
+    let lines = parts(g)?
+        .iter()
+        .map(read_coords)
+        .collect::<GResult<Vec<_>>>()?;
+    let total: f64 = lines
+        .iter()
+        .flat_map(|line| line.coords.windows(2))
+        .map(|w| (w[1][0] - w[0][0]).hypot(w[1][1] - w[0][1]))
+        .sum();
+    let mut distance = 0.;
+    let mut res = Vec::with_capacity(lines.len());
+    for mut line in lines {
+        let mut previous: Option<Coord> = None;
+        for coord in line.coords.iter_mut() {
+            if let Some(p) = previous {
+                distance += (coord[0] - p[0]).hypot(coord[1] - p[1]);
+            }
+            previous = Some(*coord);
+            coord[3] = if total > 0. {
+                start + (end - start) * distance / total
+            } else {
+                start
+            };
+        }
+        res.push(create_line_string(
+            g.clone_context(),
+            &line.coords,
+            line.has_z,
+        )?);
+    }
+    if geometry_type == GeometryTypes::LineString {
+        match res.pop() {
+            Some(line) => Ok(line),
+            None => create_line_string(g.clone_context(), &[], false),
+        }
+    } else {
+        Geometry::create_multiline_string(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::locate_between_measures;
+    use crate::{Geom, Geometry};
+
+    #[test]
+    fn test_locate_between_measures_empty_parts() {
+        let points = Geometry::new_from_wkt("MULTIPOINT M ((1 1 5), EMPTY, (2 2 50))").unwrap();
+        let located = locate_between_measures(&points, 0., 10.).unwrap();
+        assert_eq!(located.get_num_geometries(), Ok(1));
+        assert_eq!(located.get_geometry_n(0).unwrap().get_x(), Ok(1.));
+
+        let lines = Geometry::new_from_wkt("MULTILINESTRING M ((0 0 0, 10 0 10), EMPTY)").unwrap();
+        let located = locate_between_measures(&lines, 0., 5.).unwrap();
+        assert_eq!(located.get_num_geometries(), Ok(1));
+        assert_eq!(located.length(), Ok(5.));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::{Error, GResult};
+use crate::functions::create_point_with_context;
+use crate::{Geom, Geometry, GeometryTypes, PreparedGeometry};
+
+/// Appends the vertices of `ring` to `out`, adding points so that consecutive points are at
+/// most `distance` apart.
+fn densify_ring<G: Geom>(ring: &G, distance: f64, out: &mut Vec<(f64, f64)>) -> GResult<()> {
+    let coords = ring.get_coord_seq()?;
+    let size = coords.size()?;
+    for i in 1..size {
+        let (ax, ay) = (coords.get_x(i - 1)?, coords.get_y(i - 1)?);
+        let (bx, by) = (coords.get_x(i)?, coords.get_y(i)?);
+        let steps = ((bx - ax).hypot(by - ay) / distance).ceil().max(1.) as usize;
+        for step in 0..steps {
+            let t = step as f64 / steps as f64;
+            out.push((ax + t * (bx - ax), ay + t * (by - ay)));
+        }
+    }
+    Ok(())
+}
+
+fn boundary_points<G: Geom>(polygon: &G, distance: f64, out: &mut Vec<(f64, f64)>) -> GResult<()> {
+    if polygon.is_empty()? {
+        return Ok(());
+    }
+    densify_ring(&polygon.get_exterior_ring()?, distance, out)?;
+    for i in 0..polygon.get_num_interior_rings()? {
+        densify_ring(&polygon.get_interior_ring_n(i as _)?, distance, out)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn approximate_medial_axis<G: Geom>(g: &G, densify_distance: f64) -> GResult<Geometry> {
+    if densify_distance.is_nan() || densify_distance <= 0. {
+        return Err(Error::GenericError(
+            "densify_distance must be positive".to_owned(),
+        ));
+    }
+    let mut points = Vec::new();
+    match g.geometry_type() {
+        GeometryTypes::Polygon => boundary_points(g, densify_distance, &mut points)?,
+        GeometryTypes::MultiPolygon => {
+            if !g.is_empty()? {
+                for i in 0..g.get_num_geometries()? {
+                    boundary_points(&g.get_geometry_n(i)?, densify_distance, &mut points)?;
+                }
+            }
+        }
+        t => {
+            return Err(Error::GenericError(format!(
+                "Geometry must be a Polygon or a MultiPolygon, got a {t:?}"
+            )))
+        }
+    }
+    if points.is_empty() {
+        return Geometry::create_empty_collection(GeometryTypes::MultiLineString);
+    }
+    let sites = Geometry::create_multipoint(
+        points
+            .into_iter()
+            .map(|(x, y)| create_point_with_context(g.clone_context(), x, y))
+            .collect::<GResult<_>>()?,
+    )?;
+    let edges = sites.voronoi(None::<&Geometry>, 0., true)?;
+    let prepared = PreparedGeometry::new(g)?;
+    let mut interior = Vec::new();
+    for i in 0..edges.get_num_geometries()? {
+        let edge = edges.get_geometry_n(i)?;
+        if prepared.contains(&edge)? {
+            interior.push(Geom::clone(&edge));
+        }
+    }
+    Geometry::create_multiline_string(interior)?.line_merge()
+}
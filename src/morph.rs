@@ -0,0 +1,155 @@
+use crate::error::{Error, GResult};
+use crate::{CoordSeq, Geom, Geometry, GeometryTypes};
+
+type Ring = Vec<(f64, f64)>;
+
+/// Reads the vertices of `ring`, without the closing one, in counterclockwise order.
+fn read_ring<G: Geom>(ring: &G) -> GResult<Ring> {
+    let coords = ring.get_coord_seq()?;
+    let mut res = (0..coords.size()?)
+        .map(|i| Ok((coords.get_x(i)?, coords.get_y(i)?)))
+        .collect::<GResult<Ring>>()?;
+    res.pop();
+    let signed_area: f64 = (0..res.len())
+        .map(|i| {
+            let ((ax, ay), (bx, by)) = (res[i], res[(i + 1) % res.len()]);
+            ax * by - bx * ay
+        })
+        .sum();
+    if signed_area < 0. {
+        res.reverse();
+    }
+    Ok(res)
+}
+
+fn centroid(ring: &[(f64, f64)]) -> (f64, f64) {
+    let n = ring.len() as f64;
+    let (x, y) = ring
+        .iter()
+        .fold((0., 0.), |(sx, sy), (x, y)| (sx + x, sy + y));
+    (x / n, y / n)
+}
+
+/// Rotates `b` so it starts at the vertex corresponding to the start of `a`, once both rings are
+/// centered.
+fn align(a: &[(f64, f64)], b: &mut Ring) {
+    let ((acx, acy), (bcx, bcy)) = (centroid(a), centroid(b));
+    let (ax, ay) = (a[0].0 - acx, a[0].1 - acy);
+    let distance = |&(x, y): &(f64, f64)| (x - bcx - ax).hypot(y - bcy - ay);
+    let start = (0..b.len())
+        .min_by(|&i, &j| distance(&b[i]).total_cmp(&distance(&b[j])))
+        .unwrap_or(0);
+    b.rotate_left(start);
+}
+
+/// Returns the position of each vertex of the closed `ring` as a fraction of its perimeter.
+fn fractions(ring: &[(f64, f64)]) -> Vec<f64> {
+    let mut res = Vec::with_capacity(ring.len());
+    let mut length = 0.;
+    for i in 0..ring.len() {
+        res.push(length);
+        let ((ax, ay), (bx, by)) = (ring[i], ring[(i + 1) % ring.len()]);
+        length += (bx - ax).hypot(by - ay);
+    }
+    if length > 0. {
+        res.iter_mut().for_each(|f| *f /= length);
+    }
+    res
+}
+
+/// Returns the points of the closed `ring` at the given (sorted) perimeter `fractions`.
+fn resample(ring: &[(f64, f64)], ring_fractions: &[f64], fractions: &[f64]) -> Ring {
+    let mut res = Vec::with_capacity(fractions.len());
+    let mut segment = 0;
+    for &f in fractions {
+        while segment + 1 < ring.len() && ring_fractions[segment + 1] <= f {
+            segment += 1;
+        }
+        let ((ax, ay), (bx, by)) = (ring[segment], ring[(segment + 1) % ring.len()]);
+        let end = ring_fractions.get(segment + 1).copied().unwrap_or(1.);
+        let start = ring_fractions[segment];
+        let t = if end > start {
+            (f - start) / (end - start)
+        } else {
+            0.
+        };
+        res.push((ax + t * (bx - ax), ay + t * (by - ay)));
+    }
+    res
+}
+
+fn morph_ring<A: Geom, B: Geom>(a: &A, b: &B, t: f64) -> GResult<Geometry> {
+    let a = read_ring(a)?;
+    let mut b = read_ring(b)?;
+    if a.len() < 3 || b.len() < 3 {
+        return Err(Error::GenericError("rings must not be empty".to_owned()));
+    }
+    align(&a, &mut b);
+    let (a_fractions, b_fractions) = (fractions(&a), fractions(&b));
+    // Both rings are sampled at the vertices of each other, so the shapes are exactly preserved
+    // at `t == 0` and `t == 1`.
+    let mut all = a_fractions.clone();
+    all.extend_from_slice(&b_fractions);
+    all.sort_by(f64::total_cmp);
+    all.dedup();
+    let (a, b) = (
+        resample(&a, &a_fractions, &all),
+        resample(&b, &b_fractions, &all),
+    );
+    let mut coords: Vec<_> = a
+        .iter()
+        .zip(&b)
+        .map(|(&(ax, ay), &(bx, by))| [ax + t * (bx - ax), ay + t * (by - ay)])
+        .collect();
+    coords.push(coords[0]);
+    CoordSeq::new_from_vec(&coords)?.create_linear_ring()
+}
+
+/// Returns the polygon at `t` (between `0` and `1`) in the transition from `a` to `b`, two
+/// polygons, for animations or temporal interpolation of boundaries.
+///
+/// The rings of `a` and `b` are oriented the same way, aligned on the vertices the closest to
+/// each other (relatively to the ring centroids) and sampled at the same perimeter fractions, then
+/// each pair of corresponding points is linearly interpolated. Holes are paired by index, so `a`
+/// and `b` must have the same number of holes.
+///
+/// The result isn't guaranteed to be valid when `a` and `b` are very different.
+///
+/// # Example
+///
+/// ```
+/// use geos::{morph, Geom, Geometry};
+///
+/// let a = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap();
+/// let b = Geometry::new_from_wkt("POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))").unwrap();
+///
+/// let middle = morph(&a, &b, 0.5).expect("morph failed");
+/// assert_eq!(middle.area(), Ok(9.));
+/// assert_eq!(morph(&a, &b, 0.).unwrap().equals(&a), Ok(true));
+/// assert_eq!(morph(&a, &b, 1.).unwrap().equals(&b), Ok(true));
+/// ```
+pub fn morph<A: Geom, B: Geom>(a: &A, b: &B, t: f64) -> GResult<Geometry> {
+    for g in [a.geometry_type(), b.geometry_type()] {
+        if g != GeometryTypes::Polygon {
+            return Err(Error::GenericError(format!(
+                "Geometries must be Polygons, got a {g:?}"
+            )));
+        }
+    }
+    if !(0. ..=1.).contains(&t) {
+        return Err(Error::GenericError(format!(
+            "t must be between 0 and 1, got {t}"
+        )));
+    }
+    let holes = a.get_num_interior_rings()?;
+    if b.get_num_interior_rings()? != holes {
+        return Err(Error::ImpossibleOperation(
+            "polygons must have the same number of holes".to_owned(),
+        ));
+    }
+    let exterior = morph_ring(&a.get_exterior_ring()?, &b.get_exterior_ring()?, t)?;
+    let interiors = (0..holes as u32)
+        .map(|i| morph_ring(&a.get_interior_ring_n(i)?, &b.get_interior_ring_n(i)?, t))
+        .collect::<GResult<_>>()?;
+    Geometry::create_polygon(exterior, interiors)
+}
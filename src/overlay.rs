@@ -0,0 +1,44 @@
+//! Overlay operations keeping track of the attributes attached to the input geometries.
+//!
+//! See the [`layer`](crate::layer) module for the index-based variants returning input indices.
+use crate::error::GResult;
+use crate::{layer, Geometry};
+
+/// Computes the intersection of each pair of intersecting geometries of `a` and `b`, returning
+/// each resulting geometry along with references to the attributes of both inputs.
+///
+/// Empty intersections are skipped.
+///
+/// # Example
+///
+/// ```
+/// use geos::{overlay, Geom, Geometry};
+///
+/// let parcels = vec![
+///     (Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))").unwrap(), "parcel 1"),
+///     (Geometry::new_from_wkt("POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))").unwrap(), "parcel 2"),
+/// ];
+/// let zones = vec![
+///     (Geometry::new_from_wkt("POLYGON((1 0, 3 0, 3 2, 1 2, 1 0))").unwrap(), 42),
+/// ];
+///
+/// let res = overlay::intersect_with_data(&parcels, &zones).expect("intersection failed");
+/// assert_eq!(res.len(), 2);
+/// assert_eq!(res[0].0.area(), Ok(2.));
+/// assert_eq!((res[0].1, res[0].2), (&"parcel 1", &42));
+/// assert_eq!((res[1].1, res[1].2), (&"parcel 2", &42));
+/// ```
+pub fn intersect_with_data<'a, 'b, A, B>(
+    a: &'a [(Geometry, A)],
+    b: &'b [(Geometry, B)],
+) -> GResult<Vec<(Geometry, &'a A, &'b B)>> {
+    let geoms_a = a.iter().map(|(g, _)| g).collect::<Vec<_>>();
+    let geoms_b = b.iter().map(|(g, _)| g).collect::<Vec<_>>();
+    Ok(layer::intersection(&geoms_a, &geoms_b)?
+        .into_iter()
+        .filter_map(|piece| match (piece.a, piece.b) {
+            (Some(i), Some(j)) => Some((piece.geometry, &a[i].1, &b[j].1)),
+            _ => None,
+        })
+        .collect())
+}
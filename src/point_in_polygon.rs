@@ -0,0 +1,148 @@
+use crate::error::GResult;
+use crate::functions::create_point_with_context;
+#[cfg(feature = "rayon")]
+use crate::prepared_geometry::PARALLEL_THRESHOLD;
+#[cfg(feature = "rayon")]
+use crate::AsRaw;
+use crate::{ContextHandle, Geometry, PreparedGeometry, STRtree, SpatialIndex};
+#[cfg(feature = "rayon")]
+use geos_sys::GEOSGeom_clone_r;
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Index used to find which polygon contains a point, optimized to locate a lot of points
+/// (GPS points into administrative areas for example).
+///
+/// Polygons are stored in a [`STRtree`] and each of them is prepared (see
+/// [`PreparedGeometry`]), so each lookup only checks the few polygons whose envelope contains
+/// the point, using their prepared version.
+///
+/// `PointInPolygonIndex` is not `Sync` since GEOS lazily builds its internal indexes on the
+/// first queries. To locate points from several threads, create one index per thread.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geometry, PointInPolygonIndex};
+///
+/// let polygons = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((10 0, 20 0, 20 10, 10 10, 10 0))").unwrap(),
+/// ];
+/// let index = PointInPolygonIndex::new(polygons).expect("failed to create index");
+///
+/// assert_eq!(index.locate(15., 5.), Ok(Some(1)));
+/// assert_eq!(
+///     index.locate_many(&[(5., 5.), (15., 5.), (30., 5.)]),
+///     Ok(vec![Some(0), Some(1), None]),
+/// );
+/// ```
+pub struct PointInPolygonIndex {
+    // Declared before `polygons` so prepared geometries are dropped first.
+    prepared: Vec<PreparedGeometry>,
+    polygons: Vec<Geometry>,
+    tree: STRtree<usize>,
+    context: Arc<ContextHandle>,
+    not_sync: PhantomData<Cell<()>>,
+}
+
+impl PointInPolygonIndex {
+    /// Creates a new index from the given polygons. Indices returned by
+    /// [`PointInPolygonIndex::locate`] are indices in `polygons`.
+    pub fn new(polygons: Vec<Geometry>) -> GResult<PointInPolygonIndex> {
+        let mut tree = STRtree::with_capacity(10)?;
+        let mut prepared = Vec::with_capacity(polygons.len());
+        for (i, polygon) in polygons.iter().enumerate() {
+            tree.insert(polygon, i)?;
+            prepared.push(PreparedGeometry::new(polygon)?);
+        }
+        Ok(PointInPolygonIndex {
+            prepared,
+            polygons,
+            tree,
+            context: Arc::new(ContextHandle::init_e(Some("PointInPolygonIndex::new"))?),
+            not_sync: PhantomData,
+        })
+    }
+
+    /// Returns the polygons of the index.
+    pub fn polygons(&self) -> &[Geometry] {
+        &self.polygons
+    }
+
+    fn locate_point(&self, point: &Geometry) -> GResult<Option<usize>> {
+        let mut candidates = Vec::new();
+        self.tree.query(point, |i| candidates.push(*i));
+        candidates.sort_unstable();
+        for i in candidates {
+            if self.prepared[i].intersects(point)? {
+                return Ok(Some(i));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the index of the polygon containing the point (`x`, `y`), `None` if there is
+    /// none. Points on a boundary are considered inside. If several polygons contain the point,
+    /// the lowest index is returned.
+    pub fn locate(&self, x: f64, y: f64) -> GResult<Option<usize>> {
+        self.locate_point(&create_point_with_context(self.context.clone(), x, y)?)
+    }
+
+    /// Same as [`PointInPolygonIndex::locate`] for each of the given points.
+    ///
+    /// With the `rayon` feature, `points` is split across the rayon threads, each thread using
+    /// its own context and its own prepared copies of the polygons it checks (GEOS prepared
+    /// geometries aren't thread-safe).
+    pub fn locate_many(&self, points: &[(f64, f64)]) -> GResult<Vec<Option<usize>>> {
+        #[cfg(feature = "rayon")]
+        if points.len() >= PARALLEL_THRESHOLD {
+            use rayon::prelude::*;
+
+            // `self` isn't `Sync`, unlike the tree and the polygons.
+            let (tree, polygons) = (&self.tree, &self.polygons);
+            let nb_threads = rayon::current_num_threads().max(1);
+            let chunk_size = (points.len() + nb_threads - 1) / nb_threads;
+            let chunks = points
+                .par_chunks(chunk_size)
+                .map(|chunk| -> GResult<Vec<Option<usize>>> {
+                    let context = Arc::new(ContextHandle::init_e(Some(
+                        "PointInPolygonIndex::locate_many",
+                    ))?);
+                    // Polygons are only prepared once they are a candidate for a point.
+                    let mut prepared: Vec<Option<PreparedGeometry>> =
+                        polygons.iter().map(|_| None).collect();
+                    let mut locate = |x, y| -> GResult<Option<usize>> {
+                        let point = create_point_with_context(context.clone(), x, y)?;
+                        let mut candidates = Vec::new();
+                        tree.query(&point, |i| candidates.push(*i));
+                        candidates.sort_unstable();
+                        for i in candidates {
+                            let polygon = match &prepared[i] {
+                                Some(polygon) => polygon,
+                                None => {
+                                    let geom = unsafe {
+                                        let ptr = GEOSGeom_clone_r(
+                                            context.as_raw(),
+                                            polygons[i].as_raw(),
+                                        );
+                                        Geometry::new_from_raw(ptr, context.clone(), "locate_many")?
+                                    };
+                                    prepared[i].insert(PreparedGeometry::from_geometry(geom)?)
+                                }
+                            };
+                            if polygon.intersects(&point)? {
+                                return Ok(Some(i));
+                            }
+                        }
+                        Ok(None)
+                    };
+                    chunk.iter().map(|&(x, y)| locate(x, y)).collect()
+                })
+                .collect::<GResult<Vec<_>>>()?;
+            return Ok(chunks.into_iter().flatten().collect());
+        }
+        points.iter().map(|&(x, y)| self.locate(x, y)).collect()
+    }
+}
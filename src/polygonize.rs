@@ -0,0 +1,64 @@
+use crate::error::GResult;
+use crate::{Geom, Geometry, GeometryTypes};
+use std::borrow::Borrow;
+
+/// Full output of a polygonization, as returned by [`Geometry::polygonize_detailed`]: the
+/// polygons along with the linework which couldn't be used to build them.
+pub struct Polygonized {
+    polygons: Geometry,
+    cut_edges: Geometry,
+    dangles: Geometry,
+    invalid_rings: Geometry,
+}
+
+impl Polygonized {
+    /// Returns the polygons built from the linework, as a `GeometryCollection`.
+    pub fn polygons(&self) -> &Geometry {
+        &self.polygons
+    }
+
+    /// Returns the edges which have a polygon on both sides, but aren't part of their boundary.
+    pub fn cut_edges(&self) -> &Geometry {
+        &self.cut_edges
+    }
+
+    /// Returns the edges which have at least one end not connected to any other edge.
+    pub fn dangles(&self) -> &Geometry {
+        &self.dangles
+    }
+
+    /// Returns the rings which are closed but would form invalid polygons (self-intersecting
+    /// for instance).
+    pub fn invalid_rings(&self) -> &Geometry {
+        &self.invalid_rings
+    }
+
+    /// Returns the polygons, dropping the rest of the output.
+    pub fn into_polygons(self) -> Geometry {
+        self.polygons
+    }
+}
+
+fn or_empty(g: Option<Geometry>) -> GResult<Geometry> {
+    match g {
+        Some(g) => Ok(g),
+        None => Geometry::create_empty_collection(GeometryTypes::GeometryCollection),
+    }
+}
+
+pub(crate) fn polygonize_detailed<T: Borrow<Geometry>>(geometries: &[T]) -> GResult<Polygonized> {
+    let input = if geometries.is_empty() {
+        Geometry::create_empty_collection(GeometryTypes::GeometryCollection)?
+    } else {
+        Geometry::create_geometry_collection(
+            geometries.iter().map(|g| Geom::clone(g.borrow())).collect(),
+        )?
+    };
+    let (polygons, cut_edges, dangles, invalid_rings) = input.polygonize_full()?;
+    Ok(Polygonized {
+        polygons,
+        cut_edges: or_empty(cut_edges)?,
+        dangles: or_empty(dangles)?,
+        invalid_rings: or_empty(invalid_rings)?,
+    })
+}
@@ -0,0 +1,498 @@
+use crate::error::{Error, GResult};
+use crate::intersection_matrix::parse_pattern;
+use crate::{Geom, Geometry, IntersectionMatrix, PreparedGeometry};
+use std::borrow::Borrow;
+use std::str::FromStr;
+
+/// A combination of spatial predicates, evaluated between a fixed *target* geometry and
+/// candidate geometries: `Predicate::Contains` is true for the candidates contained in the
+/// target.
+///
+/// Predicates are combined with [`Predicate::and`], [`Predicate::or`], [`Predicate::and_not`]
+/// and `!`, or parsed from a string such as `"intersects and not touches"` or
+/// `"(contains or relate('T*T******')) and not equals"`. Before being evaluated, they are
+/// compiled against the target with [`Predicate::compile`].
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geometry, Predicate};
+///
+/// let target = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+/// let candidates = vec![
+///     Geometry::new_from_wkt("POINT(5 5)").unwrap(),
+///     Geometry::new_from_wkt("POINT(10 5)").unwrap(),
+///     Geometry::new_from_wkt("POINT(20 5)").unwrap(),
+/// ];
+///
+/// let predicate = Predicate::intersects().and_not(Predicate::touches());
+/// assert_eq!(predicate, "intersects and not touches".parse().unwrap());
+///
+/// let compiled = predicate.compile(&target).expect("compile failed");
+/// assert_eq!(compiled.filter(&candidates), Ok(vec![0]));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Predicate {
+    Contains,
+    ContainsProperly,
+    CoveredBy,
+    Covers,
+    Crosses,
+    Disjoint,
+    Equals,
+    Intersects,
+    Overlaps,
+    Touches,
+    Within,
+    /// Matches the DE-9IM intersection matrix against a pattern such as `"T*F**FFF*"`.
+    Relate(String),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+}
+
+impl Predicate {
+    pub fn contains() -> Predicate {
+        Predicate::Contains
+    }
+
+    pub fn contains_properly() -> Predicate {
+        Predicate::ContainsProperly
+    }
+
+    pub fn covered_by() -> Predicate {
+        Predicate::CoveredBy
+    }
+
+    pub fn covers() -> Predicate {
+        Predicate::Covers
+    }
+
+    pub fn crosses() -> Predicate {
+        Predicate::Crosses
+    }
+
+    pub fn disjoint() -> Predicate {
+        Predicate::Disjoint
+    }
+
+    pub fn equals() -> Predicate {
+        Predicate::Equals
+    }
+
+    pub fn intersects() -> Predicate {
+        Predicate::Intersects
+    }
+
+    pub fn overlaps() -> Predicate {
+        Predicate::Overlaps
+    }
+
+    pub fn touches() -> Predicate {
+        Predicate::Touches
+    }
+
+    pub fn within() -> Predicate {
+        Predicate::Within
+    }
+
+    pub fn relate(pattern: &str) -> Predicate {
+        Predicate::Relate(pattern.to_owned())
+    }
+
+    pub fn and(self, other: Predicate) -> Predicate {
+        Predicate::And(Box::new(self), Box::new(other))
+    }
+
+    pub fn or(self, other: Predicate) -> Predicate {
+        Predicate::Or(Box::new(self), Box::new(other))
+    }
+
+    pub fn and_not(self, other: Predicate) -> Predicate {
+        self.and(!other)
+    }
+
+    /// Prepares `target` and builds the evaluation plan of the predicate.
+    ///
+    /// All the predicates except `Equals` and `Relate` are evaluated using the
+    /// [`PreparedGeometry`] of `target`. The operands of `and` and `or` are reordered so the
+    /// cheapest ones are evaluated first, and the intersection matrix is computed at most once
+    /// per candidate, whatever the number of `Relate` predicates.
+    pub fn compile<G: Geom>(&self, target: &G) -> GResult<CompiledPredicate> {
+        let plan = Node::compile(self)?;
+        let target = Geom::clone(target);
+        Ok(CompiledPredicate {
+            prepared: PreparedGeometry::new(&target)?,
+            target,
+            plan,
+        })
+    }
+
+    /// Returns `true` if the predicate can only be true for geometries intersecting the target,
+    /// so that the candidates can be looked up in a spatial index. It is `false` when unknown.
+    pub(crate) fn requires_intersection(&self) -> bool {
+        match self {
+            Predicate::Disjoint | Predicate::Not(_) => false,
+            // One of the interior/boundary cells of the matrix must be non-empty.
+            Predicate::Relate(pattern) => pattern
+                .bytes()
+                .enumerate()
+                .any(|(i, c)| matches!(i, 0 | 1 | 3 | 4) && b"T012".contains(&c)),
+            Predicate::And(a, b) => a.requires_intersection() || b.requires_intersection(),
+            Predicate::Or(a, b) => a.requires_intersection() && b.requires_intersection(),
+            _ => true,
+        }
+    }
+}
+
+impl std::ops::Not for Predicate {
+    type Output = Predicate;
+
+    fn not(self) -> Predicate {
+        Predicate::Not(Box::new(self))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Prepared {
+    Contains,
+    ContainsProperly,
+    CoveredBy,
+    Covers,
+    Crosses,
+    Disjoint,
+    Intersects,
+    Overlaps,
+    Touches,
+    Within,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Prepared(Prepared),
+    Equals,
+    Relate([u8; 9]),
+    And(Vec<Node>),
+    Or(Vec<Node>),
+    Not(Box<Node>),
+}
+
+impl Node {
+    fn compile(predicate: &Predicate) -> GResult<Node> {
+        Ok(match predicate {
+            Predicate::Contains => Node::Prepared(Prepared::Contains),
+            Predicate::ContainsProperly => Node::Prepared(Prepared::ContainsProperly),
+            Predicate::CoveredBy => Node::Prepared(Prepared::CoveredBy),
+            Predicate::Covers => Node::Prepared(Prepared::Covers),
+            Predicate::Crosses => Node::Prepared(Prepared::Crosses),
+            Predicate::Disjoint => Node::Prepared(Prepared::Disjoint),
+            Predicate::Intersects => Node::Prepared(Prepared::Intersects),
+            Predicate::Overlaps => Node::Prepared(Prepared::Overlaps),
+            Predicate::Touches => Node::Prepared(Prepared::Touches),
+            Predicate::Within => Node::Prepared(Prepared::Within),
+            Predicate::Equals => Node::Equals,
+            Predicate::Relate(pattern) => Node::Relate(parse_pattern(pattern)?),
+            Predicate::And(a, b) => Node::And(Node::flatten(a, b, true)?),
+            Predicate::Or(a, b) => Node::Or(Node::flatten(a, b, false)?),
+            Predicate::Not(p) => Node::Not(Box::new(Node::compile(p)?)),
+        })
+    }
+
+    /// Merges nested `and` (or `or`) operands and sorts them by cost.
+    fn flatten(a: &Predicate, b: &Predicate, is_and: bool) -> GResult<Vec<Node>> {
+        let mut nodes = Vec::new();
+        for node in [Node::compile(a)?, Node::compile(b)?] {
+            match node {
+                Node::And(children) if is_and => nodes.extend(children),
+                Node::Or(children) if !is_and => nodes.extend(children),
+                node => nodes.push(node),
+            }
+        }
+        nodes.sort_by_key(Node::cost);
+        Ok(nodes)
+    }
+
+    fn cost(&self) -> u32 {
+        match self {
+            // Both start with an envelope check.
+            Node::Prepared(Prepared::Intersects | Prepared::Disjoint) => 1,
+            Node::Prepared(_) => 2,
+            Node::Equals => 4,
+            Node::Relate(_) => 8,
+            Node::And(nodes) | Node::Or(nodes) => nodes.iter().map(Node::cost).sum(),
+            Node::Not(node) => node.cost(),
+        }
+    }
+}
+
+/// A [`Predicate`] compiled against a target geometry, as returned by [`Predicate::compile`].
+pub struct CompiledPredicate {
+    // Declared first so it's dropped before the geometry it was prepared from.
+    prepared: PreparedGeometry,
+    target: Geometry,
+    plan: Node,
+}
+
+impl CompiledPredicate {
+    /// Evaluates the predicate between the target and `other`.
+    pub fn evaluate<G: Geom>(&self, other: &G) -> GResult<bool> {
+        let mut matrix = None;
+        self.evaluate_node(&self.plan, other, &mut matrix)
+    }
+
+    /// Returns the indices of the `candidates` matching the predicate.
+    pub fn filter<I, T>(&self, candidates: I) -> GResult<Vec<usize>>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Geometry>,
+    {
+        let mut res = Vec::new();
+        for (i, candidate) in candidates.into_iter().enumerate() {
+            if self.evaluate(candidate.borrow())? {
+                res.push(i);
+            }
+        }
+        Ok(res)
+    }
+
+    fn evaluate_node<G: Geom>(
+        &self,
+        node: &Node,
+        other: &G,
+        matrix: &mut Option<IntersectionMatrix>,
+    ) -> GResult<bool> {
+        match node {
+            Node::Prepared(p) => {
+                let prepared = &self.prepared;
+                match p {
+                    Prepared::Contains => prepared.contains(other),
+                    Prepared::ContainsProperly => prepared.contains_properly(other),
+                    Prepared::CoveredBy => prepared.covered_by(other),
+                    Prepared::Covers => prepared.covers(other),
+                    Prepared::Crosses => prepared.crosses(other),
+                    Prepared::Disjoint => prepared.disjoint(other),
+                    Prepared::Intersects => prepared.intersects(other),
+                    Prepared::Overlaps => prepared.overlaps(other),
+                    Prepared::Touches => prepared.touches(other),
+                    Prepared::Within => prepared.within(other),
+                }
+            }
+            Node::Equals => self.target.equals(other),
+            Node::Relate(pattern) => {
+                let matrix = match matrix {
+                    Some(matrix) => *matrix,
+                    None => *matrix.insert(self.target.relate(other)?),
+                };
+                Ok(matrix.matches_pattern(pattern))
+            }
+            Node::And(nodes) => {
+                for node in nodes {
+                    if !self.evaluate_node(node, other, matrix)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Node::Or(nodes) => {
+                for node in nodes {
+                    if self.evaluate_node(node, other, matrix)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Node::Not(node) => Ok(!self.evaluate_node(node, other, matrix)?),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Open,
+    Close,
+}
+
+fn tokenize(s: &str) -> GResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' => {
+                chars.next();
+                tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            }
+            '\'' | '"' => {
+                chars.next();
+                let quoted: String = chars.by_ref().take_while(|&q| q != c).collect();
+                tokens.push(Token::Quoted(quoted));
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '*' => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '*') {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+            c => {
+                return Err(Error::GenericError(format!(
+                    "unexpected character {c:?} in predicate"
+                )))
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.tokens.get(self.position), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        self.position += 1;
+        self.tokens.get(self.position - 1)
+    }
+
+    fn expect(&mut self, token: Token) -> GResult<()> {
+        match self.next() {
+            Some(t) if *t == token => Ok(()),
+            t => Err(Error::GenericError(format!(
+                "expected {token:?} in predicate, got {t:?}"
+            ))),
+        }
+    }
+
+    fn expression(&mut self) -> GResult<Predicate> {
+        let mut res = self.term()?;
+        while self.peek_keyword("or") {
+            self.position += 1;
+            res = res.or(self.term()?);
+        }
+        Ok(res)
+    }
+
+    fn term(&mut self) -> GResult<Predicate> {
+        let mut res = self.factor()?;
+        while self.peek_keyword("and") {
+            self.position += 1;
+            res = res.and(self.factor()?);
+        }
+        Ok(res)
+    }
+
+    fn factor(&mut self) -> GResult<Predicate> {
+        let word = match self.next() {
+            Some(Token::Open) => {
+                let res = self.expression()?;
+                self.expect(Token::Close)?;
+                return Ok(res);
+            }
+            Some(Token::Word(word)) => word.to_ascii_lowercase(),
+            t => {
+                return Err(Error::GenericError(format!(
+                    "expected a predicate, got {t:?}"
+                )))
+            }
+        };
+        Ok(match word.as_str() {
+            "not" => !self.factor()?,
+            "contains" => Predicate::Contains,
+            "contains_properly" => Predicate::ContainsProperly,
+            "covered_by" => Predicate::CoveredBy,
+            "covers" => Predicate::Covers,
+            "crosses" => Predicate::Crosses,
+            "disjoint" => Predicate::Disjoint,
+            "equals" => Predicate::Equals,
+            "intersects" => Predicate::Intersects,
+            "overlaps" => Predicate::Overlaps,
+            "touches" => Predicate::Touches,
+            "within" => Predicate::Within,
+            "relate" => {
+                self.expect(Token::Open)?;
+                let pattern = match self.next() {
+                    Some(Token::Word(p)) | Some(Token::Quoted(p)) => p.clone(),
+                    t => {
+                        return Err(Error::GenericError(format!(
+                            "expected a DE-9IM pattern, got {t:?}"
+                        )))
+                    }
+                };
+                self.expect(Token::Close)?;
+                parse_pattern(&pattern)?;
+                Predicate::Relate(pattern)
+            }
+            _ => return Err(Error::GenericError(format!("unknown predicate {word:?}"))),
+        })
+    }
+}
+
+impl FromStr for Predicate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> GResult<Predicate> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            position: 0,
+        };
+        let res = parser.expression()?;
+        match parser.tokens.get(parser.position) {
+            None => Ok(res),
+            Some(t) => Err(Error::GenericError(format!(
+                "unexpected {t:?} in predicate"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Predicate;
+
+    #[test]
+    fn parse_predicate() {
+        let p: Predicate = "(Contains or relate('T*T******')) AND NOT equals"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            p,
+            Predicate::contains()
+                .or(Predicate::relate("T*T******"))
+                .and_not(Predicate::equals())
+        );
+        let p: Predicate = "within or crosses and touches".parse().unwrap();
+        assert_eq!(
+            p,
+            Predicate::within().or(Predicate::crosses().and(Predicate::touches()))
+        );
+        assert!("intersects and".parse::<Predicate>().is_err());
+        assert!("relate(T*T)".parse::<Predicate>().is_err());
+        assert!("(intersects".parse::<Predicate>().is_err());
+        assert!("nearby".parse::<Predicate>().is_err());
+    }
+
+    #[test]
+    fn requires_intersection() {
+        let requires = |s: &str| s.parse::<Predicate>().unwrap().requires_intersection();
+        assert!(requires("contains"));
+        assert!(requires("intersects and not touches"));
+        assert!(requires("relate('T********')"));
+        assert!(!requires("relate('FF*FF****')"));
+        assert!(!requires("disjoint"));
+        assert!(!requires("within or disjoint"));
+        assert!(!requires("not within"));
+    }
+}
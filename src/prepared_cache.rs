@@ -0,0 +1,105 @@
+use crate::{AsRaw, GResult, Geometry, PreparedGeometry};
+
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+
+/// Lazily prepares geometries and keeps the most recently used [`PreparedGeometry`]s.
+///
+/// Geometries are identified by their address: the cache borrows them for `'a`, so they can
+/// neither be dropped nor modified while it is alive. When more than `capacity` geometries
+/// are prepared, the least recently used one is dropped.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geometry, PreparedCache};
+///
+/// let polygons = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((20 0, 30 0, 30 10, 20 10, 20 0))").unwrap(),
+/// ];
+/// let point = Geometry::new_from_wkt("POINT(5 5)").unwrap();
+///
+/// let mut cache = PreparedCache::new(1);
+/// assert_eq!(cache.get_or_prepare(&polygons[0]).unwrap().contains(&point), Ok(true));
+/// // `polygons[0]` is prepared only once.
+/// assert_eq!(cache.get_or_prepare(&polygons[0]).unwrap().contains(&point), Ok(true));
+/// assert_eq!(cache.get_or_prepare(&polygons[1]).unwrap().contains(&point), Ok(false));
+/// // `polygons[0]` was evicted to make room for `polygons[1]`.
+/// assert_eq!(cache.len(), 1);
+/// ```
+pub struct PreparedCache<'a> {
+    capacity: usize,
+    // Prepared geometry and last use of each cached geometry, by address.
+    entries: HashMap<usize, (PreparedGeometry, u64)>,
+    // Address of each cached geometry, by last use.
+    uses: BTreeMap<u64, usize>,
+    clock: u64,
+    geometries: PhantomData<&'a Geometry>,
+}
+
+impl<'a> PreparedCache<'a> {
+    /// Creates a cache keeping at most `capacity` prepared geometries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> PreparedCache<'a> {
+        assert!(
+            capacity > 0,
+            "PreparedCache capacity must be greater than 0"
+        );
+        PreparedCache {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            uses: BTreeMap::new(),
+            clock: 0,
+            geometries: PhantomData,
+        }
+    }
+
+    /// Returns the prepared version of `geometry`, preparing it if it isn't in the cache yet.
+    pub fn get_or_prepare(&mut self, geometry: &'a Geometry) -> GResult<&PreparedGeometry> {
+        let key = geometry.as_raw() as usize;
+        self.clock += 1;
+        if let Some((_, last_use)) = self.entries.get_mut(&key) {
+            self.uses.remove(last_use);
+            *last_use = self.clock;
+        } else {
+            let prepared = PreparedGeometry::new(geometry)?;
+            if self.entries.len() >= self.capacity {
+                if let Some((&oldest, &evicted)) = self.uses.iter().next() {
+                    self.uses.remove(&oldest);
+                    self.entries.remove(&evicted);
+                }
+            }
+            self.entries.insert(key, (prepared, self.clock));
+        }
+        self.uses.insert(self.clock, key);
+        Ok(&self.entries[&key].0)
+    }
+
+    /// Returns `true` if `geometry` is prepared in the cache.
+    pub fn contains(&self, geometry: &Geometry) -> bool {
+        self.entries.contains_key(&(geometry.as_raw() as usize))
+    }
+
+    /// Returns the number of prepared geometries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Drops all the prepared geometries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.uses.clear();
+    }
+}
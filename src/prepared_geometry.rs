@@ -1,9 +1,10 @@
 use crate::context_handle::PtrWrap;
 use crate::error::{Error, PredicateType};
 use crate::functions::*;
-use crate::{AsRaw, ContextHandle, ContextHandling, ContextInteractions, GResult, Geom};
+use crate::{AsRaw, ContextHandle, ContextHandling, ContextInteractions, GResult, Geom, Geometry};
 use geos_sys::*;
 
+use std::borrow::Borrow;
 use std::sync::Arc;
 
 /// `PreparedGeometry` is an interface which prepares [`Geometry`](crate::Geometry) for greater performance
@@ -26,11 +27,18 @@ use std::sync::Arc;
 pub struct PreparedGeometry {
     ptr: PtrWrap<*const GEOSPreparedGeometry>,
     context: Arc<ContextHandle>,
+    // The geometry `ptr` was prepared from, when created with `from_geometry`. It is dropped
+    // after `ptr` is destroyed (in `Drop::drop`).
+    source: Option<Geometry>,
 }
 
 impl PreparedGeometry {
     /// Creates a new `PreparedGeometry` from a [`Geometry`](crate::Geometry).
     ///
+    /// The prepared geometry refers to `g` without copying it: `g` must not be dropped (nor
+    /// modified) while the `PreparedGeometry` is in use. Use [`PreparedGeometry::from_geometry`]
+    /// to have it kept alive by the `PreparedGeometry`.
+    ///
     /// # Example
     ///
     /// ```
@@ -41,12 +49,43 @@ impl PreparedGeometry {
     /// let prepared_geom = PreparedGeometry::new(&geom1);
     /// ```
     pub fn new<G: Geom>(g: &G) -> GResult<PreparedGeometry> {
+        trace_span!("prepare", g);
         unsafe {
             let ptr = GEOSPrepare_r(g.get_raw_context(), g.as_raw());
             PreparedGeometry::new_from_raw(ptr, g.clone_context(), "new")
         }
     }
 
+    /// Creates a new `PreparedGeometry` owning `geom`, so it can't be dropped before the
+    /// `PreparedGeometry`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, PreparedGeometry};
+    ///
+    /// fn prepare(wkt: &str) -> PreparedGeometry {
+    ///     let geom = Geometry::new_from_wkt(wkt).expect("Invalid geometry");
+    ///     PreparedGeometry::from_geometry(geom).expect("from_geometry failed")
+    /// }
+    ///
+    /// let prepared_geom = prepare("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))");
+    /// let point = Geometry::new_from_wkt("POINT (2.5 2.5)").expect("Invalid geometry");
+    /// assert_eq!(prepared_geom.contains(&point), Ok(true));
+    /// assert_eq!(prepared_geom.geometry().map(|g| g.area()), Some(Ok(60.)));
+    /// ```
+    pub fn from_geometry(geom: Geometry) -> GResult<PreparedGeometry> {
+        let mut prepared = PreparedGeometry::new(&geom)?;
+        prepared.source = Some(geom);
+        Ok(prepared)
+    }
+
+    /// Returns the geometry owned by `self` if it was created with
+    /// [`PreparedGeometry::from_geometry`], `None` otherwise.
+    pub fn geometry(&self) -> Option<&Geometry> {
+        self.source.as_ref()
+    }
+
     pub(crate) unsafe fn new_from_raw(
         ptr: *const GEOSPreparedGeometry,
         context: Arc<ContextHandle>,
@@ -65,6 +104,7 @@ impl PreparedGeometry {
         Ok(PreparedGeometry {
             ptr: PtrWrap(ptr),
             context,
+            source: None,
         })
     }
 
@@ -346,8 +386,199 @@ impl PreparedGeometry {
             unsafe { GEOSPreparedWithin_r(self.get_raw_context(), self.as_raw(), other.as_raw()) };
         check_geos_predicate(ret_val as _, PredicateType::PreparedWithin)
     }
+
+    /// Returns the distance between `self` and `other`, using the index of the prepared
+    /// geometry.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))")
+    ///                     .expect("Invalid geometry");
+    /// let prepared_geom = geom.to_prepared_geom().expect("to_prepared_geom failed");
+    /// let point = Geometry::new_from_wkt("POINT (13 10)").expect("Invalid geometry");
+    ///
+    /// assert_eq!(prepared_geom.distance(&point), Ok(5.));
+    /// ```
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    pub fn distance<G: Geom>(&self, other: &G) -> GResult<f64> {
+        let mut distance = 0.;
+        let ret = unsafe {
+            GEOSPreparedDistance_r(
+                self.get_raw_context(),
+                self.as_raw(),
+                other.as_raw(),
+                &mut distance,
+            )
+        };
+        check_ret(ret, PredicateType::PreparedDistance).map(|_| distance)
+    }
+
+    /// Returns `true` if the distance between `self` and `other` is less than or equal to
+    /// `distance`, using the index of the prepared geometry. It is faster than computing the
+    /// distance, since the computation stops as soon as a close enough point is found.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))")
+    ///                     .expect("Invalid geometry");
+    /// let prepared_geom = geom.to_prepared_geom().expect("to_prepared_geom failed");
+    /// let point = Geometry::new_from_wkt("POINT (13 10)").expect("Invalid geometry");
+    ///
+    /// assert_eq!(prepared_geom.is_within_distance(&point, 5.), Ok(true));
+    /// assert_eq!(prepared_geom.is_within_distance(&point, 4.), Ok(false));
+    /// ```
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    pub fn is_within_distance<G: Geom>(&self, other: &G, distance: f64) -> GResult<bool> {
+        let ret_val = unsafe {
+            GEOSPreparedDistanceWithin_r(
+                self.get_raw_context(),
+                self.as_raw(),
+                other.as_raw(),
+                distance,
+            )
+        };
+        check_geos_predicate(ret_val as _, PredicateType::PreparedDistanceWithin)
+    }
+
+    /// Returns `true` if the point (`x`, `y`) is contained by `self`, without having to create a
+    /// point geometry.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))")
+    ///                     .expect("Invalid geometry");
+    /// let prepared_geom = geom.to_prepared_geom().expect("to_prepared_geom failed");
+    ///
+    /// assert_eq!(prepared_geom.contains_xy(2.5, 2.5), Ok(true));
+    /// assert_eq!(prepared_geom.contains_xy(0., 2.5), Ok(false));
+    /// assert_eq!(prepared_geom.contains_xy(12., 2.5), Ok(false));
+    /// ```
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    pub fn contains_xy(&self, x: f64, y: f64) -> GResult<bool> {
+        let ret_val =
+            unsafe { GEOSPreparedContainsXY_r(self.get_raw_context(), self.as_raw(), x, y) };
+        check_geos_predicate(ret_val as _, PredicateType::PreparedContainsXY)
+    }
+
+    /// Returns `true` if the point (`x`, `y`) intersects `self`, without having to create a
+    /// point geometry. Unlike [`PreparedGeometry::contains_xy`], points on the boundary are
+    /// considered inside.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))")
+    ///                     .expect("Invalid geometry");
+    /// let prepared_geom = geom.to_prepared_geom().expect("to_prepared_geom failed");
+    ///
+    /// assert_eq!(prepared_geom.intersects_xy(2.5, 2.5), Ok(true));
+    /// assert_eq!(prepared_geom.intersects_xy(0., 2.5), Ok(true));
+    /// assert_eq!(prepared_geom.intersects_xy(12., 2.5), Ok(false));
+    /// ```
+    #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+    pub fn intersects_xy(&self, x: f64, y: f64) -> GResult<bool> {
+        let ret_val =
+            unsafe { GEOSPreparedIntersectsXY_r(self.get_raw_context(), self.as_raw(), x, y) };
+        check_geos_predicate(ret_val as _, PredicateType::PreparedIntersectsXY)
+    }
 }
 
+// Under this number of geometries, batch evaluations aren't worth being parallelized.
+#[cfg(feature = "rayon")]
+pub(crate) const PARALLEL_THRESHOLD: usize = 64;
+
+impl PreparedGeometry {
+    /// Evaluates `predicate` between `self` and each geometry of `others`.
+    ///
+    /// With the `rayon` feature, if `self` owns its geometry (see
+    /// [`PreparedGeometry::from_geometry`]), `others` is split across the rayon threads, each
+    /// thread using its own context and its own prepared copy of the geometry (GEOS prepared
+    /// geometries aren't thread-safe).
+    fn evaluate_many<T, F>(&self, others: &[T], predicate: F) -> GResult<Vec<bool>>
+    where
+        T: Borrow<Geometry> + Sync,
+        F: Fn(&PreparedGeometry, &Geometry) -> GResult<bool> + Sync,
+    {
+        #[cfg(feature = "rayon")]
+        if let Some(source) = &self.source {
+            if others.len() >= PARALLEL_THRESHOLD {
+                use rayon::prelude::*;
+
+                let nb_threads = rayon::current_num_threads().max(1);
+                let chunk_size = (others.len() + nb_threads - 1) / nb_threads;
+                let chunks = others
+                    .par_chunks(chunk_size)
+                    .map(|chunk| -> GResult<Vec<bool>> {
+                        let context =
+                            ContextHandle::init_e(Some("PreparedGeometry::evaluate_many"))?;
+                        let geom = unsafe {
+                            let ptr = GEOSGeom_clone_r(context.as_raw(), source.as_raw());
+                            Geometry::new_from_raw(ptr, Arc::new(context), "evaluate_many")?
+                        };
+                        let prepared = PreparedGeometry::from_geometry(geom)?;
+                        chunk
+                            .iter()
+                            .map(|g| predicate(&prepared, g.borrow()))
+                            .collect()
+                    })
+                    .collect::<GResult<Vec<_>>>()?;
+                return Ok(chunks.into_iter().flatten().collect());
+            }
+        }
+        others.iter().map(|g| predicate(self, g.borrow())).collect()
+    }
+}
+
+macro_rules! impl_many {
+    ($($name:ident => $predicate:ident,)+) => {
+        impl PreparedGeometry {
+            $(
+                #[doc = concat!(
+                    "Returns the result of [`PreparedGeometry::", stringify!($predicate),
+                    "`] for each geometry of `others`.\n\n",
+                    "With the `rayon` feature, geometries are processed in parallel if `self` ",
+                    "was created with [`PreparedGeometry::from_geometry`].",
+                )]
+                pub fn $name<T: Borrow<Geometry> + Sync>(&self, others: &[T]) -> GResult<Vec<bool>> {
+                    self.evaluate_many(others, |p, g| p.$predicate(g))
+                }
+            )+
+        }
+    };
+}
+
+impl_many!(
+    contains_many => contains,
+    contains_properly_many => contains_properly,
+    covered_by_many => covered_by,
+    covers_many => covers,
+    crosses_many => crosses,
+    disjoint_many => disjoint,
+    intersects_many => intersects,
+    overlaps_many => overlaps,
+    touches_many => touches,
+    within_many => within,
+);
+
 unsafe impl Send for PreparedGeometry {}
 unsafe impl Sync for PreparedGeometry {}
 
@@ -0,0 +1,120 @@
+//! Conversion of vector geometries to raster grids.
+use crate::error::{Error, GResult};
+use crate::functions::{bounds, rectangle};
+use crate::{Geom, GeometryTypes, PreparedGeometry};
+
+/// A north-up raster grid: row `0` is the top one, column `0` the left one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridSpec {
+    /// X coordinate of the left side of the grid.
+    pub xmin: f64,
+    /// Y coordinate of the top side of the grid.
+    pub ymax: f64,
+    pub cell_width: f64,
+    pub cell_height: f64,
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl GridSpec {
+    /// Returns the `(xmin, ymin, xmax, ymax)` bounds of the cell at `(row, column)`.
+    pub fn cell_bounds(&self, row: usize, column: usize) -> (f64, f64, f64, f64) {
+        let xmin = self.xmin + column as f64 * self.cell_width;
+        let ymax = self.ymax - row as f64 * self.cell_height;
+        (xmin, ymax - self.cell_height, xmin + self.cell_width, ymax)
+    }
+
+    /// Returns the range of indices of `count` cells of the given `size` starting at `origin`
+    /// (growing in the direction of `size`) overlapping `[low, high]`.
+    fn range(origin: f64, size: f64, count: usize, low: f64, high: f64) -> (usize, usize) {
+        let clamp = |v: f64| v.max(0.).min(count as f64) as usize;
+        (
+            clamp(((low - origin) / size).floor()),
+            clamp(((high - origin) / size).ceil()),
+        )
+    }
+}
+
+/// Returns, for each cell of `grid` (in row-major order), the fraction of the cell covered by
+/// `polygon`, a `Polygon` or a `MultiPolygon`.
+///
+/// Fractions are computed from the exact intersection areas. Only the cells overlapping the
+/// envelope of `polygon` are considered, and the cells fully inside it (checked with a prepared
+/// geometry) don't need any intersection to be computed.
+///
+/// # Example
+///
+/// ```
+/// use geos::rasterize::{coverage_fractions, GridSpec};
+/// use geos::Geometry;
+///
+/// let polygon = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 1.5, 0 1.5, 0 0))").unwrap();
+/// let grid = GridSpec {
+///     xmin: 0.,
+///     ymax: 2.,
+///     cell_width: 1.,
+///     cell_height: 1.,
+///     columns: 3,
+///     rows: 2,
+/// };
+///
+/// let fractions = coverage_fractions(&polygon, &grid).expect("coverage_fractions failed");
+/// assert_eq!(fractions, vec![0.5, 0.5, 0., 1., 1., 0.]);
+/// ```
+pub fn coverage_fractions<G: Geom>(polygon: &G, grid: &GridSpec) -> GResult<Vec<f32>> {
+    match polygon.geometry_type() {
+        GeometryTypes::Polygon | GeometryTypes::MultiPolygon => {}
+        t => {
+            return Err(Error::GenericError(format!(
+                "Geometry must be a Polygon or a MultiPolygon, got a {t:?}"
+            )))
+        }
+    }
+    if !(grid.cell_width > 0. && grid.cell_height > 0.) {
+        return Err(Error::GenericError(
+            "grid cells must have a positive size".to_owned(),
+        ));
+    }
+    let mut fractions = vec![0.; grid.rows * grid.columns];
+    let (xmin, ymin, xmax, ymax) = match bounds(polygon)? {
+        Some(bounds) => bounds,
+        None => return Ok(fractions),
+    };
+    let prepared = PreparedGeometry::new(polygon)?;
+    let cell_area = grid.cell_width * grid.cell_height;
+    let (first_row, last_row) =
+        GridSpec::range(-grid.ymax, grid.cell_height, grid.rows, -ymax, -ymin);
+    for row in first_row..last_row {
+        // The polygon is first clipped to the row, so each cell is intersected with a (much)
+        // smaller geometry.
+        let (row_xmin, row_ymin, _, row_ymax) = grid.cell_bounds(row, 0);
+        let strip = polygon.clip_by_rect(
+            row_xmin,
+            row_ymin,
+            row_xmin + grid.columns as f64 * grid.cell_width,
+            row_ymax,
+        )?;
+        let (strip_xmin, _, strip_xmax, _) = match bounds(&strip)? {
+            Some(bounds) => bounds,
+            None => continue,
+        };
+        let (first_column, last_column) = GridSpec::range(
+            grid.xmin,
+            grid.cell_width,
+            grid.columns,
+            strip_xmin.max(xmin),
+            strip_xmax.min(xmax),
+        );
+        for column in first_column..last_column {
+            let (cxmin, cymin, cxmax, cymax) = grid.cell_bounds(row, column);
+            let cell = rectangle(polygon.clone_context(), cxmin, cymin, cxmax, cymax)?;
+            fractions[row * grid.columns + column] = if prepared.contains(&cell)? {
+                1.
+            } else {
+                let area = strip.clip_by_rect(cxmin, cymin, cxmax, cymax)?.area()?;
+                (area / cell_area).min(1.) as f32
+            };
+        }
+    }
+    Ok(fractions)
+}
@@ -0,0 +1,378 @@
+use crate::error::{Error, GResult};
+use std::fmt;
+
+/// Kind of limit of a [`ReaderLimits`], reported by [`Error::LimitExceeded`].
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum LimitKind {
+    Bytes,
+    Coordinates,
+    Depth,
+}
+
+impl fmt::Display for LimitKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LimitKind::Bytes => write!(f, "byte length"),
+            LimitKind::Coordinates => write!(f, "coordinate count"),
+            LimitKind::Depth => write!(f, "nesting depth"),
+        }
+    }
+}
+
+/// Limits checked before handing untrusted WKT, WKB or GeoJSON input to GEOS (see
+/// [`Geometry::new_from_wkt_with_limits`](crate::Geometry::new_from_wkt_with_limits),
+/// [`Geometry::new_from_wkb_with_limits`](crate::Geometry::new_from_wkb_with_limits) and
+/// `from_geojson::parse_with_limits`).
+///
+/// `None` means no limit, which is the default.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Error, Geometry, LimitKind, ReaderLimits};
+///
+/// let limits = ReaderLimits {
+///     max_coordinates: Some(2),
+///     ..Default::default()
+/// };
+/// assert!(Geometry::new_from_wkt_with_limits("LINESTRING(0 0, 1 1)", &limits).is_ok());
+/// assert_eq!(
+///     Geometry::new_from_wkt_with_limits("LINESTRING(0 0, 1 1, 2 2)", &limits).err(),
+///     Some(Error::LimitExceeded(LimitKind::Coordinates, 2)),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReaderLimits {
+    /// Maximum length of the input, in bytes.
+    pub max_bytes: Option<usize>,
+    /// Maximum number of coordinates of the geometry.
+    pub max_coordinates: Option<usize>,
+    /// Maximum nesting depth of the geometry (1 for a point or a polygon, 2 for a multi
+    /// polygon, etc).
+    pub max_depth: Option<usize>,
+}
+
+fn check(kind: LimitKind, value: usize, max: Option<usize>) -> GResult<()> {
+    match max {
+        Some(max) if value > max => Err(Error::LimitExceeded(kind, max)),
+        _ => Ok(()),
+    }
+}
+
+impl ReaderLimits {
+    pub(crate) fn check_bytes(&self, len: usize) -> GResult<()> {
+        check(LimitKind::Bytes, len, self.max_bytes)
+    }
+
+    /// Checks the limits on a WKT string, without parsing it.
+    pub(crate) fn check_wkt(&self, wkt: &str) -> GResult<()> {
+        self.check_bytes(wkt.len())?;
+        let mut reader = WktScanner::default();
+        for b in wkt.bytes() {
+            reader.push(b);
+        }
+        reader.end_word();
+        check(
+            LimitKind::Coordinates,
+            reader.coordinates,
+            self.max_coordinates,
+        )?;
+        check(LimitKind::Depth, reader.max_depth, self.max_depth)
+    }
+
+    /// Checks the limits on a WKB buffer (ISO and extended WKB are supported), without parsing
+    /// it.
+    pub(crate) fn check_wkb(&self, wkb: &[u8]) -> GResult<()> {
+        self.check_bytes(wkb.len())?;
+        let mut reader = WkbReader {
+            wkb,
+            pos: 0,
+            coordinates: 0,
+            limits: self,
+        };
+        reader.geometry(1)
+    }
+
+    /// Checks the limits on a parsed GeoJSON geometry.
+    #[cfg(feature = "json")]
+    pub(crate) fn check_geojson(&self, geometry: &geojson::Geometry) -> GResult<()> {
+        let mut coordinates = 0;
+        self.check_geojson_value(&geometry.value, 1, &mut coordinates)
+    }
+
+    #[cfg(feature = "json")]
+    fn check_geojson_value(
+        &self,
+        value: &geojson::Value,
+        depth: usize,
+        coordinates: &mut usize,
+    ) -> GResult<()> {
+        use geojson::Value;
+
+        check(LimitKind::Depth, depth, self.max_depth)?;
+        let (count, is_multi) = match value {
+            Value::Point(_) => (1, false),
+            Value::LineString(line) => (line.len(), false),
+            Value::Polygon(rings) => (rings.iter().map(Vec::len).sum(), false),
+            Value::MultiPoint(points) => (points.len(), true),
+            Value::MultiLineString(lines) => (lines.iter().map(Vec::len).sum(), true),
+            Value::MultiPolygon(polygons) => (
+                polygons
+                    .iter()
+                    .flat_map(|rings| rings.iter().map(Vec::len))
+                    .sum(),
+                true,
+            ),
+            Value::GeometryCollection(geometries) => {
+                for geometry in geometries {
+                    self.check_geojson_value(&geometry.value, depth + 1, coordinates)?;
+                }
+                (0, false)
+            }
+        };
+        if is_multi {
+            check(LimitKind::Depth, depth + 1, self.max_depth)?;
+        }
+        *coordinates += count;
+        check(LimitKind::Coordinates, *coordinates, self.max_coordinates)
+    }
+}
+
+/// Counts the coordinates and the nesting depth of a WKT string.
+#[derive(Default)]
+struct WktScanner {
+    coordinates: usize,
+    max_depth: usize,
+    parenthesis: usize,
+    /// Parenthesis level at which each opened geometry collection starts.
+    collections: Vec<usize>,
+    word: Vec<u8>,
+    last_word_is_collection: bool,
+    expecting_coordinate: bool,
+    in_number: bool,
+}
+
+impl WktScanner {
+    fn end_word(&mut self) {
+        if self.word.is_empty() {
+            return;
+        }
+        let word = std::mem::take(&mut self.word);
+        let is_collection = word == b"GEOMETRYCOLLECTION";
+        if word == b"EMPTY" && self.last_word_is_collection {
+            self.collections.pop();
+        } else if self.expecting_coordinate && (word == b"NAN" || word == b"INF") {
+            self.coordinates += 1;
+            self.expecting_coordinate = false;
+        } else if is_collection
+            || word.starts_with(b"MULTI")
+            || [&b"POINT"[..], b"LINESTRING", b"LINEARRING", b"POLYGON"].contains(&&word[..])
+        {
+            let depth = self.collections.len() + if word.starts_with(b"MULTI") { 2 } else { 1 };
+            self.max_depth = self.max_depth.max(depth);
+            if is_collection {
+                self.collections.push(self.parenthesis);
+            }
+        }
+        self.last_word_is_collection = is_collection;
+    }
+
+    fn push(&mut self, b: u8) {
+        if b.is_ascii_alphabetic() && !self.in_number {
+            self.word.push(b.to_ascii_uppercase());
+            return;
+        }
+        self.end_word();
+        self.in_number = matches!(b, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E');
+        match b {
+            b'(' => {
+                self.parenthesis += 1;
+                self.expecting_coordinate = true;
+            }
+            b')' => {
+                self.parenthesis = self.parenthesis.saturating_sub(1);
+                self.expecting_coordinate = false;
+                if self.collections.last() == Some(&self.parenthesis) {
+                    self.collections.pop();
+                }
+            }
+            b',' => self.expecting_coordinate = true,
+            b'0'..=b'9' | b'-' | b'+' | b'.' if self.expecting_coordinate => {
+                self.coordinates += 1;
+                self.expecting_coordinate = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+struct WkbReader<'a> {
+    wkb: &'a [u8],
+    pos: usize,
+    coordinates: usize,
+    limits: &'a ReaderLimits,
+}
+
+impl<'a> WkbReader<'a> {
+    fn truncated() -> Error {
+        Error::InvalidGeometry("truncated WKB".to_owned())
+    }
+
+    fn bytes(&mut self, len: usize) -> GResult<&'a [u8]> {
+        let end = self.pos.checked_add(len).ok_or_else(Self::truncated)?;
+        let bytes = self.wkb.get(self.pos..end).ok_or_else(Self::truncated)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self, little_endian: bool) -> GResult<u32> {
+        let mut buf = [0; 4];
+        buf.copy_from_slice(self.bytes(4)?);
+        Ok(if little_endian {
+            u32::from_le_bytes(buf)
+        } else {
+            u32::from_be_bytes(buf)
+        })
+    }
+
+    fn points(&mut self, count: usize, dims: usize) -> GResult<()> {
+        self.coordinates = self.coordinates.saturating_add(count);
+        check(
+            LimitKind::Coordinates,
+            self.coordinates,
+            self.limits.max_coordinates,
+        )?;
+        self.bytes(count.saturating_mul(dims * 8))?;
+        Ok(())
+    }
+
+    fn geometry(&mut self, depth: usize) -> GResult<()> {
+        check(LimitKind::Depth, depth, self.limits.max_depth)?;
+        let little_endian = match self.bytes(1)?[0] {
+            0 => false,
+            1 => true,
+            b => {
+                return Err(Error::InvalidGeometry(format!(
+                    "invalid WKB byte order: {b}"
+                )))
+            }
+        };
+        let raw_type = self.u32(little_endian)?;
+        // Extended WKB flags.
+        let mut dims = 2;
+        if raw_type & 0x8000_0000 != 0 {
+            dims += 1;
+        }
+        if raw_type & 0x4000_0000 != 0 {
+            dims += 1;
+        }
+        if raw_type & 0x2000_0000 != 0 {
+            // SRID
+            self.bytes(4)?;
+        }
+        let iso_type = raw_type & 0x0FFF_FFFF;
+        dims += match iso_type / 1000 {
+            1 | 2 => 1,
+            3 => 2,
+            _ => 0,
+        };
+        match iso_type % 1000 {
+            1 => self.points(1, dims),
+            2 => {
+                let count = self.u32(little_endian)? as usize;
+                self.points(count, dims)
+            }
+            3 => {
+                for _ in 0..self.u32(little_endian)? {
+                    let count = self.u32(little_endian)? as usize;
+                    self.points(count, dims)?;
+                }
+                Ok(())
+            }
+            4..=7 => {
+                for _ in 0..self.u32(little_endian)? {
+                    self.geometry(depth + 1)?;
+                }
+                Ok(())
+            }
+            t => Err(Error::InvalidGeometry(format!(
+                "unknown WKB geometry type: {t}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LimitKind, ReaderLimits};
+    use crate::Error;
+
+    #[test]
+    fn test_wkt_limits() {
+        let limits = ReaderLimits {
+            max_depth: Some(2),
+            ..Default::default()
+        };
+        assert!(limits.check_wkt("POLYGON((0 0, 1 0, 1 1, 0 0))").is_ok());
+        assert!(limits.check_wkt("MULTIPOINT((0 0), (1 1))").is_ok());
+        assert!(limits
+            .check_wkt("GEOMETRYCOLLECTION(GEOMETRYCOLLECTION EMPTY, POINT(0 0))")
+            .is_ok());
+        assert_eq!(
+            limits.check_wkt("GEOMETRYCOLLECTION(MULTIPOINT(0 0))"),
+            Err(Error::LimitExceeded(LimitKind::Depth, 2)),
+        );
+
+        let limits = ReaderLimits {
+            max_coordinates: Some(4),
+            max_bytes: Some(40),
+            ..Default::default()
+        };
+        assert!(limits
+            .check_wkt("POLYGON((0 0, 1e-3 0, 1 1, -0 0))")
+            .is_ok());
+        assert_eq!(
+            limits.check_wkt("MULTIPOINT(0 0, 1 1, 2 2, 3 3, 4 4)"),
+            Err(Error::LimitExceeded(LimitKind::Coordinates, 4)),
+        );
+        assert_eq!(
+            limits.check_wkt("MULTIPOINT(0 0, 1 1, 2 2, NaN NaN, 4 4)"),
+            Err(Error::LimitExceeded(LimitKind::Coordinates, 4)),
+        );
+        assert_eq!(
+            limits.check_wkt(&format!("POINT({} 0)", "1".repeat(40))),
+            Err(Error::LimitExceeded(LimitKind::Bytes, 40)),
+        );
+    }
+
+    #[test]
+    fn test_wkb_limits() {
+        // MULTIPOINT((1 2), (3 4)) in little endian.
+        let mut wkb = vec![1, 4, 0, 0, 0, 2, 0, 0, 0];
+        for (x, y) in [(1f64, 2f64), (3., 4.)] {
+            wkb.extend([1, 1, 0, 0, 0]);
+            wkb.extend(x.to_le_bytes());
+            wkb.extend(y.to_le_bytes());
+        }
+        assert!(ReaderLimits::default().check_wkb(&wkb).is_ok());
+        let limits = ReaderLimits {
+            max_coordinates: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            limits.check_wkb(&wkb),
+            Err(Error::LimitExceeded(LimitKind::Coordinates, 1)),
+        );
+        let limits = ReaderLimits {
+            max_depth: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            limits.check_wkb(&wkb),
+            Err(Error::LimitExceeded(LimitKind::Depth, 1)),
+        );
+        assert!(ReaderLimits::default()
+            .check_wkb(&wkb[..wkb.len() - 1])
+            .is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::error::GResult;
+use crate::functions::is_valid_detail;
+#[cfg(any(feature = "v3_6_0", feature = "dox"))]
+use crate::Precision;
+use crate::{Geom, Geometry};
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+use crate::{MakeValidMethod, MakeValidParams};
+
+/// Strategy used by [`Geom::repair`] to fix an invalid geometry.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RepairStrategy {
+    /// Uses the "structure" algorithm of `GEOSMakeValid`, which keeps the polygons structure
+    /// (exterior ring and holes) and drops the collapsed parts.
+    ///
+    /// Available using the `v3_10_0` feature.
+    #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+    MakeValidStructure,
+    /// Uses the "linework" algorithm of `GEOSMakeValid`, which builds polygons from the noded
+    /// linework of the input and keeps collapsed parts as lines or points.
+    ///
+    /// Available using the `v3_8_0` feature.
+    #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    MakeValidLinework,
+    /// Buffers the geometry by 0, which fixes most self-intersecting polygons but may drop
+    /// parts of "bow-tie" polygons.
+    BufferZero,
+    /// Snap rounds the geometry to a grid of the given size, which always gives a valid
+    /// result.
+    ///
+    /// Available using the `v3_6_0` feature.
+    #[cfg(any(feature = "v3_6_0", feature = "dox"))]
+    PrecisionReduce(f64),
+    /// Applies each strategy in order, stopping as soon as the geometry is valid.
+    Chain(Vec<RepairStrategy>),
+}
+
+impl Default for RepairStrategy {
+    /// Tries [`RepairStrategy::MakeValidLinework`] (when the `v3_8_0` feature is enabled), then
+    /// falls back to [`RepairStrategy::BufferZero`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, RepairStrategy};
+    ///
+    /// let bow_tie = Geometry::new_from_wkt("POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))").unwrap();
+    /// let (repaired, report) = bow_tie.repair(RepairStrategy::default()).expect("repair failed");
+    ///
+    /// assert!(repaired.is_valid());
+    /// assert_eq!(report.invalid_reason.as_deref(), Some("Self-intersection"));
+    /// assert_eq!(report.applied.len(), 1);
+    /// // GEOS gives an area of 0 to the bow-tie: make valid turns it into two triangles of area
+    /// // 1, while a buffer of 0 only keeps one of them.
+    /// #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+    /// assert_eq!(report.area_delta, 2.);
+    /// #[cfg(not(any(feature = "v3_8_0", feature = "dox")))]
+    /// assert_eq!(report.area_delta, 1.);
+    /// assert!(report.area_changed(0.5));
+    /// ```
+    fn default() -> RepairStrategy {
+        RepairStrategy::Chain(vec![
+            #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+            RepairStrategy::MakeValidLinework,
+            RepairStrategy::BufferZero,
+        ])
+    }
+}
+
+/// What changed when repairing a geometry with [`Geom::repair`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepairReport {
+    /// Whether the input geometry was valid (in which case it is returned untouched).
+    pub was_valid: bool,
+    /// Why the input geometry was invalid (see [`Geom::is_valid_detail`]).
+    pub invalid_reason: Option<String>,
+    /// Whether the repaired geometry is valid.
+    pub is_valid: bool,
+    /// Area of the repaired geometry minus the area of the input geometry.
+    pub area_delta: f64,
+    /// Number of parts (see [`Geom::get_num_geometries`]) of the input geometry missing from
+    /// the repaired geometry.
+    pub parts_dropped: usize,
+    /// Strategies which were applied, in order ([`RepairStrategy::Chain`] being replaced by
+    /// the strategies of the chain which were applied).
+    pub applied: Vec<RepairStrategy>,
+}
+
+impl RepairReport {
+    /// Returns `true` if the area changed by more than `tolerance` during the repair.
+    pub fn area_changed(&self, tolerance: f64) -> bool {
+        self.area_delta.abs() > tolerance
+    }
+}
+
+fn num_parts<G: Geom>(g: &G) -> GResult<usize> {
+    if g.is_empty()? {
+        Ok(0)
+    } else {
+        g.get_num_geometries()
+    }
+}
+
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+fn make_valid_structure<G: Geom>(g: &G) -> GResult<Geometry> {
+    let params = MakeValidParams::builder()
+        .method(MakeValidMethod::Structure)
+        .build()?;
+    g.make_valid_with_params(&params)
+}
+
+#[cfg(any(feature = "v3_6_0", feature = "dox"))]
+fn precision_reduce<G: Geom>(g: &G, grid_size: f64) -> GResult<Geometry> {
+    g.set_precision(grid_size, Precision::ValidOutput)
+}
+
+/// Applies `strategy` to `g`, unless `g` is already valid.
+fn apply(
+    g: Geometry,
+    strategy: &RepairStrategy,
+    applied: &mut Vec<RepairStrategy>,
+) -> GResult<Geometry> {
+    if g.is_valid() {
+        return Ok(g);
+    }
+    let res = match strategy {
+        #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+        RepairStrategy::MakeValidStructure => make_valid_structure(&g)?,
+        #[cfg(any(feature = "v3_8_0", feature = "dox"))]
+        RepairStrategy::MakeValidLinework => g.make_valid()?,
+        RepairStrategy::BufferZero => g.buffer(0., 8)?,
+        #[cfg(any(feature = "v3_6_0", feature = "dox"))]
+        RepairStrategy::PrecisionReduce(grid_size) => precision_reduce(&g, *grid_size)?,
+        RepairStrategy::Chain(strategies) => {
+            let mut g = g;
+            for strategy in strategies {
+                g = apply(g, strategy, applied)?;
+            }
+            return Ok(g);
+        }
+    };
+    applied.push(strategy.clone());
+    Ok(res)
+}
+
+pub(crate) fn repair<G: Geom>(
+    g: &G,
+    strategy: &RepairStrategy,
+) -> GResult<(Geometry, RepairReport)> {
+    let invalid_reason = is_valid_detail(g, 0)?.map(|(reason, _)| reason);
+    let (area, parts) = (g.area()?, num_parts(g)?);
+    let mut applied = Vec::new();
+    let res = apply(Geom::clone(g), strategy, &mut applied)?;
+    let report = RepairReport {
+        was_valid: invalid_reason.is_none(),
+        invalid_reason,
+        is_valid: res.is_valid(),
+        area_delta: res.area()? - area,
+        parts_dropped: parts.saturating_sub(num_parts(&res)?),
+        applied,
+    };
+    Ok((res, report))
+}
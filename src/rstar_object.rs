@@ -0,0 +1,57 @@
+//! Allows storing geometries in an [`rstar::RTree`].
+use crate::functions::{bounds, create_point_with_context};
+use crate::{ContextHandling, Geom, Geometry};
+use rstar::{Envelope, PointDistance, RTreeObject, AABB};
+
+impl RTreeObject for Geometry {
+    type Envelope = AABB<[f64; 2]>;
+
+    /// Returns the bounds of the geometry, an empty envelope if the geometry is empty.
+    fn envelope(&self) -> Self::Envelope {
+        match bounds(self) {
+            Ok(Some((xmin, ymin, xmax, ymax))) => AABB::from_corners([xmin, ymin], [xmax, ymax]),
+            _ => AABB::new_empty(),
+        }
+    }
+}
+
+impl PointDistance for Geometry {
+    /// Returns the squared distance between the geometry and `point`, infinity if it can't be
+    /// computed.
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        create_point_with_context(self.clone_context(), point[0], point[1])
+            .and_then(|p| self.distance(&p))
+            .map_or(f64::INFINITY, |d| d * d)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Geom, Geometry};
+    use rstar::{RTree, AABB};
+
+    #[test]
+    fn test_rtree() {
+        let geometries = [
+            "POINT(0 0)",
+            "LINESTRING(5 0, 5 10)",
+            "POLYGON((8 8, 9 8, 9 9, 8 8))",
+        ]
+        .iter()
+        .map(|wkt| Geometry::new_from_wkt(wkt).unwrap())
+        .collect();
+        let tree = RTree::bulk_load(geometries);
+
+        let nearest = tree.nearest_neighbor(&[4., 4.]).unwrap();
+        assert_eq!(
+            nearest.to_wkt_precision(0).unwrap(),
+            "LINESTRING (5 0, 5 10)"
+        );
+
+        let found: Vec<_> = tree
+            .locate_in_envelope_intersecting(&AABB::from_corners([7., 7.], [10., 10.]))
+            .map(|g| g.to_wkt_precision(0).unwrap())
+            .collect();
+        assert_eq!(found, vec!["POLYGON ((8 8, 9 8, 9 9, 8 8))"]);
+    }
+}
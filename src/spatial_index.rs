@@ -1,34 +1,105 @@
 use std::ffi::c_void;
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 use geos_sys::*;
 
 use crate::context_handle::PtrWrap;
 use crate::ContextHandling;
-use crate::{AsRaw, AsRawMut, GResult};
-use crate::{ContextHandle, Geom};
+use crate::{AsRaw, AsRawMut, Error, GResult};
+use crate::{ContextHandle, Geom, Geometry};
 
+/// Spatial index of items, each of them being associated with a geometry.
+///
+/// It is implemented by [`STRtree`], and can be implemented by other indexes so that the code
+/// using an index (like [`spatial_join::join_with_tree`](crate::spatial_join::join_with_tree))
+/// doesn't depend on a specific one.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry, GResult, STRtree, SpatialIndex};
+///
+/// // Works with any index.
+/// fn closest_name<T: SpatialIndex<String>>(index: &T, wkt: &str) -> GResult<Option<String>> {
+///     let geometry = Geometry::new_from_wkt(wkt)?;
+///     Ok(index.nearest(&geometry)?.cloned())
+/// }
+///
+/// let mut tree = STRtree::with_capacity(10).unwrap();
+/// tree.insert(&Geometry::new_from_wkt("POINT(0 0)").unwrap(), "origin".to_owned()).unwrap();
+/// tree.insert(&Geometry::new_from_wkt("POINT(9 9)").unwrap(), "corner".to_owned()).unwrap();
+///
+/// assert_eq!(closest_name(&tree, "POINT(1 2)"), Ok(Some("origin".to_owned())));
+/// ```
 pub trait SpatialIndex<I> {
-    fn insert<G: Geom>(&mut self, geometry: &G, item: I);
+    /// Inserts `item`, associated with `geometry`.
+    fn insert<G: Geom>(&mut self, geometry: &G, item: I) -> GResult<()>;
 
+    /// Calls `visitor` on each item whose geometry envelope intersects the envelope of
+    /// `geometry`.
     fn query<G: Geom, V: FnMut(&I)>(&self, geometry: &G, visitor: V);
+
+    /// Returns the item whose geometry is the closest to `geometry`, `None` if the index is
+    /// empty.
+    fn nearest<G: Geom>(&self, geometry: &G) -> GResult<Option<&I>>;
 }
 
+/// Spatial index of items, each of them being associated with a geometry.
+///
+/// The tree keeps its own copy of the inserted geometries, so they can be dropped (or
+/// modified) once inserted.
+///
+/// The tree is built by GEOS on the first query (or explicitly with [`STRtree::build`]). Once
+/// built, GEOS can't insert items in it anymore: the new items are inserted in a new tree, to
+/// which the other items are moved on the next query. Inserting several items between two
+/// queries thus rebuilds the tree only once, but alternating insertions and queries rebuilds it
+/// each time: to insert a lot of items, use a [`STRtreeBuilder`] (see
+/// [`STRtree::into_builder`]).
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geometry, STRtree, SpatialIndex};
+///
+/// let mut tree = STRtree::with_capacity(10).unwrap();
+/// for (i, wkt) in ["POINT(0 0)", "POINT(5 5)", "LINESTRING(0 10, 10 10)"].iter().enumerate() {
+///     tree.insert(&Geometry::new_from_wkt(wkt).unwrap(), i).unwrap();
+/// }
+///
+/// let search = Geometry::new_from_wkt("POLYGON((4 4, 11 4, 11 11, 4 11, 4 4))").unwrap();
+/// let mut found = tree.query_items(&search);
+/// found.sort();
+/// assert_eq!(found, vec![&1, &2]);
+/// ```
 pub struct STRtree<I> {
-    pub(crate) ptr: PtrWrap<*mut GEOSSTRtree>,
+    ptr: AtomicPtr<GEOSSTRtree>,
+    // Tree receiving the items inserted once `ptr` is built (null if there is none): the items
+    // of `ptr` are moved to it on the next query.
+    next: Mutex<PtrWrap<*mut GEOSSTRtree>>,
     context: Arc<ContextHandle>,
+    node_capacity: usize,
+    len: usize,
+    built: AtomicBool,
     item_type: PhantomData<I>,
 }
 
+// Items are stored in the tree along with their geometry, which GEOS may refer to.
+type Entry<I> = (Geometry, I);
+
 impl<I> STRtree<I> {
     pub fn with_capacity(node_capacity: usize) -> GResult<STRtree<I>> {
         match ContextHandle::init_e(Some("STRtree::with_capacity")) {
             Ok(context_handle) => unsafe {
                 let ptr = GEOSSTRtree_create_r(context_handle.as_raw(), node_capacity);
                 Ok(STRtree {
-                    ptr: PtrWrap(ptr),
+                    ptr: AtomicPtr::new(ptr),
+                    next: Mutex::new(PtrWrap(std::ptr::null_mut())),
                     context: Arc::new(context_handle),
+                    node_capacity,
+                    len: 0,
+                    built: AtomicBool::new(false),
                     item_type: PhantomData,
                 })
             },
@@ -36,35 +107,327 @@ impl<I> STRtree<I> {
         }
     }
 
-    pub fn iterate<V>(&self, visitor: V)
+    pub fn iterate<V>(&self, mut visitor: V)
     where
         V: FnMut(&I),
     {
+        self.iterate_entries(|(_, item)| visitor(item));
+    }
+
+    /// Returns the items whose geometry envelope intersects the envelope of `geometry`.
+    pub fn query_items<G: Geom>(&self, geometry: &G) -> Vec<&I> {
+        let mut items = Vec::new();
+        self.query_entries(geometry, |entry: &Entry<I>| {
+            // The entries live as long as the tree.
+            items.push(unsafe { &*(&entry.1 as *const I) });
+        });
+        items
+    }
+
+    /// Returns the item minimizing `distance`, `None` if the tree is empty. `distance` is
+    /// called with the geometry and the item of the candidates, and must never be less than
+    /// the distance between `geometry` and the envelope of the candidate geometry.
+    ///
+    /// [`SpatialIndex::nearest`] uses the distance between the geometries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, STRtree, SpatialIndex};
+    ///
+    /// let mut stations = STRtree::with_capacity(10).unwrap();
+    /// stations.insert(&Geometry::new_from_wkt("POINT(0 0)").unwrap(), ("North", 0.)).unwrap();
+    /// stations.insert(&Geometry::new_from_wkt("POINT(10 0)").unwrap(), ("South", 100.)).unwrap();
+    ///
+    /// let point = Geometry::new_from_wkt("POINT(4 0)").unwrap();
+    /// // Each station has a cost added to its distance.
+    /// let nearest = stations.nearest_by(&point, |station, (_, cost)| {
+    ///     Ok(station.distance(&point)? + cost)
+    /// });
+    /// assert_eq!(nearest, Ok(Some(&("North", 0.))));
+    /// ```
+    pub fn nearest_by<G, F>(&self, geometry: &G, distance: F) -> GResult<Option<&I>>
+    where
+        G: Geom,
+        F: FnMut(&Geometry, &I) -> GResult<f64>,
+    {
+        struct Data<I, F> {
+            query: *const c_void,
+            distance: F,
+            error: Option<Error>,
+            item_type: PhantomData<I>,
+        }
+
+        unsafe extern "C" fn callback<I, F>(
+            item1: *const c_void,
+            item2: *const c_void,
+            distance: *mut f64,
+            userdata: *mut c_void,
+        ) -> libc::c_int
+        where
+            F: FnMut(&Geometry, &I) -> GResult<f64>,
+        {
+            let data = &mut *(userdata as *mut Data<I, F>);
+            // One of the items is the query geometry, the other one is an entry of the tree.
+            let entry = if item1 == data.query { item2 } else { item1 };
+            let (geometry, item) = &*(entry as *const Entry<I>);
+            match (data.distance)(geometry, item) {
+                Ok(d) => {
+                    *distance = d;
+                    1
+                }
+                Err(e) => {
+                    data.error = Some(e);
+                    0
+                }
+            }
+        }
+
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let mut data = Data {
+            query: geometry.as_raw() as *const c_void,
+            distance,
+            error: None,
+            item_type: PhantomData::<I>,
+        };
+        self.build_shared();
+        let nearest = unsafe {
+            GEOSSTRtree_nearest_generic_r(
+                self.get_raw_context(),
+                self.ptr.load(Ordering::Relaxed),
+                data.query,
+                geometry.as_raw(),
+                Some(callback::<I, F>),
+                &mut data as *mut Data<I, F> as *mut c_void,
+            )
+        };
+        if let Some(e) = data.error {
+            return Err(e);
+        }
+        if nearest.is_null() {
+            return Err(Error::GenericError(
+                "GEOSSTRtree_nearest_generic_r failed".to_owned(),
+            ));
+        }
+        Ok(Some(unsafe { &(*(nearest as *const Entry<I>)).1 }))
+    }
+
+    /// Returns the number of items in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the tree is built, meaning that inserting an item rebuilds it on the
+    /// next query.
+    pub fn is_built(&self) -> bool {
+        self.built.load(Ordering::Relaxed) && self.next().is_null()
+    }
+
+    /// Builds the tree. It is otherwise built on the first query.
+    pub fn build(&mut self) -> GResult<()> {
+        self.build_shared();
+        Ok(())
+    }
+
+    /// Removes `item`, inserted with a geometry intersecting the envelope of `geometry`.
+    /// Returns `false` if there is no such item. If several items are equal to `item`, only one
+    /// of them is removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geometry, STRtree, SpatialIndex};
+    ///
+    /// let point = Geometry::new_from_wkt("POINT(1 1)").unwrap();
+    /// let mut tree = STRtree::with_capacity(10).unwrap();
+    /// tree.insert(&point, "a").unwrap();
+    /// tree.insert(&point, "b").unwrap();
+    ///
+    /// assert_eq!(tree.remove(&point, &"a"), Ok(true));
+    /// assert_eq!(tree.remove(&point, &"a"), Ok(false));
+    /// assert_eq!(tree.query_items(&point), vec![&"b"]);
+    /// ```
+    pub fn remove<G: Geom>(&mut self, geometry: &G, item: &I) -> GResult<bool>
+    where
+        I: PartialEq,
+    {
+        let mut found = None;
+        self.query_entries(geometry, |entry: &Entry<I>| {
+            if found.is_none() && entry.1 == *item {
+                found = Some(entry as *const Entry<I> as *mut Entry<I>);
+            }
+        });
+        let Some(entry) = found else {
+            return Ok(false);
+        };
+        let ret = unsafe {
+            GEOSSTRtree_remove_r(
+                self.get_raw_context(),
+                self.ptr.load(Ordering::Relaxed),
+                (*entry).0.as_raw(),
+                entry as *mut c_void,
+            )
+        };
+        match ret {
+            0 => Ok(false),
+            1 => {
+                drop(unsafe { Box::from_raw(entry) });
+                self.len -= 1;
+                Ok(true)
+            }
+            _ => Err(Error::GenericError(
+                "GEOSSTRtree_remove_r failed".to_owned(),
+            )),
+        }
+    }
+
+    /// Converts the tree back to a builder, to insert new items.
+    pub fn into_builder(mut self) -> GResult<STRtreeBuilder<I>> {
+        self.unbuild()?;
+        Ok(STRtreeBuilder { tree: self })
+    }
+
+    /// Moves the entries to a new tree, which isn't built yet.
+    fn unbuild(&mut self) -> GResult<()> {
+        self.move_to_next_tree();
+        if !self.is_built() {
+            return Ok(());
+        }
+        let mut entries = Vec::with_capacity(self.len);
+        self.iterate_tree(|entry: &Entry<I>| {
+            entries.push(entry as *const Entry<I> as *mut Entry<I>);
+        });
         unsafe {
-            let (closure, callback) = unpack_closure(&visitor);
-            GEOSSTRtree_iterate_r(self.get_raw_context(), *self.ptr, Some(callback), closure);
+            let ptr = GEOSSTRtree_create_r(self.get_raw_context(), self.node_capacity);
+            if ptr.is_null() {
+                return Err(Error::GenericError(
+                    "GEOSSTRtree_create_r failed".to_owned(),
+                ));
+            }
+            for entry in entries {
+                GEOSSTRtree_insert_r(
+                    self.get_raw_context(),
+                    ptr,
+                    (*entry).0.as_raw(),
+                    entry as *mut c_void,
+                );
+            }
+            // The entries now belong to the new tree, so they mustn't be dropped here.
+            GEOSSTRtree_destroy_r(self.get_raw_context(), self.ptr.load(Ordering::Relaxed));
+            self.ptr.store(ptr, Ordering::Relaxed);
         }
+        self.built.store(false, Ordering::Relaxed);
+        Ok(())
     }
-}
 
-impl<I> SpatialIndex<I> for STRtree<I> {
-    fn insert<G: Geom>(&mut self, geometry: &G, item: I) {
+    fn next(&self) -> MutexGuard<'_, PtrWrap<*mut GEOSSTRtree>> {
+        // No code which could panic runs while the lock is held, so it can't be poisoned.
+        self.next.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Moves the entries of the built tree to the tree holding the items inserted since it was
+    /// built, if any, which then replaces it.
+    ///
+    /// `next` can only be set through `&mut self`, so once it is null it stays so while the tree
+    /// is shared: a query can't see the tree being replaced under it.
+    fn move_to_next_tree(&self) {
+        self.move_to_tree(&mut self.next());
+    }
+
+    /// Moves the entries to the next tree, if any, and builds the tree. Both happen while `next`
+    /// is locked, so that queries sharing the tree don't build it at the same time: once this
+    /// returns, the tree is only read until it is borrowed mutably again.
+    fn build_shared(&self) {
+        let mut next = self.next();
+        self.move_to_tree(&mut next);
+        if !self.built.load(Ordering::Relaxed) {
+            self.build_tree();
+            self.built.store(true, Ordering::Relaxed);
+        }
+    }
+
+    fn build_tree(&self) {
+        let context = self.get_raw_context();
+        let ptr = self.ptr.load(Ordering::Relaxed);
+        #[cfg(any(feature = "v3_12_0", feature = "dox"))]
+        if unsafe { GEOSSTRtree_build_r(context, ptr) } == 1 {
+            return;
+        }
+        // GEOS builds the tree on the first query, so query it with the geometry of any of its
+        // items. An empty tree is never queried, so it doesn't need to be built.
+        let mut first = None;
+        self.iterate_tree(|entry: &Entry<I>| {
+            first.get_or_insert(entry.0.as_raw());
+        });
+        if let Some(geometry) = first {
+            unsafe {
+                let (closure, callback) = unpack_closure(&|_: &Entry<I>| {});
+                GEOSSTRtree_query_r(context, ptr, geometry, Some(callback), closure);
+            }
+        }
+    }
+
+    fn move_to_tree(&self, next: &mut PtrWrap<*mut GEOSSTRtree>) {
+        if next.is_null() {
+            return;
+        }
+        let mut entries = Vec::with_capacity(self.len);
+        self.iterate_tree(|entry: &Entry<I>| {
+            entries.push(entry as *const Entry<I> as *mut Entry<I>);
+        });
         unsafe {
-            GEOSSTRtree_insert_r(
+            for entry in entries {
+                GEOSSTRtree_insert_r(
+                    self.get_raw_context(),
+                    **next,
+                    (*entry).0.as_raw(),
+                    entry as *mut c_void,
+                );
+            }
+            // The entries now belong to the new tree, so they mustn't be dropped here.
+            GEOSSTRtree_destroy_r(self.get_raw_context(), self.ptr.load(Ordering::Relaxed));
+        }
+        self.ptr.store(**next, Ordering::Relaxed);
+        *next = PtrWrap(std::ptr::null_mut());
+        self.built.store(false, Ordering::Relaxed);
+    }
+
+    fn iterate_entries<V: FnMut(&Entry<I>)>(&self, visitor: V) {
+        if self.is_empty() {
+            return;
+        }
+        self.build_shared();
+        self.iterate_tree(visitor);
+    }
+
+    fn iterate_tree<V: FnMut(&Entry<I>)>(&self, visitor: V) {
+        unsafe {
+            let (closure, callback) = unpack_closure(&visitor);
+            GEOSSTRtree_iterate_r(
                 self.get_raw_context(),
-                *self.ptr,
-                geometry.as_raw(),
-                Box::into_raw(Box::new(item)) as *mut c_void,
+                self.ptr.load(Ordering::Relaxed),
+                Some(callback),
+                closure,
             );
         }
     }
 
-    fn query<'b, G: Geom, V: FnMut(&I)>(&self, geometry: &G, visitor: V) {
+    fn query_entries<G: Geom, V: FnMut(&Entry<I>)>(&self, geometry: &G, visitor: V) {
+        if self.is_empty() {
+            return;
+        }
+        self.build_shared();
         unsafe {
             let (closure, callback) = unpack_closure(&visitor);
             GEOSSTRtree_query_r(
                 self.get_raw_context(),
-                *self.ptr,
+                self.ptr.load(Ordering::Relaxed),
                 geometry.as_raw(),
                 Some(callback),
                 closure,
@@ -73,11 +436,124 @@ impl<I> SpatialIndex<I> for STRtree<I> {
     }
 }
 
+impl<I> SpatialIndex<I> for STRtree<I> {
+    fn insert<G: Geom>(&mut self, geometry: &G, item: I) -> GResult<()> {
+        let context = self.get_raw_context();
+        let built = self.built.load(Ordering::Relaxed);
+        let next = self.next.get_mut().unwrap_or_else(PoisonError::into_inner);
+        // GEOS can't insert items in a built tree: they are inserted in a new one, to which the
+        // other items are moved on the next query.
+        let tree = if !built {
+            *self.ptr.get_mut()
+        } else {
+            if next.is_null() {
+                let ptr = unsafe { GEOSSTRtree_create_r(context, self.node_capacity) };
+                if ptr.is_null() {
+                    return Err(Error::GenericError(
+                        "GEOSSTRtree_create_r failed".to_owned(),
+                    ));
+                }
+                *next = PtrWrap(ptr);
+            }
+            **next
+        };
+        let entry: Box<Entry<I>> = Box::new((Geom::clone(geometry), item));
+        unsafe {
+            GEOSSTRtree_insert_r(
+                context,
+                tree,
+                entry.0.as_raw(),
+                Box::into_raw(entry) as *mut c_void,
+            );
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn query<G: Geom, V: FnMut(&I)>(&self, geometry: &G, mut visitor: V) {
+        self.query_entries(geometry, |(_, item)| visitor(item));
+    }
+
+    /// See [`STRtree::nearest_by`] to use another distance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geometry, STRtree, SpatialIndex};
+    ///
+    /// let mut roads = STRtree::with_capacity(10).unwrap();
+    /// let main_street = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)").unwrap();
+    /// let high_street = Geometry::new_from_wkt("LINESTRING(0 5, 10 5)").unwrap();
+    /// roads.insert(&main_street, "Main Street").unwrap();
+    /// roads.insert(&high_street, "High Street").unwrap();
+    ///
+    /// let gps_point = Geometry::new_from_wkt("POINT(3 3.5)").unwrap();
+    /// assert_eq!(roads.nearest(&gps_point), Ok(Some(&"High Street")));
+    /// ```
+    fn nearest<G: Geom>(&self, geometry: &G) -> GResult<Option<&I>> {
+        self.nearest_by(geometry, |candidate, _| candidate.distance(geometry))
+    }
+}
+
+/// Builder of a [`STRtree`], to which items can be inserted but which can't be queried.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geometry, STRtreeBuilder};
+///
+/// let mut builder = STRtreeBuilder::with_capacity(10).unwrap();
+/// for i in 0..10 {
+///     let point = Geometry::new_from_wkt(&format!("POINT({i} {i})")).unwrap();
+///     builder.insert(&point, i).unwrap();
+/// }
+/// let mut tree = builder.build().unwrap();
+/// let point = Geometry::new_from_wkt("POINT(3 3)").unwrap();
+/// assert_eq!(tree.query_items(&point), vec![&3]);
+///
+/// // Inserts a new item, without rebuilding the tree for each insertion.
+/// let mut builder = tree.into_builder().unwrap();
+/// builder.insert(&point, 10).unwrap();
+/// let tree = builder.build().unwrap();
+/// assert_eq!(tree.len(), 11);
+/// ```
+pub struct STRtreeBuilder<I> {
+    tree: STRtree<I>,
+}
+
+impl<I> STRtreeBuilder<I> {
+    pub fn with_capacity(node_capacity: usize) -> GResult<STRtreeBuilder<I>> {
+        Ok(STRtreeBuilder {
+            tree: STRtree::with_capacity(node_capacity)?,
+        })
+    }
+
+    pub fn insert<G: Geom>(&mut self, geometry: &G, item: I) -> GResult<()> {
+        self.tree.insert(geometry, item)
+    }
+
+    /// Returns the number of items inserted so far.
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Builds the tree.
+    pub fn build(mut self) -> GResult<STRtree<I>> {
+        self.tree.build()?;
+        Ok(self.tree)
+    }
+}
+
 impl<I> AsRaw for STRtree<I> {
     type RawType = GEOSSTRtree;
 
     fn as_raw(&self) -> *const Self::RawType {
-        *self.ptr
+        self.move_to_next_tree();
+        self.ptr.load(Ordering::Relaxed)
     }
 }
 
@@ -85,7 +561,8 @@ impl<I> AsRawMut for STRtree<I> {
     type RawType = GEOSSTRtree;
 
     unsafe fn as_raw_mut_override(&self) -> *mut Self::RawType {
-        *self.ptr
+        self.move_to_next_tree();
+        self.ptr.load(Ordering::Relaxed)
     }
 }
 
@@ -104,17 +581,18 @@ impl<I> ContextHandling for STRtree<I> {
 impl<I> Drop for STRtree<I> {
     fn drop(&mut self) {
         unsafe extern "C" fn callback<I>(item: *mut c_void, _data: *mut c_void) {
-            drop(Box::from_raw(item as *mut I));
+            drop(Box::from_raw(item as *mut Entry<I>));
         }
 
+        self.move_to_next_tree();
         unsafe {
             GEOSSTRtree_iterate_r(
                 self.get_raw_context(),
-                *self.ptr,
+                self.ptr.load(Ordering::Relaxed),
                 Some(callback::<I>),
                 std::ptr::null_mut(),
             );
-            GEOSSTRtree_destroy_r(self.get_raw_context(), *self.ptr);
+            GEOSSTRtree_destroy_r(self.get_raw_context(), self.ptr.load(Ordering::Relaxed));
         }
     }
 }
@@ -142,7 +620,7 @@ where
 mod test {
     use std::collections::HashSet;
 
-    use crate::{Geometry, STRtree, SpatialIndex};
+    use crate::{Error, Geometry, STRtree, SpatialIndex};
 
     #[test]
     fn test_strtree() {
@@ -152,9 +630,9 @@ mod test {
         let line = Geometry::new_from_wkt("LINESTRING (0 0, 10 0)").unwrap();
         let polygon = Geometry::new_from_wkt("POLYGON((2 2, 8 2, 8 8, 2 8, 2 2))").unwrap();
 
-        tree.insert(&point, "Point");
-        tree.insert(&line, "Line");
-        tree.insert(&polygon, "Polygon");
+        tree.insert(&point, "Point").unwrap();
+        tree.insert(&line, "Line").unwrap();
+        tree.insert(&polygon, "Polygon").unwrap();
 
         // Test iterate
 
@@ -176,5 +654,101 @@ mod test {
         });
 
         assert_eq!(items, vec!["Point", "Polygon"].into_iter().collect());
+
+        let mut found = tree.query_items(&point);
+        found.sort();
+        assert_eq!(found, vec![&"Point", &"Polygon"]);
+        assert_eq!(tree.len(), 3);
+    }
+
+    #[test]
+    fn test_strtree_outlives_geometries() {
+        let mut tree = STRtree::<usize>::with_capacity(10).unwrap();
+        for i in 0..10 {
+            let point = Geometry::new_from_wkt(&format!("POINT({i} {i})")).unwrap();
+            tree.insert(&point, i).unwrap();
+        }
+
+        let search = Geometry::new_from_wkt("LINESTRING(2.5 2.5, 5.5 5.5)").unwrap();
+        let mut found = tree.query_items(&search);
+        found.sort();
+        assert_eq!(found, vec![&3, &4, &5]);
+    }
+
+    #[test]
+    fn test_strtree_nearest() {
+        let mut tree = STRtree::<usize>::with_capacity(10).unwrap();
+        let point = Geometry::new_from_wkt("POINT(1 1)").unwrap();
+        assert_eq!(tree.nearest(&point), Ok(None));
+
+        for i in 0..100 {
+            let line = Geometry::new_from_wkt(&format!("LINESTRING({i} 0, {i} 10)")).unwrap();
+            tree.insert(&line, i).unwrap();
+        }
+        let point = Geometry::new_from_wkt("POINT(42.3 5)").unwrap();
+        assert_eq!(tree.nearest(&point), Ok(Some(&42)));
+
+        let error = tree.nearest_by(&point, |_, _| Err(Error::GenericError("nope".to_owned())));
+        assert_eq!(error, Err(Error::GenericError("nope".to_owned())));
+    }
+
+    #[test]
+    fn test_strtree_insert_after_build() {
+        let mut tree = STRtree::<usize>::with_capacity(10).unwrap();
+        let a = Geometry::new_from_wkt("POINT(0 0)").unwrap();
+        let b = Geometry::new_from_wkt("POINT(5 5)").unwrap();
+        tree.insert(&a, 0).unwrap();
+        tree.build().unwrap();
+        assert!(tree.is_built());
+
+        tree.insert(&b, 1).unwrap();
+        assert!(!tree.is_built());
+        tree.insert(&b, 2).unwrap();
+        let mut found = tree.query_items(&b);
+        found.sort();
+        assert_eq!(found, vec![&1, &2]);
+
+        assert_eq!(tree.remove(&b, &1), Ok(true));
+        tree.insert(&a, 3).unwrap();
+        let mut items = Vec::new();
+        tree.iterate(|i| items.push(*i));
+        items.sort();
+        assert_eq!(items, vec![0, 2, 3]);
+        assert_eq!(tree.len(), 3);
+
+        // Items inserted after a query are found by the next one.
+        let c = Geometry::new_from_wkt("POINT(20 20)").unwrap();
+        tree.insert(&c, 4).unwrap();
+        tree.insert(&c, 5).unwrap();
+        assert!(matches!(tree.nearest(&c), Ok(Some(&4)) | Ok(Some(&5))));
+        let mut found = tree.query_items(&c);
+        found.sort();
+        assert_eq!(found, vec![&4, &5]);
+        assert_eq!(tree.len(), 5);
+    }
+
+    #[test]
+    fn test_strtree_shared_queries() {
+        let mut tree = STRtree::<usize>::with_capacity(10).unwrap();
+        for i in 0..100 {
+            let point = Geometry::new_from_wkt(&format!("POINT({i} {i})")).unwrap();
+            tree.insert(&point, i).unwrap();
+        }
+        tree.build().unwrap();
+        let point = Geometry::new_from_wkt("POINT(200 200)").unwrap();
+        tree.insert(&point, 200).unwrap();
+
+        // The first queries move the items to a new tree and build it.
+        let tree = &tree;
+        std::thread::scope(|s| {
+            for i in 0..4 {
+                s.spawn(move || {
+                    let search = Geometry::new_from_wkt(&format!("POINT({i} {i})")).unwrap();
+                    assert_eq!(tree.query_items(&search), vec![&i]);
+                });
+            }
+        });
+        assert!(tree.is_built());
+        assert_eq!(tree.len(), 101);
     }
 }
@@ -0,0 +1,183 @@
+//! Spatial joins between two lists of geometries.
+//!
+//! Candidate pairs are found using a [`STRtree`](crate::STRtree) of the right geometries, then
+//! the [`Predicate`] is compiled against each left geometry (see [`Predicate::compile`]) to
+//! check its candidates. Joins are thus faster when the left geometries are the complex ones
+//! (polygons containing points for example).
+//!
+//! # Example
+//!
+//! ```
+//! use geos::spatial_join;
+//! use geos::{Geometry, Predicate};
+//!
+//! let zones = vec![
+//!     Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap(),
+//!     Geometry::new_from_wkt("POLYGON((10 0, 20 0, 20 10, 10 10, 10 0))").unwrap(),
+//! ];
+//! let shops = vec![
+//!     Geometry::new_from_wkt("POINT(5 5)").unwrap(),
+//!     Geometry::new_from_wkt("POINT(10 5)").unwrap(),
+//!     Geometry::new_from_wkt("POINT(15 5)").unwrap(),
+//! ];
+//!
+//! let pairs = spatial_join::join(&zones, &shops, &Predicate::contains()).unwrap();
+//! assert_eq!(pairs, vec![(0, 0), (1, 2)]);
+//! let pairs = spatial_join::join(&zones, &shops, &Predicate::intersects()).unwrap();
+//! assert_eq!(pairs, vec![(0, 0), (0, 1), (1, 1), (1, 2)]);
+//! let predicate = "intersects and not touches".parse().unwrap();
+//! let pairs = spatial_join::join(&zones, &shops, &predicate).unwrap();
+//! assert_eq!(pairs, vec![(0, 0), (1, 2)]);
+//! ```
+use crate::error::GResult;
+#[cfg(not(any(feature = "v3_10_0", feature = "dox")))]
+use crate::layer::is_within_distance;
+use crate::layer::{build_tree, query_within_distance};
+#[cfg(any(feature = "v3_10_0", feature = "dox"))]
+use crate::PreparedGeometry;
+use crate::{Geometry, Predicate, SpatialIndex};
+use std::borrow::Borrow;
+
+/// Returns all the `(i, j)` such as `predicate` is true between `left[i]` (its target) and
+/// `right[j]`, sorted.
+pub fn join<A, B>(left: &[A], right: &[B], predicate: &Predicate) -> GResult<Vec<(usize, usize)>>
+where
+    A: Borrow<Geometry>,
+    B: Borrow<Geometry>,
+{
+    join_with_tree(left, &build_tree(right)?, right, predicate)
+}
+
+/// Same as [`join`], using an existing `tree` (or any other [`SpatialIndex`]) of the `right`
+/// geometries, each of them being inserted with its index in `right`. It avoids rebuilding the
+/// tree when joining several lists of geometries with the same right geometries.
+///
+/// The tree is only used when `predicate` can't be true for disjoint geometries: with
+/// `disjoint` or `not` for example, each left geometry is checked against all the right ones.
+///
+/// # Example
+///
+/// ```
+/// use geos::spatial_join;
+/// use geos::{Geometry, Predicate, STRtree, SpatialIndex};
+///
+/// let parcels = vec![
+///     Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap(),
+///     Geometry::new_from_wkt("POLYGON((20 0, 30 0, 30 10, 20 10, 20 0))").unwrap(),
+/// ];
+/// let mut tree = STRtree::with_capacity(10).unwrap();
+/// for (i, parcel) in parcels.iter().enumerate() {
+///     tree.insert(parcel, i).unwrap();
+/// }
+///
+/// let roads = vec![Geometry::new_from_wkt("LINESTRING(-5 5, 15 5)").unwrap()];
+/// let crossed = spatial_join::join_with_tree(&roads, &tree, &parcels, &Predicate::crosses());
+/// assert_eq!(crossed, Ok(vec![(0, 0)]));
+/// let away = spatial_join::join_with_tree(&roads, &tree, &parcels, &Predicate::disjoint());
+/// assert_eq!(away, Ok(vec![(0, 1)]));
+/// ```
+pub fn join_with_tree<A, B, T>(
+    left: &[A],
+    tree: &T,
+    right: &[B],
+    predicate: &Predicate,
+) -> GResult<Vec<(usize, usize)>>
+where
+    A: Borrow<Geometry>,
+    B: Borrow<Geometry>,
+    T: SpatialIndex<usize>,
+{
+    let use_tree = predicate.requires_intersection();
+    let mut pairs = Vec::new();
+    for (i, g) in left.iter().enumerate() {
+        let g = g.borrow();
+        let candidates = if use_tree {
+            let mut candidates = Vec::new();
+            tree.query(g, |j| candidates.push(*j));
+            candidates.sort_unstable();
+            candidates
+        } else {
+            (0..right.len()).collect()
+        };
+        if candidates.is_empty() {
+            continue;
+        }
+        let compiled = predicate.compile(g)?;
+        for j in candidates {
+            if compiled.evaluate(right[j].borrow())? {
+                pairs.push((i, j));
+            }
+        }
+    }
+    Ok(pairs)
+}
+
+/// Returns all the `(i, j)` such as the distance between `left[i]` and `right[j]` is less than
+/// or equal to `distance`, sorted.
+pub fn join_within_distance<A, B>(
+    left: &[A],
+    right: &[B],
+    distance: f64,
+) -> GResult<Vec<(usize, usize)>>
+where
+    A: Borrow<Geometry>,
+    B: Borrow<Geometry>,
+{
+    join_within_distance_with_tree(left, &build_tree(right)?, right, distance)
+}
+
+/// Same as [`join_within_distance`], using an existing `tree` of the `right` geometries (see
+/// [`join_with_tree`]).
+///
+/// # Example
+///
+/// ```
+/// use geos::spatial_join;
+/// use geos::{Geometry, STRtree, SpatialIndex};
+///
+/// let stops = vec![
+///     Geometry::new_from_wkt("POINT(0 0)").unwrap(),
+///     Geometry::new_from_wkt("POINT(10 0)").unwrap(),
+/// ];
+/// let mut tree = STRtree::with_capacity(10).unwrap();
+/// for (i, stop) in stops.iter().enumerate() {
+///     tree.insert(stop, i).unwrap();
+/// }
+///
+/// let homes = vec![Geometry::new_from_wkt("POINT(3 4)").unwrap()];
+/// let pairs = spatial_join::join_within_distance_with_tree(&homes, &tree, &stops, 5.).unwrap();
+/// assert_eq!(pairs, vec![(0, 0)]);
+/// ```
+pub fn join_within_distance_with_tree<A, B, T>(
+    left: &[A],
+    tree: &T,
+    right: &[B],
+    distance: f64,
+) -> GResult<Vec<(usize, usize)>>
+where
+    A: Borrow<Geometry>,
+    B: Borrow<Geometry>,
+    T: SpatialIndex<usize>,
+{
+    let mut pairs = Vec::new();
+    for (i, g) in left.iter().enumerate() {
+        let g = g.borrow();
+        let candidates = query_within_distance(tree, g, distance)?;
+        if candidates.is_empty() {
+            continue;
+        }
+        #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+        let prepared = PreparedGeometry::new(g)?;
+        for j in candidates {
+            let other = right[j].borrow();
+            #[cfg(any(feature = "v3_10_0", feature = "dox"))]
+            let matches = prepared.is_within_distance(other, distance)?;
+            #[cfg(not(any(feature = "v3_10_0", feature = "dox")))]
+            let matches = is_within_distance(g, other, distance)?;
+            if matches {
+                pairs.push((i, j));
+            }
+        }
+    }
+    Ok(pairs)
+}
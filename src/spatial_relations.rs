@@ -0,0 +1,121 @@
+//! Spatial predicates shared by geometries and prepared geometries.
+//!
+//! [`SpatialRelations`] methods have the same names as the ones of [`Geom`], so it isn't
+//! exported at the crate root: importing both traits (with `use geos::*` for instance) would
+//! make calls like `g.contains(..)` ambiguous.
+use crate::error::GResult;
+use crate::{Geom, PreparedGeometry};
+
+/// Spatial predicates shared by geometries (implemented through [`Geom`]) and
+/// [`PreparedGeometry`], so generic code (spatial filters, joins...) can accept both.
+///
+/// The methods have the same names as the ones of [`Geom`] and [`PreparedGeometry`], so this
+/// trait is meant to be used as a bound rather than imported along with [`Geom`].
+///
+/// # Example
+///
+/// ```
+/// use geos::spatial_relations::SpatialRelations;
+/// use geos::Geometry;
+///
+/// fn count_inside<R: SpatialRelations>(area: &R, points: &[Geometry]) -> usize {
+///     points.iter().filter(|p| area.contains(*p).unwrap_or(false)).count()
+/// }
+///
+/// let area = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+/// let points = vec![
+///     Geometry::new_from_wkt("POINT(5 5)").unwrap(),
+///     Geometry::new_from_wkt("POINT(15 5)").unwrap(),
+/// ];
+///
+/// assert_eq!(count_inside(&area, &points), 1);
+/// let prepared = geos::PreparedGeometry::new(&area).unwrap();
+/// assert_eq!(count_inside(&prepared, &points), 1);
+/// ```
+pub trait SpatialRelations {
+    fn contains<G: Geom>(&self, other: &G) -> GResult<bool>;
+    fn covered_by<G: Geom>(&self, other: &G) -> GResult<bool>;
+    fn covers<G: Geom>(&self, other: &G) -> GResult<bool>;
+    fn crosses<G: Geom>(&self, other: &G) -> GResult<bool>;
+    fn disjoint<G: Geom>(&self, other: &G) -> GResult<bool>;
+    fn intersects<G: Geom>(&self, other: &G) -> GResult<bool>;
+    fn overlaps<G: Geom>(&self, other: &G) -> GResult<bool>;
+    fn touches<G: Geom>(&self, other: &G) -> GResult<bool>;
+    fn within<G: Geom>(&self, other: &G) -> GResult<bool>;
+}
+
+impl<T: Geom> SpatialRelations for T {
+    fn contains<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::contains(self, other)
+    }
+
+    fn covered_by<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::covered_by(self, other)
+    }
+
+    fn covers<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::covers(self, other)
+    }
+
+    fn crosses<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::crosses(self, other)
+    }
+
+    fn disjoint<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::disjoint(self, other)
+    }
+
+    fn intersects<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::intersects(self, other)
+    }
+
+    fn overlaps<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::overlaps(self, other)
+    }
+
+    fn touches<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::touches(self, other)
+    }
+
+    fn within<G: Geom>(&self, other: &G) -> GResult<bool> {
+        Geom::within(self, other)
+    }
+}
+
+impl SpatialRelations for PreparedGeometry {
+    fn contains<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::contains(self, other)
+    }
+
+    fn covered_by<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::covered_by(self, other)
+    }
+
+    fn covers<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::covers(self, other)
+    }
+
+    fn crosses<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::crosses(self, other)
+    }
+
+    fn disjoint<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::disjoint(self, other)
+    }
+
+    fn intersects<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::intersects(self, other)
+    }
+
+    fn overlaps<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::overlaps(self, other)
+    }
+
+    fn touches<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::touches(self, other)
+    }
+
+    fn within<G: Geom>(&self, other: &G) -> GResult<bool> {
+        PreparedGeometry::within(self, other)
+    }
+}
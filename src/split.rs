@@ -0,0 +1,140 @@
+use crate::error::{Error, GResult};
+use crate::{CoordSeq, Geom, Geometry, GeometryTypes};
+
+fn is_polygonal(t: GeometryTypes) -> bool {
+    matches!(t, GeometryTypes::Polygon | GeometryTypes::MultiPolygon)
+}
+
+fn is_lineal(t: GeometryTypes) -> bool {
+    matches!(
+        t,
+        GeometryTypes::LineString | GeometryTypes::LinearRing | GeometryTypes::MultiLineString
+    )
+}
+
+fn is_puntal(t: GeometryTypes) -> bool {
+    matches!(t, GeometryTypes::Point | GeometryTypes::MultiPoint)
+}
+
+fn parts<G: Geom>(g: &G) -> GResult<Vec<Geometry>> {
+    if g.is_empty()? {
+        return Ok(Vec::new());
+    }
+    (0..g.get_num_geometries()?)
+        .map(|i| g.get_geometry_n(i).map(|p| Geom::clone(&p)))
+        .collect()
+}
+
+fn xy(coords: &CoordSeq) -> GResult<Vec<(f64, f64)>> {
+    (0..coords.size()?)
+        .map(|i| Ok((coords.get_x(i)?, coords.get_y(i)?)))
+        .collect()
+}
+
+/// Polygons are split by polygonizing their noded boundary along with the blade, then only
+/// keeping the faces inside the original polygon (so holes stay holes).
+fn split_polygon<G: Geom, B: Geom>(polygon: &G, blade: &B) -> GResult<Vec<Geometry>> {
+    let edges = polygon.boundary()?.union(blade)?;
+    let faces = Geometry::polygonize(&[edges])?;
+    let mut res = Vec::new();
+    for face in parts(&faces)? {
+        if polygon.contains(&face.point_on_surface()?)? {
+            res.push(face);
+        }
+    }
+    Ok(res)
+}
+
+/// Splits a line at the given points, keeping the ones lying on it.
+fn split_line_by_points<G: Geom>(line: &G, points: &[(f64, f64)]) -> GResult<Vec<Geometry>> {
+    let coords = xy(&line.get_coord_seq()?)?;
+    if coords.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut pieces = Vec::new();
+    let mut current = vec![coords[0]];
+    for (i, w) in coords.windows(2).enumerate() {
+        let ((ax, ay), (bx, by)) = (w[0], w[1]);
+        let (dx, dy) = (bx - ax, by - ay);
+        let len2 = dx * dx + dy * dy;
+        let is_last = i + 2 == coords.len();
+        // Position of each point on the segment, between 0 (excluded) and 1 (included).
+        let mut cuts = points
+            .iter()
+            .filter_map(|&(px, py)| {
+                if len2 == 0. {
+                    return None;
+                }
+                // Distance from the point to the segment line, compared to a tolerance scaled
+                // to the magnitude of the coordinates.
+                let distance = (dx * (py - ay) - dy * (px - ax)).abs() / len2.sqrt();
+                let tolerance = 1e-12 * (ax.abs() + ay.abs() + bx.abs() + by.abs()).max(1.);
+                let t = (dx * (px - ax) + dy * (py - ay)) / len2;
+                if distance <= tolerance && t > 0. && t <= 1. {
+                    Some(t)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        cuts.sort_by(|a, b| a.partial_cmp(b).expect("invalid coordinate"));
+        cuts.dedup();
+        let mut end_pushed = false;
+        for t in cuts {
+            let p = if t == 1. {
+                end_pushed = true;
+                (bx, by)
+            } else {
+                (ax + t * dx, ay + t * dy)
+            };
+            if t == 1. && is_last {
+                break;
+            }
+            current.push(p);
+            pieces.push(std::mem::replace(&mut current, vec![p]));
+        }
+        if !end_pushed || is_last {
+            current.push((bx, by));
+        }
+    }
+    if current.len() > 1 {
+        pieces.push(current);
+    }
+    pieces
+        .into_iter()
+        .map(|piece| {
+            let piece = piece.iter().map(|&(x, y)| [x, y]).collect::<Vec<_>>();
+            CoordSeq::new_from_vec(&piece)?.create_line_string()
+        })
+        .collect()
+}
+
+pub(crate) fn split<G: Geom, B: Geom>(g: &G, blade: &B) -> GResult<Geometry> {
+    let (geom_type, blade_type) = (g.geometry_type(), blade.geometry_type());
+    if is_polygonal(blade_type) && (is_polygonal(geom_type) || is_lineal(geom_type)) {
+        return split(g, &blade.boundary()?);
+    }
+    let mut res = Vec::new();
+    if g.is_empty()? || blade.is_empty()? || (is_polygonal(geom_type) && is_puntal(blade_type)) {
+        res.push(Geom::clone(g));
+    } else if is_polygonal(geom_type) && is_lineal(blade_type) {
+        for polygon in parts(g)? {
+            res.extend(split_polygon(&polygon, blade)?);
+        }
+    } else if is_lineal(geom_type) && is_puntal(blade_type) {
+        let points = parts(blade)?
+            .iter()
+            .map(|p| Ok((p.get_x()?, p.get_y()?)))
+            .collect::<GResult<Vec<_>>>()?;
+        for line in parts(g)? {
+            res.extend(split_line_by_points(&line, &points)?);
+        }
+    } else if is_lineal(geom_type) && is_lineal(blade_type) {
+        res.extend(parts(&g.difference(blade)?)?);
+    } else {
+        return Err(Error::ImpossibleOperation(format!(
+            "cannot split a {geom_type:?} by a {blade_type:?}"
+        )));
+    }
+    Geometry::create_geometry_collection(res)
+}
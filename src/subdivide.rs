@@ -0,0 +1,147 @@
+use crate::error::{Error, GResult};
+use crate::functions::bounds;
+use crate::{Geom, Geometry, GeometryTypes};
+
+// Same limit as PostGIS' ST_Subdivide.
+const MAX_DEPTH: u32 = 50;
+
+pub(crate) fn subdivide<G: Geom>(g: &G, max_vertices: usize) -> GResult<Vec<Geometry>> {
+    if max_vertices < 5 {
+        return Err(Error::GenericError(
+            "max_vertices must be at least 5".to_owned(),
+        ));
+    }
+    let mut out = Vec::new();
+    subdivide_geometry(g, max_vertices, 0, &mut out)?;
+    Ok(out)
+}
+
+/// Returns the halves of the given envelope, cut along its longest side.
+fn halves((xmin, ymin, xmax, ymax): (f64, f64, f64, f64)) -> [(f64, f64, f64, f64); 2] {
+    let (width, height) = (xmax - xmin, ymax - ymin);
+    if width >= height {
+        let middle = xmin + width / 2.;
+        [(xmin, ymin, middle, ymax), (middle, ymin, xmax, ymax)]
+    } else {
+        let middle = ymin + height / 2.;
+        [(xmin, ymin, xmax, middle), (xmin, middle, xmax, ymax)]
+    }
+}
+
+fn subdivide_geometry<G: Geom>(
+    g: &G,
+    max_vertices: usize,
+    depth: u32,
+    out: &mut Vec<Geometry>,
+) -> GResult<()> {
+    if g.is_empty()? {
+        return Ok(());
+    }
+    match g.geometry_type() {
+        GeometryTypes::MultiPoint => {
+            let mut points = Vec::with_capacity(g.get_num_geometries()?);
+            for i in 0..g.get_num_geometries()? {
+                let point = g.get_geometry_n(i)?;
+                if !point.is_empty()? {
+                    points.push((point.get_x()?, point.get_y()?, Geom::clone(&point)));
+                }
+            }
+            return subdivide_points(points, max_vertices, depth, out);
+        }
+        GeometryTypes::MultiLineString
+        | GeometryTypes::MultiPolygon
+        | GeometryTypes::GeometryCollection => {
+            for i in 0..g.get_num_geometries()? {
+                subdivide_geometry(&g.get_geometry_n(i)?, max_vertices, depth, out)?;
+            }
+            return Ok(());
+        }
+        _ => {}
+    }
+    let envelope = match bounds(g)? {
+        Some(b) => b,
+        None => return Ok(()),
+    };
+    let (xmin, ymin, xmax, ymax) = envelope;
+    if g.get_num_coordinates()? <= max_vertices
+        || depth >= MAX_DEPTH
+        || (xmin == xmax && ymin == ymax)
+    {
+        out.push(Geom::clone(g));
+        return Ok(());
+    }
+    for (x0, y0, x1, y1) in halves(envelope) {
+        let half = g.clip_by_rect(x0, y0, x1, y1)?;
+        subdivide_geometry(&half, max_vertices, depth + 1, out)?;
+    }
+    Ok(())
+}
+
+/// Splits the points of a `MultiPoint` between the halves of their envelope, so that each
+/// point ends up in exactly one piece (clipping would duplicate the points lying on the cut).
+fn subdivide_points(
+    points: Vec<(f64, f64, Geometry)>,
+    max_vertices: usize,
+    depth: u32,
+    out: &mut Vec<Geometry>,
+) -> GResult<()> {
+    if points.is_empty() {
+        return Ok(());
+    }
+    let envelope = points.iter().fold(
+        (
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ),
+        |(xmin, ymin, xmax, ymax), (x, y, _)| {
+            (xmin.min(*x), ymin.min(*y), xmax.max(*x), ymax.max(*y))
+        },
+    );
+    let (xmin, ymin, xmax, ymax) = envelope;
+    if points.len() <= max_vertices || depth >= MAX_DEPTH || (xmin == xmax && ymin == ymax) {
+        out.push(Geometry::create_multipoint(
+            points.into_iter().map(|(_, _, p)| p).collect(),
+        )?);
+        return Ok(());
+    }
+    let (middle_x, middle_y) = ((xmin + xmax) / 2., (ymin + ymax) / 2.);
+    let (first, second) = points.into_iter().partition(|(x, y, _)| {
+        if xmax - xmin >= ymax - ymin {
+            *x < middle_x
+        } else {
+            *y < middle_y
+        }
+    });
+    subdivide_points(first, max_vertices, depth + 1, out)?;
+    subdivide_points(second, max_vertices, depth + 1, out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::subdivide;
+    use crate::{Geom, Geometry, GeometryTypes};
+
+    #[test]
+    fn test_subdivide_multipoint() {
+        // Small multipoints are kept whole.
+        let points = Geometry::new_from_wkt("MULTIPOINT(0 0, 1 1, 2 2)").unwrap();
+        let pieces = subdivide(&points, 5).unwrap();
+        assert_eq!(pieces.len(), 1);
+        assert_eq!(pieces[0].geometry_type(), GeometryTypes::MultiPoint);
+        assert_eq!(pieces[0].get_num_geometries(), Ok(3));
+
+        // Bigger ones are split, each point ending up in exactly one piece.
+        let wkt = (0..20)
+            .map(|i| format!("{} {}", i % 5, i / 5))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let points = Geometry::new_from_wkt(&format!("MULTIPOINT({wkt})")).unwrap();
+        let pieces = subdivide(&points, 5).unwrap();
+        assert!(pieces.len() > 1);
+        assert!(pieces.iter().all(|p| p.get_num_geometries().unwrap() <= 5));
+        let total: usize = pieces.iter().map(|p| p.get_num_geometries().unwrap()).sum();
+        assert_eq!(total, 20);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::enums::GeometryTypes;
-use crate::{Geom, Geometry, PreparedGeometry};
+use crate::{
+    Geom, Geometry, GeometryBuilder, PointInPolygonIndex, PreparedCache, PreparedGeometry,
+};
 
 #[test]
 fn test_relationship() {
@@ -75,6 +77,79 @@ fn test_prepared_geoms() {
     }
 }
 
+#[test]
+fn test_prepared_geoms_many() {
+    let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 0 5, 5 5, 5 0, 0 0))").unwrap();
+    // Enough points to be processed in parallel with the `rayon` feature.
+    let points: Vec<_> = (0..200)
+        .map(|i| Geometry::new_from_wkt(&format!("POINT ({} 1)", i as f64 / 10.)).unwrap())
+        .collect();
+    let expected: Vec<_> = (0..200).map(|i| i > 0 && i < 50).collect();
+
+    let borrowed = PreparedGeometry::new(&polygon).expect("failed to create prepared geom");
+    assert_eq!(borrowed.contains_many(&points).unwrap(), expected);
+    let owned = PreparedGeometry::from_geometry(Geom::clone(&polygon))
+        .expect("failed to create prepared geom");
+    assert_eq!(owned.contains_many(&points).unwrap(), expected);
+    let touching = owned.touches_many(&points).unwrap();
+    assert_eq!(touching.iter().filter(|t| **t).count(), 2);
+}
+
+#[test]
+fn test_point_in_polygon_many() {
+    let polygons = vec![
+        Geometry::new_from_wkt("POLYGON ((0 0, 0 5, 5 5, 5 0, 0 0))").unwrap(),
+        Geometry::new_from_wkt("POLYGON ((5 0, 5 5, 10 5, 10 0, 5 0))").unwrap(),
+    ];
+    let index = PointInPolygonIndex::new(polygons).unwrap();
+    // Enough points to be processed in parallel with the `rayon` feature.
+    let points: Vec<_> = (0..200).map(|i| (i as f64 / 10., 1.)).collect();
+    let expected: Vec<_> = (0..200)
+        .map(|i| match i {
+            0..=50 => Some(0),
+            51..=100 => Some(1),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(index.locate_many(&points).unwrap(), expected);
+}
+
+#[test]
+fn test_prepared_cache_eviction() {
+    let polygons: Vec<_> = (0..3)
+        .map(|i| {
+            let x = i as f64 * 10.;
+            Geometry::new_from_wkt(&format!(
+                "POLYGON (({x} 0, {x} 5, {} 5, {} 0, {x} 0))",
+                x + 5.,
+                x + 5.
+            ))
+            .unwrap()
+        })
+        .collect();
+    let point = Geometry::new_from_wkt("POINT (1 1)").unwrap();
+
+    let mut cache = PreparedCache::new(2);
+    assert!(cache.is_empty());
+    assert_eq!(
+        cache.get_or_prepare(&polygons[0]).unwrap().contains(&point),
+        Ok(true)
+    );
+    assert_eq!(
+        cache.get_or_prepare(&polygons[1]).unwrap().contains(&point),
+        Ok(false)
+    );
+    // Makes `polygons[1]` the least recently used geometry.
+    cache.get_or_prepare(&polygons[0]).unwrap();
+    cache.get_or_prepare(&polygons[2]).unwrap();
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains(&polygons[0]));
+    assert!(!cache.contains(&polygons[1]));
+    assert!(cache.contains(&polygons[2]));
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
 #[test]
 fn test_wkt_rounding_precision() {
     let g = Geometry::new_from_wkt("LINESTRING(0.0 0.0, 7.0 7.0, 45.0 50.5, 100.0 100.0)").unwrap();
@@ -172,6 +247,71 @@ fn test_get_geometry_n() {
     );
 }
 
+#[test]
+fn test_get_bounds_checked() {
+    let multilinestring =
+        Geometry::new_from_wkt("MULTILINESTRING ((1 1, 10 50, 20 25), (0 0, 7 7, 45 50, 100 100))")
+            .unwrap();
+
+    assert_eq!(
+        multilinestring.geometry(1).to_wkt_precision(0),
+        Ok("LINESTRING (0 0, 7 7, 45 50, 100 100)".to_owned()),
+    );
+    assert!(multilinestring.get(2).is_none());
+    assert!(multilinestring.get_interior_ring(0).is_none());
+
+    let empty = Geometry::new_from_wkt("GEOMETRYCOLLECTION EMPTY").unwrap();
+    assert!(empty.get(0).is_none());
+
+    let polygon =
+        Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0), (1 1, 2 1, 2 5, 1 5, 1 1))")
+            .unwrap();
+    let ring = polygon.interior_ring(0);
+    assert_eq!(ring.get_num_points(), Ok(5));
+    assert!(polygon.get_interior_ring(1).is_none());
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+fn test_geometry_out_of_bounds() {
+    let multipoint = Geometry::new_from_wkt("MULTIPOINT (1 1, 2 2)").unwrap();
+    multipoint.geometry(2);
+}
+
+#[test]
+fn test_geometry_builder() {
+    let lines = GeometryBuilder::multi_line_string()
+        .line(&[[0., 0.], [1., 1.]])
+        .line(&[[2., 2., 1.], [3., 3., 1.]])
+        .build();
+    // Dimensions only need to be consistent within a part.
+    assert!(lines.is_ok());
+
+    let polygons = GeometryBuilder::multi_polygon()
+        .exterior(&[[0., 0.], [1., 0.], [1., 1.], [0., 0.]])
+        .exterior(&[[5., 5.], [9., 5.], [9., 9.], [5., 9.], [5., 5.]])
+        .hole(&[[6., 6.], [7., 6.], [7., 7.], [6., 6.]])
+        .build()
+        .unwrap();
+    assert_eq!(
+        polygons.to_wkt_precision(0),
+        Ok("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), \
+            ((5 5, 9 5, 9 9, 5 9, 5 5), (6 6, 7 6, 7 7, 6 6)))"
+            .to_owned()),
+    );
+
+    let point = GeometryBuilder::point().build().unwrap();
+    assert!(point.is_empty().unwrap());
+    assert!(GeometryBuilder::point()
+        .coords(&[[0., 0.], [1., 1.]])
+        .build()
+        .is_err());
+    assert!(GeometryBuilder::polygon()
+        .hole(&[[6., 6.], [7., 6.], [7., 7.], [6., 6.]])
+        .build()
+        .is_err());
+}
+
 fn assert_almost_eq(a: f64, b: f64) {
     let f: f64 = a / b;
     assert!(f < 1.0001);
@@ -0,0 +1,260 @@
+//! Helpers to cut geometries into XYZ tiles, as done by vector tile producers.
+//!
+//! Geometries are expected to be in Web Mercator (EPSG:3857) coordinates, and tiles use the
+//! XYZ scheme: tile `(0, 0)` is the top-left one at every zoom level.
+use crate::error::{Error, GResult};
+use crate::{CoordSeq, Geom, Geometry, GeometryTypes};
+
+/// Half the width of the Web Mercator world, in meters.
+pub const WEB_MERCATOR_EXTENT: f64 = 20037508.342789244;
+const MAX_ZOOM: u8 = 30;
+
+/// Bounds of a tile, in Web Mercator coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TileBounds {
+    pub xmin: f64,
+    pub ymin: f64,
+    pub xmax: f64,
+    pub ymax: f64,
+}
+
+impl TileBounds {
+    /// Returns the bounds of the `(z, x, y)` tile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::tile::{TileBounds, WEB_MERCATOR_EXTENT};
+    ///
+    /// let bounds = TileBounds::new(1, 0, 0).expect("invalid tile");
+    /// assert_eq!(bounds.xmin, -WEB_MERCATOR_EXTENT);
+    /// assert_eq!(bounds.ymin, 0.);
+    /// assert_eq!(bounds.xmax, 0.);
+    /// assert_eq!(bounds.ymax, WEB_MERCATOR_EXTENT);
+    /// ```
+    pub fn new(z: u8, x: u32, y: u32) -> GResult<TileBounds> {
+        if z > MAX_ZOOM {
+            return Err(Error::GenericError(format!(
+                "tile zoom must be at most {MAX_ZOOM}, got {z}"
+            )));
+        }
+        let count = 1u32 << z;
+        if x >= count || y >= count {
+            return Err(Error::GenericError(format!(
+                "invalid tile ({z}, {x}, {y}): x and y must be lower than {count}"
+            )));
+        }
+        let size = 2. * WEB_MERCATOR_EXTENT / count as f64;
+        Ok(TileBounds {
+            xmin: -WEB_MERCATOR_EXTENT + x as f64 * size,
+            ymin: WEB_MERCATOR_EXTENT - (y + 1) as f64 * size,
+            xmax: -WEB_MERCATOR_EXTENT + (x + 1) as f64 * size,
+            ymax: WEB_MERCATOR_EXTENT - y as f64 * size,
+        })
+    }
+
+    /// Returns the bounds grown by `buffer_px` pixels on each side, for a tile of `extent`
+    /// pixels wide.
+    pub fn buffered(&self, buffer_px: u32, extent: u32) -> TileBounds {
+        let buffer = (self.xmax - self.xmin) * buffer_px as f64 / extent as f64;
+        TileBounds {
+            xmin: self.xmin - buffer,
+            ymin: self.ymin - buffer,
+            xmax: self.xmax + buffer,
+            ymax: self.ymax + buffer,
+        }
+    }
+}
+
+/// Returns the part of `geom` inside the `(z, x, y)` tile, grown by `buffer_px` pixels (for a
+/// tile of `extent` pixels wide) to avoid rendering artifacts at the tile edges. The result is
+/// empty if `geom` doesn't intersect the tile.
+///
+/// As it relies on [`Geom::clip_by_rect`], the result may be invalid for polygons (but is fine
+/// for rendering).
+///
+/// # Example
+///
+/// ```
+/// use geos::{tile, Geom, Geometry};
+///
+/// let geom = Geometry::new_from_wkt("LINESTRING(-1000 1000, 1000 1000)").unwrap();
+///
+/// let piece = tile::clip(&geom, 1, 0, 0, 0, 4096).expect("clip failed");
+/// assert_eq!(piece.to_wkt_precision(0).unwrap(), "LINESTRING (-1000 1000, 0 1000)");
+///
+/// let piece = tile::clip(&geom, 1, 0, 1, 0, 4096).expect("clip failed");
+/// assert!(piece.is_empty().unwrap());
+/// ```
+pub fn clip<G: Geom>(
+    geom: &G,
+    z: u8,
+    x: u32,
+    y: u32,
+    buffer_px: u32,
+    extent: u32,
+) -> GResult<Geometry> {
+    if extent == 0 {
+        return Err(Error::GenericError(
+            "tile extent must be greater than 0".to_owned(),
+        ));
+    }
+    let bounds = TileBounds::new(z, x, y)?.buffered(buffer_px, extent);
+    geom.clip_by_rect(bounds.xmin, bounds.ymin, bounds.xmax, bounds.ymax)
+}
+
+/// Scales `g` coordinates to the `[0, extent]` integer space of the tile, with the Y axis
+/// pointing down as in MVT.
+struct Quantizer {
+    bounds: TileBounds,
+    scale_x: f64,
+    scale_y: f64,
+}
+
+impl Quantizer {
+    fn coords<G: Geom>(&self, g: &G) -> GResult<Vec<[f64; 2]>> {
+        let coords = g.get_coord_seq()?;
+        let size = coords.size()?;
+        let mut res: Vec<[f64; 2]> = Vec::with_capacity(size);
+        for i in 0..size {
+            let point = [
+                ((coords.get_x(i)? - self.bounds.xmin) * self.scale_x).round(),
+                ((self.bounds.ymax - coords.get_y(i)?) * self.scale_y).round(),
+            ];
+            if res.last() != Some(&point) {
+                res.push(point);
+            }
+        }
+        Ok(res)
+    }
+
+    fn ring<G: Geom>(&self, ring: &G) -> GResult<Option<Geometry>> {
+        let coords = self.coords(ring)?;
+        if coords.len() < 4 {
+            return Ok(None);
+        }
+        CoordSeq::new_from_vec(&coords)?
+            .create_linear_ring()
+            .map(Some)
+    }
+
+    fn parts<G: Geom>(&self, g: &G) -> GResult<Vec<Geometry>> {
+        let mut parts = Vec::new();
+        for i in 0..g.get_num_geometries()? {
+            if let Some(part) = self.quantize(&g.get_geometry_n(i)?)? {
+                parts.push(part);
+            }
+        }
+        Ok(parts)
+    }
+
+    /// Returns `None` if `g` collapsed.
+    fn quantize<G: Geom>(&self, g: &G) -> GResult<Option<Geometry>> {
+        if g.is_empty()? {
+            return Ok(None);
+        }
+        let res = match g.geometry_type() {
+            GeometryTypes::Point => CoordSeq::new_from_vec(&self.coords(g)?)?.create_point()?,
+            GeometryTypes::LineString => {
+                let coords = self.coords(g)?;
+                if coords.len() < 2 {
+                    return Ok(None);
+                }
+                CoordSeq::new_from_vec(&coords)?.create_line_string()?
+            }
+            GeometryTypes::LinearRing => return self.ring(g),
+            GeometryTypes::Polygon => {
+                let exterior = match self.ring(&g.get_exterior_ring()?)? {
+                    Some(exterior) => exterior,
+                    None => return Ok(None),
+                };
+                let mut interiors = Vec::new();
+                for i in 0..g.get_num_interior_rings()? {
+                    interiors.extend(self.ring(&g.get_interior_ring_n(i as _)?)?);
+                }
+                Geometry::create_polygon(exterior, interiors)?
+            }
+            geom_type => {
+                let parts = self.parts(g)?;
+                if parts.is_empty() {
+                    return Ok(None);
+                }
+                match geom_type {
+                    GeometryTypes::MultiPoint => Geometry::create_multipoint(parts)?,
+                    GeometryTypes::MultiLineString => Geometry::create_multiline_string(parts)?,
+                    GeometryTypes::MultiPolygon => Geometry::create_multipolygon(parts)?,
+                    _ => Geometry::create_geometry_collection(parts)?,
+                }
+            }
+        };
+        Ok(Some(res))
+    }
+}
+
+/// Fixes the polygons made invalid by the rounding of their coordinates.
+fn revalidate(g: Geometry) -> GResult<Geometry> {
+    if !matches!(
+        g.geometry_type(),
+        GeometryTypes::Polygon | GeometryTypes::MultiPolygon
+    ) || g.is_valid()
+    {
+        return Ok(g);
+    }
+    #[cfg(any(feature = "v3_6_0", feature = "dox"))]
+    {
+        // Snap rounding on the unit grid keeps the coordinates integral, unlike a buffer.
+        g.set_precision(1., crate::Precision::ValidOutput)
+    }
+    #[cfg(not(any(feature = "v3_6_0", feature = "dox")))]
+    {
+        g.buffer(0., 8)
+    }
+}
+
+pub(crate) fn quantize<G: Geom>(g: &G, bounds: &TileBounds, extent: u32) -> GResult<Geometry> {
+    if extent == 0 {
+        return Err(Error::GenericError(
+            "tile extent must be greater than 0".to_owned(),
+        ));
+    }
+    let quantizer = Quantizer {
+        bounds: *bounds,
+        scale_x: extent as f64 / (bounds.xmax - bounds.xmin),
+        scale_y: extent as f64 / (bounds.ymax - bounds.ymin),
+    };
+    match quantizer.quantize(g)? {
+        Some(res) => revalidate(res),
+        None => match g.geometry_type() {
+            GeometryTypes::Point => Geometry::create_empty_point(),
+            GeometryTypes::LineString | GeometryTypes::LinearRing => {
+                Geometry::create_empty_line_string()
+            }
+            GeometryTypes::Polygon => Geometry::create_empty_polygon(),
+            geom_type => Geometry::create_empty_collection(geom_type),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{TileBounds, WEB_MERCATOR_EXTENT};
+
+    #[test]
+    fn test_tile_bounds() {
+        let world = TileBounds::new(0, 0, 0).unwrap();
+        assert_eq!(world.xmin, -WEB_MERCATOR_EXTENT);
+        assert_eq!(world.ymin, -WEB_MERCATOR_EXTENT);
+        assert_eq!(world.xmax, WEB_MERCATOR_EXTENT);
+        assert_eq!(world.ymax, WEB_MERCATOR_EXTENT);
+
+        let tile = TileBounds::new(2, 3, 3).unwrap();
+        assert_eq!(tile.xmax, WEB_MERCATOR_EXTENT);
+        assert_eq!(tile.ymin, -WEB_MERCATOR_EXTENT);
+
+        let buffered = world.buffered(64, 4096);
+        assert_eq!(buffered.xmax, WEB_MERCATOR_EXTENT * (1. + 2. * 64. / 4096.));
+
+        assert!(TileBounds::new(1, 2, 0).is_err());
+        assert!(TileBounds::new(31, 0, 0).is_err());
+    }
+}
@@ -1,31 +1,169 @@
-use crate::error::Error;
-use crate::{ConstGeometry, Geom, Geometry as GGeometry};
-use geo_types::Geometry;
-use wkt;
-use wkt::TryFromWkt;
+use crate::error::{Error, GResult};
+use crate::{ConstGeometry, Geom, Geometry as GGeometry, GeometryTypes};
+use geo_types::{
+    Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use geos_sys::*;
 
 use std::convert::TryFrom;
 
-macro_rules! impl_try_into {
-    ($ty_name:ident $(,$lt:lifetime)?) => (
-impl<'b$(,$lt)?> TryFrom<&'b $ty_name$(<$lt>)?> for Geometry<f64> {
-    type Error = Error;
+/// Reads the coordinates of a `Point`, `LineString` or `LinearRing` directly from the GEOS
+/// coordinate sequence, without cloning it.
+fn coords<G: Geom>(geom: &G) -> GResult<Vec<(f64, f64)>> {
+    unsafe {
+        let context = geom.get_raw_context();
+        let cs = GEOSGeom_getCoordSeq_r(context, geom.as_raw());
+        if cs.is_null() {
+            return Err(Error::GenericError(
+                "GEOSGeom_getCoordSeq_r failed".to_owned(),
+            ));
+        }
+        let mut size = 0;
+        if GEOSCoordSeq_getSize_r(context, cs, &mut size) == 0 {
+            return Err(Error::GenericError(
+                "GEOSCoordSeq_getSize_r failed".to_owned(),
+            ));
+        }
+        let mut coords = Vec::with_capacity(size as _);
+        for i in 0..size {
+            let mut x = 0.;
+            let mut y = 0.;
+            if GEOSCoordSeq_getX_r(context, cs, i, &mut x) == 0
+                || GEOSCoordSeq_getY_r(context, cs, i, &mut y) == 0
+            {
+                return Err(Error::GenericError(
+                    "failed to read coordinate sequence".to_owned(),
+                ));
+            }
+            coords.push((x, y));
+        }
+        Ok(coords)
+    }
+}
+
+fn check_type<G: Geom>(geom: &G, expected: &[GeometryTypes]) -> GResult<()> {
+    let found = geom.geometry_type();
+    if expected.contains(&found) {
+        Ok(())
+    } else {
+        Err(Error::ConversionError(format!(
+            "expected {:?}, found {found:?}",
+            expected[0]
+        )))
+    }
+}
 
-    fn try_from(other: &'b $ty_name$(<$lt>)?) -> Result<Geometry<f64>, Self::Error> {
-        // This is a first draft, it's very inefficient, we use wkt as a pivot format to
-        // translate the geometry.
-        // We should at least use wkb, or even better implement a direct translation
-        let wkt_str = other.to_wkt()?;
-        geo_types::Geometry::try_from_wkt_str(&wkt_str)
-            .map_err(|e| Error::ConversionError(format!("impossible to read wkt: {}", e)))
+fn to_point<G: Geom>(geom: &G) -> GResult<Point<f64>> {
+    check_type(geom, &[GeometryTypes::Point])?;
+    match coords(geom)?.first() {
+        Some(&(x, y)) => Ok(Point::new(x, y)),
+        None => Err(Error::ConversionError(
+            "impossible to convert an empty point".to_owned(),
+        )),
     }
 }
+
+fn to_line_string<G: Geom>(geom: &G) -> GResult<LineString<f64>> {
+    check_type(
+        geom,
+        &[GeometryTypes::LineString, GeometryTypes::LinearRing],
+    )?;
+    Ok(LineString::from(coords(geom)?))
+}
+
+fn to_polygon<G: Geom>(geom: &G) -> GResult<Polygon<f64>> {
+    check_type(geom, &[GeometryTypes::Polygon])?;
+    if geom.is_empty()? {
+        return Ok(Polygon::new(LineString(Vec::new()), Vec::new()));
+    }
+    let exterior = to_line_string(&geom.get_exterior_ring()?)?;
+    let nb_interiors = geom.get_num_interior_rings()?;
+    let mut interiors = Vec::with_capacity(nb_interiors);
+    for i in 0..nb_interiors {
+        interiors.push(to_line_string(&geom.get_interior_ring_n(i as _)?)?);
+    }
+    Ok(Polygon::new(exterior, interiors))
+}
+
+fn to_parts<G, T, F>(geom: &G, expected: GeometryTypes, f: F) -> GResult<Vec<T>>
+where
+    G: Geom,
+    F: Fn(&ConstGeometry) -> GResult<T>,
+{
+    check_type(geom, &[expected])?;
+    // `get_num_geometries` fails on empty collections.
+    if geom.is_empty()? {
+        return Ok(Vec::new());
+    }
+    let n = geom.get_num_geometries()?;
+    let mut parts = Vec::with_capacity(n);
+    for i in 0..n {
+        parts.push(f(&geom.get_geometry_n(i)?)?);
+    }
+    Ok(parts)
+}
+
+fn to_multi_point<G: Geom>(geom: &G) -> GResult<MultiPoint<f64>> {
+    to_parts(geom, GeometryTypes::MultiPoint, |g| to_point(g)).map(MultiPoint)
+}
+
+fn to_multi_line_string<G: Geom>(geom: &G) -> GResult<MultiLineString<f64>> {
+    to_parts(geom, GeometryTypes::MultiLineString, |g| to_line_string(g)).map(MultiLineString)
+}
+
+fn to_multi_polygon<G: Geom>(geom: &G) -> GResult<MultiPolygon<f64>> {
+    to_parts(geom, GeometryTypes::MultiPolygon, |g| to_polygon(g)).map(MultiPolygon)
+}
+
+fn to_geometry_collection<G: Geom>(geom: &G) -> GResult<GeometryCollection<f64>> {
+    to_parts(geom, GeometryTypes::GeometryCollection, |g| to_geometry(g)).map(GeometryCollection)
+}
+
+fn to_geometry<G: Geom>(geom: &G) -> GResult<Geometry<f64>> {
+    match geom.geometry_type() {
+        GeometryTypes::Point => to_point(geom).map(Geometry::Point),
+        GeometryTypes::LineString | GeometryTypes::LinearRing => {
+            to_line_string(geom).map(Geometry::LineString)
+        }
+        GeometryTypes::Polygon => to_polygon(geom).map(Geometry::Polygon),
+        GeometryTypes::MultiPoint => to_multi_point(geom).map(Geometry::MultiPoint),
+        GeometryTypes::MultiLineString => to_multi_line_string(geom).map(Geometry::MultiLineString),
+        GeometryTypes::MultiPolygon => to_multi_polygon(geom).map(Geometry::MultiPolygon),
+        GeometryTypes::GeometryCollection => {
+            to_geometry_collection(geom).map(Geometry::GeometryCollection)
+        }
+        GeometryTypes::__Unknown(x) => Err(Error::ConversionError(format!(
+            "unknown geometry type: {x}"
+        ))),
+    }
+}
+
+macro_rules! impl_try_into {
+    ($ty_name:ident $(,$lt:lifetime)?) => (
 impl$(<$lt>)? TryFrom<$ty_name$(<$lt>)?> for Geometry<f64> {
     type Error = Error;
 
     fn try_from(other: $ty_name$(<$lt>)?) -> Result<Geometry<f64>, Self::Error> {
         Geometry::try_from(&other)
     }
+}
+impl_try_into!($ty_name $(,$lt)?; Geometry, to_geometry);
+impl_try_into!($ty_name $(,$lt)?; Point, to_point);
+impl_try_into!($ty_name $(,$lt)?; LineString, to_line_string);
+impl_try_into!($ty_name $(,$lt)?; Polygon, to_polygon);
+impl_try_into!($ty_name $(,$lt)?; MultiPoint, to_multi_point);
+impl_try_into!($ty_name $(,$lt)?; MultiLineString, to_multi_line_string);
+impl_try_into!($ty_name $(,$lt)?; MultiPolygon, to_multi_polygon);
+impl_try_into!($ty_name $(,$lt)?; GeometryCollection, to_geometry_collection);
+    );
+    ($ty_name:ident $(,$lt:lifetime)?; $geo_type:ident, $convert:ident) => (
+impl<'b$(,$lt)?> TryFrom<&'b $ty_name$(<$lt>)?> for $geo_type<f64> {
+    type Error = Error;
+
+    fn try_from(other: &'b $ty_name$(<$lt>)?) -> Result<$geo_type<f64>, Self::Error> {
+        $convert(other)
+    }
 }
     );
 }
@@ -35,7 +173,7 @@ impl_try_into!(ConstGeometry, 'c);
 
 #[cfg(test)]
 mod test {
-    use crate::Geometry as GGeometry;
+    use crate::{Geom, Geometry as GGeometry};
     use geo_types::{Coordinate, Geometry, LineString, MultiPoint, MultiPolygon, Point, Polygon};
     use std::convert::TryInto;
 
@@ -80,4 +218,33 @@ mod test {
         // This check is to enforce that `TryFrom` is implemented for both reference and value.
         assert_eq!(expected, mp.try_into().unwrap());
     }
+
+    #[test]
+    fn geom_to_geo_borrowed_types() {
+        let poly = "POLYGON((0 0, 10 0, 10 6, 0 6, 0 0), (1 1, 2 1, 2 5, 1 5, 1 1))";
+        let poly = GGeometry::new_from_wkt(poly).unwrap();
+
+        let geo_polygon: Polygon<f64> = (&poly).try_into().unwrap();
+        assert_eq!(
+            geo_polygon.exterior(),
+            &LineString(coords(vec![
+                (0., 0.),
+                (10., 0.),
+                (10., 6.),
+                (0., 6.),
+                (0., 0.),
+            ]))
+        );
+        assert_eq!(geo_polygon.interiors().len(), 1);
+
+        let interior: LineString<f64> = (&poly.get_interior_ring_n(0).unwrap()).try_into().unwrap();
+        assert_eq!(interior, geo_polygon.interiors()[0]);
+
+        let mp = GGeometry::new_from_wkt("MULTIPOINT (0 0, 1 1)").unwrap();
+        let geo_point: Point<f64> = (&mp.get_geometry_n(1).unwrap()).try_into().unwrap();
+        assert_eq!(geo_point, Point::new(1., 1.));
+
+        let res: Result<Point<f64>, _> = (&poly).try_into();
+        assert!(res.is_err());
+    }
 }
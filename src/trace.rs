@@ -0,0 +1,29 @@
+//! Tracing spans wrapping the major operations, enabled by the `tracing` feature.
+//!
+//! Spans use the `geos` target and the `debug` level. The `coordinates` field (the total number
+//! of coordinates of the input geometries) or the `bytes` field (the size of the parsed input)
+//! is only computed if the span is enabled.
+
+/// Enters a span named `$name` until the end of the current scope.
+#[cfg(feature = "tracing")]
+macro_rules! trace_span {
+    ($name:literal, bytes = $bytes:expr) => {
+        let _span = tracing::debug_span!(target: "geos", $name, bytes = $bytes).entered();
+    };
+    ($name:literal, $($geom:expr),+) => {
+        let _span = {
+            let span =
+                tracing::debug_span!(target: "geos", $name, coordinates = tracing::field::Empty);
+            if !span.is_disabled() {
+                let coordinates: usize = 0 $(+ $geom.get_num_coordinates().unwrap_or(0))+;
+                span.record("coordinates", coordinates);
+            }
+            span.entered()
+        };
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace_span {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,30 @@
+use crate::Geometry;
+use geos_sys::GEOSValidFlags_GEOSVALID_ALLOW_SELFTOUCHING_RING_FORMING_HOLE;
+
+/// Options of [`Geom::is_valid_detail`](crate::Geom::is_valid_detail).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValidityFlags {
+    /// Considers valid the polygons whose exterior ring touches itself, forming a hole (the
+    /// ESRI model), instead of requiring such holes to be described as separate rings.
+    pub allow_self_touching_ring_forming_hole: bool,
+}
+
+impl ValidityFlags {
+    pub(crate) fn bits(&self) -> i32 {
+        let mut flags = 0;
+        if self.allow_self_touching_ring_forming_hole {
+            flags |= GEOSValidFlags_GEOSVALID_ALLOW_SELFTOUCHING_RING_FORMING_HOLE as i32;
+        }
+        flags
+    }
+}
+
+/// Why a geometry is invalid, as returned by
+/// [`Geom::is_valid_detail`](crate::Geom::is_valid_detail).
+#[derive(Clone)]
+pub struct ValidityDetail {
+    /// Human-readable description of the problem.
+    pub reason: String,
+    /// Point where the problem is located, if GEOS provides one.
+    pub location: Option<Geometry>,
+}
@@ -7,7 +7,9 @@ use crate::{
 };
 use geos_sys::*;
 use std::convert::TryFrom;
+use std::ffi::CStr;
 use std::sync::Arc;
+use std::{fmt, io};
 
 /// The `WKTWriter` type is used to generate `WKT` formatted output from [`Geometry`](crate::Geometry).
 ///
@@ -107,6 +109,73 @@ impl WKTWriter {
         }
     }
 
+    /// Writes out the given `geometry` as WKT format into `out`, without allocating an
+    /// intermediate `String`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geometry, WKTWriter};
+    ///
+    /// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)").expect("Invalid geometry");
+    /// let mut writer = WKTWriter::new().expect("Failed to create WKTWriter");
+    /// writer.set_trim(true);
+    ///
+    /// let mut out = String::from("geom: ");
+    /// writer.write_to(&point_geom, &mut out).unwrap();
+    /// assert_eq!(out, "geom: POINT (2.5 2.5)");
+    /// ```
+    pub fn write_to<G: Geom, W: fmt::Write>(&mut self, geometry: &G, out: &mut W) -> GResult<()> {
+        self.write_with(geometry, "WKTWriter::write_to", |s| {
+            out.write_str(s)
+                .map_err(|e| Error::GenericError(format!("WKTWriter::write_to failed: {e}")))
+        })
+    }
+
+    /// Writes out the given `geometry` as WKT format into the `out` [`io::Write`] (a file, a
+    /// socket...).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geometry, WKTWriter};
+    ///
+    /// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)").expect("Invalid geometry");
+    /// let mut writer = WKTWriter::new().expect("Failed to create WKTWriter");
+    /// writer.set_trim(true);
+    ///
+    /// let mut out: Vec<u8> = Vec::new();
+    /// writer.write_to_io(&point_geom, &mut out).unwrap();
+    /// assert_eq!(out, b"POINT (2.5 2.5)");
+    /// ```
+    pub fn write_to_io<G: Geom, W: io::Write>(&mut self, geometry: &G, out: &mut W) -> GResult<()> {
+        self.write_with(geometry, "WKTWriter::write_to_io", |s| {
+            out.write_all(s.as_bytes())
+                .map_err(|e| Error::GenericError(format!("WKTWriter::write_to_io failed: {e}")))
+        })
+    }
+
+    fn write_with<G: Geom, F: FnOnce(&str) -> GResult<()>>(
+        &mut self,
+        geometry: &G,
+        caller: &str,
+        f: F,
+    ) -> GResult<()> {
+        unsafe {
+            let ptr =
+                GEOSWKTWriter_write_r(self.get_raw_context(), self.as_raw_mut(), geometry.as_raw());
+            if ptr.is_null() {
+                return Err(Error::NoConstructionFromNullPtr(caller.to_owned()));
+            }
+            let res = match CStr::from_ptr(ptr).to_str() {
+                Ok(s) => f(s),
+                Err(e) => Err(Error::GenericError(format!("{caller} failed: {e}"))),
+            };
+            GEOSFree_r(self.get_raw_context(), ptr as *mut _);
+            res
+        }
+    }
+
     /// Sets the `precision` to be used when calling [`WKTWriter::write`]. Often, what users
     /// actually want is the [`WKTWriter::set_trim`] method instead.
     ///
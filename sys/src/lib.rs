@@ -11,7 +11,7 @@
 //! will also statically link libgeos to this crate.  In order to build GEOS, you
 //! need to have `cmake` and a C++ compiler.
 //!
-//! This documentation is generated based on GEOS 3.11.  Please see the
+//! This documentation is generated based on GEOS 3.12.  Please see the
 //! [GEOS Changelog](https://github.com/libgeos/geos/blob/main/NEWS.md) for
 //! a listing of which entries were added for each GEOS version.
 
@@ -40,5 +40,8 @@ include!("../prebuilt-bindings/geos_3.9.rs");
 #[cfg(all(feature = "v3_10_0", not(any(feature = "v3_11_0", feature = "dox"))))]
 include!("../prebuilt-bindings/geos_3.10.rs");
 
-#[cfg(any(feature = "v3_11_0", feature = "dox"))]
+#[cfg(all(feature = "v3_11_0", not(any(feature = "v3_12_0", feature = "dox"))))]
 include!("../prebuilt-bindings/geos_3.11.rs");
+
+#[cfg(any(feature = "v3_12_0", feature = "dox"))]
+include!("../prebuilt-bindings/geos_3.12.rs");